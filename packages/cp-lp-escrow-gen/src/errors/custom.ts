@@ -0,0 +1,34 @@
+export type CustomError = PositionNotEmpty | PersonalRewarderOpen
+
+export class PositionNotEmpty extends Error {
+  static readonly code = 6000
+  readonly code = 6000
+  readonly name = "PositionNotEmpty"
+  readonly msg = "Personal position still holds LP tokens"
+
+  constructor(readonly logs?: string[]) {
+    super("6000: Personal position still holds LP tokens")
+  }
+}
+
+export class PersonalRewarderOpen extends Error {
+  static readonly code = 6001
+  readonly code = 6001
+  readonly name = "PersonalRewarderOpen"
+  readonly msg = "Gauge personal rewarder of the position must be closed first"
+
+  constructor(readonly logs?: string[]) {
+    super("6001: Gauge personal rewarder of the position must be closed first")
+  }
+}
+
+export function fromCode(code: number, logs?: string[]): CustomError | null {
+  switch (code) {
+    case 6000:
+      return new PositionNotEmpty(logs)
+    case 6001:
+      return new PersonalRewarderOpen(logs)
+  }
+
+  return null
+}
@@ -0,0 +1,33 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface ClosePersonalPositionAccounts {
+  owner: PublicKey
+  timeTracker: PublicKey
+  personalPosition: PublicKey
+  poolGauge: PublicKey
+  personalRewarder: PublicKey
+  destination: PublicKey
+}
+
+/** Close an empty personal position and refund its rent */
+export function closePersonalPosition(
+  accounts: ClosePersonalPositionAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.owner, isSigner: true, isWritable: false },
+    { pubkey: accounts.timeTracker, isSigner: false, isWritable: true },
+    { pubkey: accounts.personalPosition, isSigner: false, isWritable: true },
+    { pubkey: accounts.poolGauge, isSigner: false, isWritable: false },
+    { pubkey: accounts.personalRewarder, isSigner: false, isWritable: false },
+    { pubkey: accounts.destination, isSigner: false, isWritable: true },
+  ]
+  const identifier = Buffer.from([55, 241, 98, 29, 212, 231, 74, 65])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
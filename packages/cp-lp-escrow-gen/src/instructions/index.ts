@@ -6,5 +6,7 @@ export { deposit } from "./deposit"
 export type { DepositArgs, DepositAccounts } from "./deposit"
 export { withdraw } from "./withdraw"
 export type { WithdrawArgs, WithdrawAccounts } from "./withdraw"
+export { closePersonalPosition } from "./closePersonalPosition"
+export type { ClosePersonalPositionAccounts } from "./closePersonalPosition"
 export { updatePersonalPosition } from "./updatePersonalPosition"
 export type { UpdatePersonalPositionAccounts } from "./updatePersonalPosition"
@@ -0,0 +1,181 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface BribeFields {
+  /** Pool gauge whose voters are paid */
+  poolGauge: PublicKey
+  /** Mint of the bribe tokens */
+  mint: PublicKey
+  /** Epoch whose voters are paid */
+  epoch: BN
+  /** Token account holding the bribe, owned by this account */
+  vault: PublicKey
+  /** Creator of the bribe, receives refunds of unclaimed tokens */
+  refundAuthority: PublicKey
+  /** Total amount deposited */
+  totalAmount: BN
+  /** Amount claimed by voters */
+  claimedAmount: BN
+  /** Unclaimed amount rolled over or refunded after the claim window */
+  settledAmount: BN
+  bump: Array<number>
+}
+
+export interface BribeJSON {
+  /** Pool gauge whose voters are paid */
+  poolGauge: string
+  /** Mint of the bribe tokens */
+  mint: string
+  /** Epoch whose voters are paid */
+  epoch: string
+  /** Token account holding the bribe, owned by this account */
+  vault: string
+  /** Creator of the bribe, receives refunds of unclaimed tokens */
+  refundAuthority: string
+  /** Total amount deposited */
+  totalAmount: string
+  /** Amount claimed by voters */
+  claimedAmount: string
+  /** Unclaimed amount rolled over or refunded after the claim window */
+  settledAmount: string
+  bump: Array<number>
+}
+
+/**
+ * Tokens offered to the voters of a pool gauge for one epoch
+ * One per gauge, mint and epoch, deposits are permissionless
+ */
+export class Bribe {
+  /** Pool gauge whose voters are paid */
+  readonly poolGauge: PublicKey
+  /** Mint of the bribe tokens */
+  readonly mint: PublicKey
+  /** Epoch whose voters are paid */
+  readonly epoch: BN
+  /** Token account holding the bribe, owned by this account */
+  readonly vault: PublicKey
+  /** Creator of the bribe, receives refunds of unclaimed tokens */
+  readonly refundAuthority: PublicKey
+  /** Total amount deposited */
+  readonly totalAmount: BN
+  /** Amount claimed by voters */
+  readonly claimedAmount: BN
+  /** Unclaimed amount rolled over or refunded after the claim window */
+  readonly settledAmount: BN
+  readonly bump: Array<number>
+
+  static readonly discriminator = Buffer.from([
+    123, 25, 44, 23, 111, 217, 65, 73,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.publicKey("poolGauge"),
+    borsh.publicKey("mint"),
+    borsh.u64("epoch"),
+    borsh.publicKey("vault"),
+    borsh.publicKey("refundAuthority"),
+    borsh.u64("totalAmount"),
+    borsh.u64("claimedAmount"),
+    borsh.u64("settledAmount"),
+    borsh.array(borsh.u8(), 1, "bump"),
+  ])
+
+  constructor(fields: BribeFields) {
+    this.poolGauge = fields.poolGauge
+    this.mint = fields.mint
+    this.epoch = fields.epoch
+    this.vault = fields.vault
+    this.refundAuthority = fields.refundAuthority
+    this.totalAmount = fields.totalAmount
+    this.claimedAmount = fields.claimedAmount
+    this.settledAmount = fields.settledAmount
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Bribe | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<Bribe | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): Bribe {
+    if (!data.slice(0, 8).equals(Bribe.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = Bribe.layout.decode(data.slice(8))
+
+    return new Bribe({
+      poolGauge: dec.poolGauge,
+      mint: dec.mint,
+      epoch: dec.epoch,
+      vault: dec.vault,
+      refundAuthority: dec.refundAuthority,
+      totalAmount: dec.totalAmount,
+      claimedAmount: dec.claimedAmount,
+      settledAmount: dec.settledAmount,
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): BribeJSON {
+    return {
+      poolGauge: this.poolGauge.toString(),
+      mint: this.mint.toString(),
+      epoch: this.epoch.toString(),
+      vault: this.vault.toString(),
+      refundAuthority: this.refundAuthority.toString(),
+      totalAmount: this.totalAmount.toString(),
+      claimedAmount: this.claimedAmount.toString(),
+      settledAmount: this.settledAmount.toString(),
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: BribeJSON): Bribe {
+    return new Bribe({
+      poolGauge: new PublicKey(obj.poolGauge),
+      mint: new PublicKey(obj.mint),
+      epoch: new BN(obj.epoch),
+      vault: new PublicKey(obj.vault),
+      refundAuthority: new PublicKey(obj.refundAuthority),
+      totalAmount: new BN(obj.totalAmount),
+      claimedAmount: new BN(obj.claimedAmount),
+      settledAmount: new BN(obj.settledAmount),
+      bump: obj.bump,
+    })
+  }
+}
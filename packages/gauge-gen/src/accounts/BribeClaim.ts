@@ -0,0 +1,109 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface BribeClaimFields {
+  bribe: PublicKey
+  personalGauge: PublicKey
+  /** Amount claimed */
+  amount: BN
+}
+
+export interface BribeClaimJSON {
+  bribe: string
+  personalGauge: string
+  /** Amount claimed */
+  amount: string
+}
+
+/** Receipt that a personal gauge claimed a bribe, preventing double claims */
+export class BribeClaim {
+  readonly bribe: PublicKey
+  readonly personalGauge: PublicKey
+  /** Amount claimed */
+  readonly amount: BN
+
+  static readonly discriminator = Buffer.from([
+    232, 205, 168, 216, 148, 55, 115, 118,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.publicKey("bribe"),
+    borsh.publicKey("personalGauge"),
+    borsh.u64("amount"),
+  ])
+
+  constructor(fields: BribeClaimFields) {
+    this.bribe = fields.bribe
+    this.personalGauge = fields.personalGauge
+    this.amount = fields.amount
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<BribeClaim | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<BribeClaim | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): BribeClaim {
+    if (!data.slice(0, 8).equals(BribeClaim.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = BribeClaim.layout.decode(data.slice(8))
+
+    return new BribeClaim({
+      bribe: dec.bribe,
+      personalGauge: dec.personalGauge,
+      amount: dec.amount,
+    })
+  }
+
+  toJSON(): BribeClaimJSON {
+    return {
+      bribe: this.bribe.toString(),
+      personalGauge: this.personalGauge.toString(),
+      amount: this.amount.toString(),
+    }
+  }
+
+  static fromJSON(obj: BribeClaimJSON): BribeClaim {
+    return new BribeClaim({
+      bribe: new PublicKey(obj.bribe),
+      personalGauge: new PublicKey(obj.personalGauge),
+      amount: new BN(obj.amount),
+    })
+  }
+}
@@ -0,0 +1,116 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface EmissionScheduleFields {
+  /** Number of segments in use */
+  numSegments: number
+  /** Segments, sorted by strictly increasing start time */
+  segments: Array<types.EmissionSegmentFields>
+}
+
+export interface EmissionScheduleJSON {
+  /** Number of segments in use */
+  numSegments: number
+  /** Segments, sorted by strictly increasing start time */
+  segments: Array<types.EmissionSegmentJSON>
+}
+
+/**
+ * Stepped schedule of RAY emission for all gauges
+ * Before the first segment starts, the `GaugeConfig.ray_emission_per_day` rate applies
+ * After the last segment starts, its rate applies indefinitely
+ */
+export class EmissionSchedule {
+  /** Number of segments in use */
+  readonly numSegments: number
+  /** Segments, sorted by strictly increasing start time */
+  readonly segments: Array<types.EmissionSegment>
+
+  static readonly discriminator = Buffer.from([
+    124, 49, 254, 56, 35, 98, 181, 88,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.u8("numSegments"),
+    borsh.array(types.EmissionSegment.layout(), 16, "segments"),
+  ])
+
+  constructor(fields: EmissionScheduleFields) {
+    this.numSegments = fields.numSegments
+    this.segments = fields.segments.map(
+      (item) => new types.EmissionSegment({ ...item })
+    )
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<EmissionSchedule | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<EmissionSchedule | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): EmissionSchedule {
+    if (!data.slice(0, 8).equals(EmissionSchedule.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = EmissionSchedule.layout.decode(data.slice(8))
+
+    return new EmissionSchedule({
+      numSegments: dec.numSegments,
+      segments: dec.segments.map(
+        (
+          item: any /* eslint-disable-line @typescript-eslint/no-explicit-any */
+        ) => types.EmissionSegment.fromDecoded(item)
+      ),
+    })
+  }
+
+  toJSON(): EmissionScheduleJSON {
+    return {
+      numSegments: this.numSegments,
+      segments: this.segments.map((item) => item.toJSON()),
+    }
+  }
+
+  static fromJSON(obj: EmissionScheduleJSON): EmissionSchedule {
+    return new EmissionSchedule({
+      numSegments: obj.numSegments,
+      segments: obj.segments.map(
+        (item) => types.EmissionSegment.fromJSON(item)
+      ),
+    })
+  }
+}
@@ -0,0 +1,93 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface EpochRecordFields {
+  snapshot: types.EpochSnapshotFields
+}
+
+export interface EpochRecordJSON {
+  snapshot: types.EpochSnapshotJSON
+}
+
+/**
+ * Permanent copy of an epoch boundary, written when the epoch is advanced
+ * Lets gauges apply queued votes after the boundary left the config's epoch history
+ */
+export class EpochRecord {
+  readonly snapshot: types.EpochSnapshot
+
+  static readonly discriminator = Buffer.from([
+    193, 72, 26, 28, 161, 98, 58, 132,
+  ])
+
+  static readonly layout = borsh.struct([
+    types.EpochSnapshot.layout("snapshot"),
+  ])
+
+  constructor(fields: EpochRecordFields) {
+    this.snapshot = new types.EpochSnapshot({ ...fields.snapshot })
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<EpochRecord | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<EpochRecord | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): EpochRecord {
+    if (!data.slice(0, 8).equals(EpochRecord.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = EpochRecord.layout.decode(data.slice(8))
+
+    return new EpochRecord({
+      snapshot: types.EpochSnapshot.fromDecoded(dec.snapshot),
+    })
+  }
+
+  toJSON(): EpochRecordJSON {
+    return {
+      snapshot: this.snapshot.toJSON(),
+    }
+  }
+
+  static fromJSON(obj: EpochRecordJSON): EpochRecord {
+    return new EpochRecord({
+      snapshot: types.EpochSnapshot.fromJSON(obj.snapshot),
+    })
+  }
+}
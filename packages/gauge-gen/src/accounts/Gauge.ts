@@ -16,6 +16,26 @@ export interface GaugeFields {
   lastSeenGlobalIndex: types.NumberRawFields
   /** Lifetime total RAY emitted by this gauge */
   totalRayEmitted: BN
+  /**
+   * Maximum share of the global emission this gauge may receive, in bps
+   * 0 falls back to the global cap
+   */
+  maxVoteShareBps: number
+  /** The snapshot of the global lifetime emission when the pool was last updated */
+  lastSeenTotalEmission: types.NumberRawFields
+  /** Listing status set by the admin */
+  status: types.GaugeStatusKind
+  /** Change to `total_votes` queued in epoch mode */
+  pendingVotesDelta: BN
+  /** Epoch at whose start the queued votes take effect */
+  pendingEpoch: BN
+  /** Total votes in effect during recent epochs, for bribe claims */
+  voteHistory: types.VoteHistoryFields
+  /**
+   * Slot of the time-tracker reward in the CLMM pool's reward infos
+   * Only set for CL gauges
+   */
+  timeTrackerRewardIndex: number
 }
 
 export interface GaugeJSON {
@@ -30,6 +50,26 @@ export interface GaugeJSON {
   lastSeenGlobalIndex: types.NumberRawJSON
   /** Lifetime total RAY emitted by this gauge */
   totalRayEmitted: string
+  /**
+   * Maximum share of the global emission this gauge may receive, in bps
+   * 0 falls back to the global cap
+   */
+  maxVoteShareBps: number
+  /** The snapshot of the global lifetime emission when the pool was last updated */
+  lastSeenTotalEmission: types.NumberRawJSON
+  /** Listing status set by the admin */
+  status: types.GaugeStatusJSON
+  /** Change to `total_votes` queued in epoch mode */
+  pendingVotesDelta: string
+  /** Epoch at whose start the queued votes take effect */
+  pendingEpoch: string
+  /** Total votes in effect during recent epochs, for bribe claims */
+  voteHistory: types.VoteHistoryJSON
+  /**
+   * Slot of the time-tracker reward in the CLMM pool's reward infos
+   * Only set for CL gauges
+   */
+  timeTrackerRewardIndex: number
 }
 
 /** The specific gauge for a pool */
@@ -45,6 +85,26 @@ export class Gauge {
   readonly lastSeenGlobalIndex: types.NumberRaw
   /** Lifetime total RAY emitted by this gauge */
   readonly totalRayEmitted: BN
+  /**
+   * Maximum share of the global emission this gauge may receive, in bps
+   * 0 falls back to the global cap
+   */
+  readonly maxVoteShareBps: number
+  /** The snapshot of the global lifetime emission when the pool was last updated */
+  readonly lastSeenTotalEmission: types.NumberRaw
+  /** Listing status set by the admin */
+  readonly status: types.GaugeStatusKind
+  /** Change to `total_votes` queued in epoch mode */
+  readonly pendingVotesDelta: BN
+  /** Epoch at whose start the queued votes take effect */
+  readonly pendingEpoch: BN
+  /** Total votes in effect during recent epochs, for bribe claims */
+  readonly voteHistory: types.VoteHistory
+  /**
+   * Slot of the time-tracker reward in the CLMM pool's reward infos
+   * Only set for CL gauges
+   */
+  readonly timeTrackerRewardIndex: number
 
   static readonly discriminator = Buffer.from([
     9, 19, 249, 189, 158, 171, 226, 205,
@@ -55,6 +115,13 @@ export class Gauge {
     borsh.u64("totalVotes"),
     types.NumberRaw.layout("lastSeenGlobalIndex"),
     borsh.u64("totalRayEmitted"),
+    borsh.u16("maxVoteShareBps"),
+    types.NumberRaw.layout("lastSeenTotalEmission"),
+    types.GaugeStatus.layout("status"),
+    borsh.i64("pendingVotesDelta"),
+    borsh.u64("pendingEpoch"),
+    types.VoteHistory.layout("voteHistory"),
+    borsh.u8("timeTrackerRewardIndex"),
   ])
 
   constructor(fields: GaugeFields) {
@@ -64,6 +131,15 @@ export class Gauge {
       ...fields.lastSeenGlobalIndex,
     })
     this.totalRayEmitted = fields.totalRayEmitted
+    this.maxVoteShareBps = fields.maxVoteShareBps
+    this.lastSeenTotalEmission = new types.NumberRaw({
+      ...fields.lastSeenTotalEmission,
+    })
+    this.status = fields.status
+    this.pendingVotesDelta = fields.pendingVotesDelta
+    this.pendingEpoch = fields.pendingEpoch
+    this.voteHistory = new types.VoteHistory({ ...fields.voteHistory })
+    this.timeTrackerRewardIndex = fields.timeTrackerRewardIndex
   }
 
   static async fetch(
@@ -114,6 +190,15 @@ export class Gauge {
       totalVotes: dec.totalVotes,
       lastSeenGlobalIndex: types.NumberRaw.fromDecoded(dec.lastSeenGlobalIndex),
       totalRayEmitted: dec.totalRayEmitted,
+      maxVoteShareBps: dec.maxVoteShareBps,
+      lastSeenTotalEmission: types.NumberRaw.fromDecoded(
+        dec.lastSeenTotalEmission
+      ),
+      status: types.GaugeStatus.fromDecoded(dec.status),
+      pendingVotesDelta: dec.pendingVotesDelta,
+      pendingEpoch: dec.pendingEpoch,
+      voteHistory: types.VoteHistory.fromDecoded(dec.voteHistory),
+      timeTrackerRewardIndex: dec.timeTrackerRewardIndex,
     })
   }
 
@@ -123,6 +208,13 @@ export class Gauge {
       totalVotes: this.totalVotes.toString(),
       lastSeenGlobalIndex: this.lastSeenGlobalIndex.toJSON(),
       totalRayEmitted: this.totalRayEmitted.toString(),
+      maxVoteShareBps: this.maxVoteShareBps,
+      lastSeenTotalEmission: this.lastSeenTotalEmission.toJSON(),
+      status: this.status.toJSON(),
+      pendingVotesDelta: this.pendingVotesDelta.toString(),
+      pendingEpoch: this.pendingEpoch.toString(),
+      voteHistory: this.voteHistory.toJSON(),
+      timeTrackerRewardIndex: this.timeTrackerRewardIndex,
     }
   }
 
@@ -132,6 +224,15 @@ export class Gauge {
       totalVotes: new BN(obj.totalVotes),
       lastSeenGlobalIndex: types.NumberRaw.fromJSON(obj.lastSeenGlobalIndex),
       totalRayEmitted: new BN(obj.totalRayEmitted),
+      maxVoteShareBps: obj.maxVoteShareBps,
+      lastSeenTotalEmission: types.NumberRaw.fromJSON(
+        obj.lastSeenTotalEmission
+      ),
+      status: types.GaugeStatus.fromJSON(obj.status),
+      pendingVotesDelta: new BN(obj.pendingVotesDelta),
+      pendingEpoch: new BN(obj.pendingEpoch),
+      voteHistory: types.VoteHistory.fromJSON(obj.voteHistory),
+      timeTrackerRewardIndex: obj.timeTrackerRewardIndex,
     })
   }
 }
@@ -7,7 +7,10 @@ import { PROGRAM_ID } from "../programId"
 export interface GaugeConfigFields {
   /** Global token account hopper for RAY tokens to emit */
   rayHopper: PublicKey
-  /** Total RAY emission per day */
+  /**
+   * Total RAY emission per day
+   * Applies until the first segment of the emission schedule starts
+   */
   rayEmissionPerDay: BN
   /** Total votes across all gauges */
   totalVotes: BN
@@ -19,12 +22,58 @@ export interface GaugeConfigFields {
   /** Time since last update */
   lastUpdatedTs: BN
   bump: Array<number>
+  /** Authority for admin-gated instructions */
+  admin: PublicKey
+  /** Admin proposed by the current admin, pending acceptance */
+  pendingAdmin: PublicKey
+  /** Bitmask of paused actions */
+  pauseFlags: number
+  /** RAY funded into the hopper that has not been emitted through the index yet */
+  remainingBudget: BN
+  /** Schedule of emission rates over time */
+  emissionSchedule: PublicKey
+  /**
+   * Maximum share of the emission any gauge may receive, in bps
+   * 0 means gauges are uncapped unless they have their own cap
+   */
+  maxVoteShareBps: number
+  /** Lifetime RAY emitted through the index, before share caps */
+  totalEmission: types.NumberRawFields
+  /**
+   * Length of a voting epoch in seconds
+   * 0 means epoch mode is off, and vote changes apply immediately
+   */
+  epochLength: BN
+  /** Current voting epoch */
+  currentEpoch: BN
+  /**
+   * Timestamp at which the current epoch ends
+   * The index does not move past it until the epoch is advanced
+   */
+  epochEndTs: BN
+  /** Change to `total_votes` queued for the start of the next epoch */
+  pendingVotesDelta: BN
+  /** Index snapshots at recent epoch boundaries, keyed by `epoch % EPOCH_HISTORY_LEN` */
+  epochHistory: Array<types.EpochSnapshotFields>
+  /**
+   * Mint of the CLMM reward that tracks the time units of CL positions
+   * The default key means it is not set yet
+   */
+  clTimeTrackerMint: PublicKey
+  /**
+   * Recent changes of `max_vote_share_bps`, oldest first
+   * Gauges syncing across a change apply the old cap up to it
+   */
+  voteShareCapHistory: Array<types.VoteShareCapChangeFields>
 }
 
 export interface GaugeConfigJSON {
   /** Global token account hopper for RAY tokens to emit */
   rayHopper: string
-  /** Total RAY emission per day */
+  /**
+   * Total RAY emission per day
+   * Applies until the first segment of the emission schedule starts
+   */
   rayEmissionPerDay: string
   /** Total votes across all gauges */
   totalVotes: string
@@ -36,13 +85,59 @@ export interface GaugeConfigJSON {
   /** Time since last update */
   lastUpdatedTs: string
   bump: Array<number>
+  /** Authority for admin-gated instructions */
+  admin: string
+  /** Admin proposed by the current admin, pending acceptance */
+  pendingAdmin: string
+  /** Bitmask of paused actions */
+  pauseFlags: number
+  /** RAY funded into the hopper that has not been emitted through the index yet */
+  remainingBudget: string
+  /** Schedule of emission rates over time */
+  emissionSchedule: string
+  /**
+   * Maximum share of the emission any gauge may receive, in bps
+   * 0 means gauges are uncapped unless they have their own cap
+   */
+  maxVoteShareBps: number
+  /** Lifetime RAY emitted through the index, before share caps */
+  totalEmission: types.NumberRawJSON
+  /**
+   * Length of a voting epoch in seconds
+   * 0 means epoch mode is off, and vote changes apply immediately
+   */
+  epochLength: string
+  /** Current voting epoch */
+  currentEpoch: string
+  /**
+   * Timestamp at which the current epoch ends
+   * The index does not move past it until the epoch is advanced
+   */
+  epochEndTs: string
+  /** Change to `total_votes` queued for the start of the next epoch */
+  pendingVotesDelta: string
+  /** Index snapshots at recent epoch boundaries, keyed by `epoch % EPOCH_HISTORY_LEN` */
+  epochHistory: Array<types.EpochSnapshotJSON>
+  /**
+   * Mint of the CLMM reward that tracks the time units of CL positions
+   * The default key means it is not set yet
+   */
+  clTimeTrackerMint: string
+  /**
+   * Recent changes of `max_vote_share_bps`, oldest first
+   * Gauges syncing across a change apply the old cap up to it
+   */
+  voteShareCapHistory: Array<types.VoteShareCapChangeJSON>
 }
 
 /** Global config for all pool connected pool gauges */
 export class GaugeConfig {
   /** Global token account hopper for RAY tokens to emit */
   readonly rayHopper: PublicKey
-  /** Total RAY emission per day */
+  /**
+   * Total RAY emission per day
+   * Applies until the first segment of the emission schedule starts
+   */
   readonly rayEmissionPerDay: BN
   /** Total votes across all gauges */
   readonly totalVotes: BN
@@ -54,6 +149,49 @@ export class GaugeConfig {
   /** Time since last update */
   readonly lastUpdatedTs: BN
   readonly bump: Array<number>
+  /** Authority for admin-gated instructions */
+  readonly admin: PublicKey
+  /** Admin proposed by the current admin, pending acceptance */
+  readonly pendingAdmin: PublicKey
+  /** Bitmask of paused actions */
+  readonly pauseFlags: number
+  /** RAY funded into the hopper that has not been emitted through the index yet */
+  readonly remainingBudget: BN
+  /** Schedule of emission rates over time */
+  readonly emissionSchedule: PublicKey
+  /**
+   * Maximum share of the emission any gauge may receive, in bps
+   * 0 means gauges are uncapped unless they have their own cap
+   */
+  readonly maxVoteShareBps: number
+  /** Lifetime RAY emitted through the index, before share caps */
+  readonly totalEmission: types.NumberRaw
+  /**
+   * Length of a voting epoch in seconds
+   * 0 means epoch mode is off, and vote changes apply immediately
+   */
+  readonly epochLength: BN
+  /** Current voting epoch */
+  readonly currentEpoch: BN
+  /**
+   * Timestamp at which the current epoch ends
+   * The index does not move past it until the epoch is advanced
+   */
+  readonly epochEndTs: BN
+  /** Change to `total_votes` queued for the start of the next epoch */
+  readonly pendingVotesDelta: BN
+  /** Index snapshots at recent epoch boundaries, keyed by `epoch % EPOCH_HISTORY_LEN` */
+  readonly epochHistory: Array<types.EpochSnapshot>
+  /**
+   * Mint of the CLMM reward that tracks the time units of CL positions
+   * The default key means it is not set yet
+   */
+  readonly clTimeTrackerMint: PublicKey
+  /**
+   * Recent changes of `max_vote_share_bps`, oldest first
+   * Gauges syncing across a change apply the old cap up to it
+   */
+  readonly voteShareCapHistory: Array<types.VoteShareCapChange>
 
   static readonly discriminator = Buffer.from([
     162, 113, 190, 180, 118, 167, 38, 30,
@@ -66,6 +204,20 @@ export class GaugeConfig {
     types.NumberRaw.layout("index"),
     borsh.u64("lastUpdatedTs"),
     borsh.array(borsh.u8(), 1, "bump"),
+    borsh.publicKey("admin"),
+    borsh.publicKey("pendingAdmin"),
+    borsh.u8("pauseFlags"),
+    borsh.u64("remainingBudget"),
+    borsh.publicKey("emissionSchedule"),
+    borsh.u16("maxVoteShareBps"),
+    types.NumberRaw.layout("totalEmission"),
+    borsh.u64("epochLength"),
+    borsh.u64("currentEpoch"),
+    borsh.u64("epochEndTs"),
+    borsh.i64("pendingVotesDelta"),
+    borsh.array(types.EpochSnapshot.layout(), 8, "epochHistory"),
+    borsh.publicKey("clTimeTrackerMint"),
+    borsh.array(types.VoteShareCapChange.layout(), 4, "voteShareCapHistory"),
   ])
 
   constructor(fields: GaugeConfigFields) {
@@ -75,6 +227,24 @@ export class GaugeConfig {
     this.index = new types.NumberRaw({ ...fields.index })
     this.lastUpdatedTs = fields.lastUpdatedTs
     this.bump = fields.bump
+    this.admin = fields.admin
+    this.pendingAdmin = fields.pendingAdmin
+    this.pauseFlags = fields.pauseFlags
+    this.remainingBudget = fields.remainingBudget
+    this.emissionSchedule = fields.emissionSchedule
+    this.maxVoteShareBps = fields.maxVoteShareBps
+    this.totalEmission = new types.NumberRaw({ ...fields.totalEmission })
+    this.epochLength = fields.epochLength
+    this.currentEpoch = fields.currentEpoch
+    this.epochEndTs = fields.epochEndTs
+    this.pendingVotesDelta = fields.pendingVotesDelta
+    this.epochHistory = fields.epochHistory.map(
+      (item) => new types.EpochSnapshot({ ...item })
+    )
+    this.clTimeTrackerMint = fields.clTimeTrackerMint
+    this.voteShareCapHistory = fields.voteShareCapHistory.map(
+      (item) => new types.VoteShareCapChange({ ...item })
+    )
   }
 
   static async fetch(
@@ -127,6 +297,28 @@ export class GaugeConfig {
       index: types.NumberRaw.fromDecoded(dec.index),
       lastUpdatedTs: dec.lastUpdatedTs,
       bump: dec.bump,
+      admin: dec.admin,
+      pendingAdmin: dec.pendingAdmin,
+      pauseFlags: dec.pauseFlags,
+      remainingBudget: dec.remainingBudget,
+      emissionSchedule: dec.emissionSchedule,
+      maxVoteShareBps: dec.maxVoteShareBps,
+      totalEmission: types.NumberRaw.fromDecoded(dec.totalEmission),
+      epochLength: dec.epochLength,
+      currentEpoch: dec.currentEpoch,
+      epochEndTs: dec.epochEndTs,
+      pendingVotesDelta: dec.pendingVotesDelta,
+      epochHistory: dec.epochHistory.map(
+        (
+          item: any /* eslint-disable-line @typescript-eslint/no-explicit-any */
+        ) => types.EpochSnapshot.fromDecoded(item)
+      ),
+      clTimeTrackerMint: dec.clTimeTrackerMint,
+      voteShareCapHistory: dec.voteShareCapHistory.map(
+        (
+          item: any /* eslint-disable-line @typescript-eslint/no-explicit-any */
+        ) => types.VoteShareCapChange.fromDecoded(item)
+      ),
     })
   }
 
@@ -138,6 +330,22 @@ export class GaugeConfig {
       index: this.index.toJSON(),
       lastUpdatedTs: this.lastUpdatedTs.toString(),
       bump: this.bump,
+      admin: this.admin.toString(),
+      pendingAdmin: this.pendingAdmin.toString(),
+      pauseFlags: this.pauseFlags,
+      remainingBudget: this.remainingBudget.toString(),
+      emissionSchedule: this.emissionSchedule.toString(),
+      maxVoteShareBps: this.maxVoteShareBps,
+      totalEmission: this.totalEmission.toJSON(),
+      epochLength: this.epochLength.toString(),
+      currentEpoch: this.currentEpoch.toString(),
+      epochEndTs: this.epochEndTs.toString(),
+      pendingVotesDelta: this.pendingVotesDelta.toString(),
+      epochHistory: this.epochHistory.map((item) => item.toJSON()),
+      clTimeTrackerMint: this.clTimeTrackerMint.toString(),
+      voteShareCapHistory: this.voteShareCapHistory.map(
+        (item) => item.toJSON()
+      ),
     }
   }
 
@@ -149,6 +357,24 @@ export class GaugeConfig {
       index: types.NumberRaw.fromJSON(obj.index),
       lastUpdatedTs: new BN(obj.lastUpdatedTs),
       bump: obj.bump,
+      admin: new PublicKey(obj.admin),
+      pendingAdmin: new PublicKey(obj.pendingAdmin),
+      pauseFlags: obj.pauseFlags,
+      remainingBudget: new BN(obj.remainingBudget),
+      emissionSchedule: new PublicKey(obj.emissionSchedule),
+      maxVoteShareBps: obj.maxVoteShareBps,
+      totalEmission: types.NumberRaw.fromJSON(obj.totalEmission),
+      epochLength: new BN(obj.epochLength),
+      currentEpoch: new BN(obj.currentEpoch),
+      epochEndTs: new BN(obj.epochEndTs),
+      pendingVotesDelta: new BN(obj.pendingVotesDelta),
+      epochHistory: obj.epochHistory.map(
+        (item) => types.EpochSnapshot.fromJSON(item)
+      ),
+      clTimeTrackerMint: new PublicKey(obj.clTimeTrackerMint),
+      voteShareCapHistory: obj.voteShareCapHistory.map(
+        (item) => types.VoteShareCapChange.fromJSON(item)
+      ),
     })
   }
 }
@@ -11,6 +11,8 @@ export interface PersonalGaugeFields {
   poolGauge: PublicKey
   /** Amount of votes pledged to a gauge */
   votes: BN
+  /** Votes in effect during recent epochs, for bribe claims */
+  voteHistory: types.VoteHistoryFields
 }
 
 export interface PersonalGaugeJSON {
@@ -20,6 +22,8 @@ export interface PersonalGaugeJSON {
   poolGauge: string
   /** Amount of votes pledged to a gauge */
   votes: string
+  /** Votes in effect during recent epochs, for bribe claims */
+  voteHistory: types.VoteHistoryJSON
 }
 
 /** Account that tracks the number of votes on a given gauge for a specific user */
@@ -30,6 +34,8 @@ export class PersonalGauge {
   readonly poolGauge: PublicKey
   /** Amount of votes pledged to a gauge */
   readonly votes: BN
+  /** Votes in effect during recent epochs, for bribe claims */
+  readonly voteHistory: types.VoteHistory
 
   static readonly discriminator = Buffer.from([
     164, 176, 49, 114, 107, 47, 171, 133,
@@ -39,12 +45,14 @@ export class PersonalGauge {
     borsh.publicKey("owner"),
     borsh.publicKey("poolGauge"),
     borsh.u64("votes"),
+    types.VoteHistory.layout("voteHistory"),
   ])
 
   constructor(fields: PersonalGaugeFields) {
     this.owner = fields.owner
     this.poolGauge = fields.poolGauge
     this.votes = fields.votes
+    this.voteHistory = new types.VoteHistory({ ...fields.voteHistory })
   }
 
   static async fetch(
@@ -94,6 +102,7 @@ export class PersonalGauge {
       owner: dec.owner,
       poolGauge: dec.poolGauge,
       votes: dec.votes,
+      voteHistory: types.VoteHistory.fromDecoded(dec.voteHistory),
     })
   }
 
@@ -102,6 +111,7 @@ export class PersonalGauge {
       owner: this.owner.toString(),
       poolGauge: this.poolGauge.toString(),
       votes: this.votes.toString(),
+      voteHistory: this.voteHistory.toJSON(),
     }
   }
 
@@ -110,6 +120,7 @@ export class PersonalGauge {
       owner: new PublicKey(obj.owner),
       poolGauge: new PublicKey(obj.poolGauge),
       votes: new BN(obj.votes),
+      voteHistory: types.VoteHistory.fromJSON(obj.voteHistory),
     })
   }
 }
@@ -0,0 +1,123 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface VoteLedgerFields {
+  /** Reactor whose votes are listed */
+  reactor: PublicKey
+  numEntries: number
+  /** Gauges with votes, the first `num_entries` are in use */
+  entries: Array<types.VoteLedgerEntryFields>
+}
+
+export interface VoteLedgerJSON {
+  /** Reactor whose votes are listed */
+  reactor: string
+  numEntries: number
+  /** Gauges with votes, the first `num_entries` are in use */
+  entries: Array<types.VoteLedgerEntryJSON>
+}
+
+/**
+ * Optional listing of where a reactor's locked votes sit, kept in sync by every vote change
+ * Lets UIs and governance programs read a single account instead of scanning personal gauges
+ * Lists at most `MAX_VOTE_LEDGER_ENTRIES` gauges: while it exists, votes cannot be pledged to more
+ * gauges at once, the ledger must be closed to vote on more
+ */
+export class VoteLedger {
+  /** Reactor whose votes are listed */
+  readonly reactor: PublicKey
+  readonly numEntries: number
+  /** Gauges with votes, the first `num_entries` are in use */
+  readonly entries: Array<types.VoteLedgerEntry>
+
+  static readonly discriminator = Buffer.from([
+    205, 83, 23, 87, 81, 197, 179, 134,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.publicKey("reactor"),
+    borsh.u8("numEntries"),
+    borsh.array(types.VoteLedgerEntry.layout(), 16, "entries"),
+  ])
+
+  constructor(fields: VoteLedgerFields) {
+    this.reactor = fields.reactor
+    this.numEntries = fields.numEntries
+    this.entries = fields.entries.map(
+      (item) => new types.VoteLedgerEntry({ ...item })
+    )
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<VoteLedger | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<VoteLedger | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): VoteLedger {
+    if (!data.slice(0, 8).equals(VoteLedger.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = VoteLedger.layout.decode(data.slice(8))
+
+    return new VoteLedger({
+      reactor: dec.reactor,
+      numEntries: dec.numEntries,
+      entries: dec.entries.map(
+        (
+          item: any /* eslint-disable-line @typescript-eslint/no-explicit-any */
+        ) => types.VoteLedgerEntry.fromDecoded(item)
+      ),
+    })
+  }
+
+  toJSON(): VoteLedgerJSON {
+    return {
+      reactor: this.reactor.toString(),
+      numEntries: this.numEntries,
+      entries: this.entries.map((item) => item.toJSON()),
+    }
+  }
+
+  static fromJSON(obj: VoteLedgerJSON): VoteLedger {
+    return new VoteLedger({
+      reactor: new PublicKey(obj.reactor),
+      numEntries: obj.numEntries,
+      entries: obj.entries.map((item) => types.VoteLedgerEntry.fromJSON(item)),
+    })
+  }
+}
@@ -1,3 +1,14 @@
+export { Bribe } from "./Bribe"
+export type { BribeFields, BribeJSON } from "./Bribe"
+export { BribeClaim } from "./BribeClaim"
+export type { BribeClaimFields, BribeClaimJSON } from "./BribeClaim"
+export { EmissionSchedule } from "./EmissionSchedule"
+export type {
+  EmissionScheduleFields,
+  EmissionScheduleJSON,
+} from "./EmissionSchedule"
+export { EpochRecord } from "./EpochRecord"
+export type { EpochRecordFields, EpochRecordJSON } from "./EpochRecord"
 export { GaugeConfig } from "./GaugeConfig"
 export type { GaugeConfigFields, GaugeConfigJSON } from "./GaugeConfig"
 export { Gauge } from "./Gauge"
@@ -14,3 +25,5 @@ export type {
   PersonalRewarderCpFields,
   PersonalRewarderCpJSON,
 } from "./PersonalRewarderCp"
+export { VoteLedger } from "./VoteLedger"
+export type { VoteLedgerFields, VoteLedgerJSON } from "./VoteLedger"
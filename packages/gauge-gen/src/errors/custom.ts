@@ -0,0 +1,474 @@
+export type CustomError =
+  | NotAdmin
+  | NotPendingAdmin
+  | InvalidPauseFlags
+  | VotesPaused
+  | ClaimsPaused
+  | InvalidEmissionSchedule
+  | GaugeNotDelegated
+  | InvalidVoteShareCap
+  | GaugeNotWhitelisted
+  | InvalidEpochLength
+  | EpochModeOff
+  | EpochNotEnded
+  | BribeClosed
+  | BribeNotClaimable
+  | BribeNotExpired
+  | BribeVotesUnknown
+  | InvalidRolloverBribe
+  | InvalidReallocation
+  | DuplicateGauge
+  | InsufficientFreeVotes
+  | PersonalGaugeNotEmpty
+  | RewarderNotEmpty
+  | VoteLedgerFull
+  | VoteLedgerIncomplete
+  | ClTimeTrackerRewardMissing
+  | ClTimeTrackerMintAlreadySet
+  | ClTimeTrackerRewardMisconfigured
+  | NotCpSwapPool
+  | NotVoteAuthority
+  | DecayNotProRata
+  | NoDueVotes
+  | EpochRecordRequired
+
+export class NotAdmin extends Error {
+  static readonly code = 6000
+  readonly code = 6000
+  readonly name = "NotAdmin"
+  readonly msg = "Not admin"
+
+  constructor(readonly logs?: string[]) {
+    super("6000: Not admin")
+  }
+}
+
+export class NotPendingAdmin extends Error {
+  static readonly code = 6001
+  readonly code = 6001
+  readonly name = "NotPendingAdmin"
+  readonly msg = "Not pending admin"
+
+  constructor(readonly logs?: string[]) {
+    super("6001: Not pending admin")
+  }
+}
+
+export class InvalidPauseFlags extends Error {
+  static readonly code = 6002
+  readonly code = 6002
+  readonly name = "InvalidPauseFlags"
+  readonly msg = "Unknown pause flags"
+
+  constructor(readonly logs?: string[]) {
+    super("6002: Unknown pause flags")
+  }
+}
+
+export class VotesPaused extends Error {
+  static readonly code = 6003
+  readonly code = 6003
+  readonly name = "VotesPaused"
+  readonly msg = "Pledging votes is paused"
+
+  constructor(readonly logs?: string[]) {
+    super("6003: Pledging votes is paused")
+  }
+}
+
+export class ClaimsPaused extends Error {
+  static readonly code = 6004
+  readonly code = 6004
+  readonly name = "ClaimsPaused"
+  readonly msg = "Reward claims are paused"
+
+  constructor(readonly logs?: string[]) {
+    super("6004: Reward claims are paused")
+  }
+}
+
+export class InvalidEmissionSchedule extends Error {
+  static readonly code = 6005
+  readonly code = 6005
+  readonly name = "InvalidEmissionSchedule"
+  readonly msg =
+    "Emission schedule has too many segments or is not sorted by start time"
+
+  constructor(readonly logs?: string[]) {
+    super(
+      "6005: Emission schedule has too many segments or is not sorted by start time"
+    )
+  }
+}
+
+export class GaugeNotDelegated extends Error {
+  static readonly code = 6006
+  readonly code = 6006
+  readonly name = "GaugeNotDelegated"
+  readonly msg = "Gauge is not in the vote delegation allow-list"
+
+  constructor(readonly logs?: string[]) {
+    super("6006: Gauge is not in the vote delegation allow-list")
+  }
+}
+
+export class InvalidVoteShareCap extends Error {
+  static readonly code = 6007
+  readonly code = 6007
+  readonly name = "InvalidVoteShareCap"
+  readonly msg = "Vote share cap cannot exceed 100%"
+
+  constructor(readonly logs?: string[]) {
+    super("6007: Vote share cap cannot exceed 100%")
+  }
+}
+
+export class GaugeNotWhitelisted extends Error {
+  static readonly code = 6008
+  readonly code = 6008
+  readonly name = "GaugeNotWhitelisted"
+  readonly msg = "Gauge is not whitelisted"
+
+  constructor(readonly logs?: string[]) {
+    super("6008: Gauge is not whitelisted")
+  }
+}
+
+export class InvalidEpochLength extends Error {
+  static readonly code = 6009
+  readonly code = 6009
+  readonly name = "InvalidEpochLength"
+  readonly msg = "Epoch length must be positive"
+
+  constructor(readonly logs?: string[]) {
+    super("6009: Epoch length must be positive")
+  }
+}
+
+export class EpochModeOff extends Error {
+  static readonly code = 6010
+  readonly code = 6010
+  readonly name = "EpochModeOff"
+  readonly msg = "Epoch mode is off"
+
+  constructor(readonly logs?: string[]) {
+    super("6010: Epoch mode is off")
+  }
+}
+
+export class EpochNotEnded extends Error {
+  static readonly code = 6011
+  readonly code = 6011
+  readonly name = "EpochNotEnded"
+  readonly msg = "Current epoch has not ended"
+
+  constructor(readonly logs?: string[]) {
+    super("6011: Current epoch has not ended")
+  }
+}
+
+export class BribeClosed extends Error {
+  static readonly code = 6012
+  readonly code = 6012
+  readonly name = "BribeClosed"
+  readonly msg = "Bribed epoch has ended"
+
+  constructor(readonly logs?: string[]) {
+    super("6012: Bribed epoch has ended")
+  }
+}
+
+export class BribeNotClaimable extends Error {
+  static readonly code = 6013
+  readonly code = 6013
+  readonly name = "BribeNotClaimable"
+  readonly msg = "Bribe is not claimable in this epoch"
+
+  constructor(readonly logs?: string[]) {
+    super("6013: Bribe is not claimable in this epoch")
+  }
+}
+
+export class BribeNotExpired extends Error {
+  static readonly code = 6014
+  readonly code = 6014
+  readonly name = "BribeNotExpired"
+  readonly msg = "Bribe claim window has not ended"
+
+  constructor(readonly logs?: string[]) {
+    super("6014: Bribe claim window has not ended")
+  }
+}
+
+export class BribeVotesUnknown extends Error {
+  static readonly code = 6015
+  readonly code = 6015
+  readonly name = "BribeVotesUnknown"
+  readonly msg = "Votes of the bribed epoch are no longer remembered"
+
+  constructor(readonly logs?: string[]) {
+    super("6015: Votes of the bribed epoch are no longer remembered")
+  }
+}
+
+export class InvalidRolloverBribe extends Error {
+  static readonly code = 6016
+  readonly code = 6016
+  readonly name = "InvalidRolloverBribe"
+  readonly msg =
+    "Rollover target must be a bribe of the same gauge, mint and creator"
+
+  constructor(readonly logs?: string[]) {
+    super(
+      "6016: Rollover target must be a bribe of the same gauge, mint and creator"
+    )
+  }
+}
+
+export class InvalidReallocation extends Error {
+  static readonly code = 6017
+  readonly code = 6017
+  readonly name = "InvalidReallocation"
+  readonly msg =
+    "Remaining accounts must be a (pool gauge, personal gauge) pair per vote delta"
+
+  constructor(readonly logs?: string[]) {
+    super(
+      "6017: Remaining accounts must be a (pool gauge, personal gauge) pair per vote delta"
+    )
+  }
+}
+
+export class DuplicateGauge extends Error {
+  static readonly code = 6018
+  readonly code = 6018
+  readonly name = "DuplicateGauge"
+  readonly msg = "Gauge appears more than once"
+
+  constructor(readonly logs?: string[]) {
+    super("6018: Gauge appears more than once")
+  }
+}
+
+export class InsufficientFreeVotes extends Error {
+  static readonly code = 6019
+  readonly code = 6019
+  readonly name = "InsufficientFreeVotes"
+  readonly msg = "Net vote change exceeds the free votes"
+
+  constructor(readonly logs?: string[]) {
+    super("6019: Net vote change exceeds the free votes")
+  }
+}
+
+export class PersonalGaugeNotEmpty extends Error {
+  static readonly code = 6020
+  readonly code = 6020
+  readonly name = "PersonalGaugeNotEmpty"
+  readonly msg = "Personal gauge still holds votes"
+
+  constructor(readonly logs?: string[]) {
+    super("6020: Personal gauge still holds votes")
+  }
+}
+
+export class RewarderNotEmpty extends Error {
+  static readonly code = 6021
+  readonly code = 6021
+  readonly name = "RewarderNotEmpty"
+  readonly msg = "Personal rewarder still holds staged RAY"
+
+  constructor(readonly logs?: string[]) {
+    super("6021: Personal rewarder still holds staged RAY")
+  }
+}
+
+export class VoteLedgerFull extends Error {
+  static readonly code = 6022
+  readonly code = 6022
+  readonly name = "VoteLedgerFull"
+  readonly msg = "Vote ledger cannot list more gauges"
+
+  constructor(readonly logs?: string[]) {
+    super("6022: Vote ledger cannot list more gauges")
+  }
+}
+
+export class VoteLedgerIncomplete extends Error {
+  static readonly code = 6023
+  readonly code = 6023
+  readonly name = "VoteLedgerIncomplete"
+  readonly msg = "Personal gauges do not add up to the reactor's locked votes"
+
+  constructor(readonly logs?: string[]) {
+    super("6023: Personal gauges do not add up to the reactor's locked votes")
+  }
+}
+
+export class ClTimeTrackerRewardMissing extends Error {
+  static readonly code = 6024
+  readonly code = 6024
+  readonly name = "ClTimeTrackerRewardMissing"
+  readonly msg = "CLMM pool has no time-tracker reward"
+
+  constructor(readonly logs?: string[]) {
+    super("6024: CLMM pool has no time-tracker reward")
+  }
+}
+
+export class ClTimeTrackerMintAlreadySet extends Error {
+  static readonly code = 6025
+  readonly code = 6025
+  readonly name = "ClTimeTrackerMintAlreadySet"
+  readonly msg = "CLMM time-tracker mint is already set"
+
+  constructor(readonly logs?: string[]) {
+    super("6025: CLMM time-tracker mint is already set")
+  }
+}
+
+export class ClTimeTrackerRewardMisconfigured extends Error {
+  static readonly code = 6026
+  readonly code = 6026
+  readonly name = "ClTimeTrackerRewardMisconfigured"
+  readonly msg = "CLMM time-tracker reward must emit one unit per second"
+
+  constructor(readonly logs?: string[]) {
+    super("6026: CLMM time-tracker reward must emit one unit per second")
+  }
+}
+
+export class NotCpSwapPool extends Error {
+  static readonly code = 6027
+  readonly code = 6027
+  readonly name = "NotCpSwapPool"
+  readonly msg =
+    "Pool gauges are only created for CP swap pools, CLMM pool gauges use init_cl_pool_gauge"
+
+  constructor(readonly logs?: string[]) {
+    super(
+      "6027: Pool gauges are only created for CP swap pools, CLMM pool gauges use init_cl_pool_gauge"
+    )
+  }
+}
+
+export class NotVoteAuthority extends Error {
+  static readonly code = 6028
+  readonly code = 6028
+  readonly name = "NotVoteAuthority"
+  readonly msg = "Signer is neither the reactor owner nor its vote delegate"
+
+  constructor(readonly logs?: string[]) {
+    super("6028: Signer is neither the reactor owner nor its vote delegate")
+  }
+}
+
+export class DecayNotProRata extends Error {
+  static readonly code = 6029
+  readonly code = 6029
+  readonly name = "DecayNotProRata"
+  readonly msg =
+    "Without the vote authority, decay is split over every gauge the reactor votes on"
+
+  constructor(readonly logs?: string[]) {
+    super(
+      "6029: Without the vote authority, decay is split over every gauge the reactor votes on"
+    )
+  }
+}
+
+export class NoDueVotes extends Error {
+  static readonly code = 6030
+  readonly code = 6030
+  readonly name = "NoDueVotes"
+  readonly msg = "Gauge has no queued votes due"
+
+  constructor(readonly logs?: string[]) {
+    super("6030: Gauge has no queued votes due")
+  }
+}
+
+export class EpochRecordRequired extends Error {
+  static readonly code = 6031
+  readonly code = 6031
+  readonly name = "EpochRecordRequired"
+  readonly msg =
+    "Queued votes are due at an epoch that left the history, apply its epoch record first"
+
+  constructor(readonly logs?: string[]) {
+    super(
+      "6031: Queued votes are due at an epoch that left the history, apply its epoch record first"
+    )
+  }
+}
+
+export function fromCode(code: number, logs?: string[]): CustomError | null {
+  switch (code) {
+    case 6000:
+      return new NotAdmin(logs)
+    case 6001:
+      return new NotPendingAdmin(logs)
+    case 6002:
+      return new InvalidPauseFlags(logs)
+    case 6003:
+      return new VotesPaused(logs)
+    case 6004:
+      return new ClaimsPaused(logs)
+    case 6005:
+      return new InvalidEmissionSchedule(logs)
+    case 6006:
+      return new GaugeNotDelegated(logs)
+    case 6007:
+      return new InvalidVoteShareCap(logs)
+    case 6008:
+      return new GaugeNotWhitelisted(logs)
+    case 6009:
+      return new InvalidEpochLength(logs)
+    case 6010:
+      return new EpochModeOff(logs)
+    case 6011:
+      return new EpochNotEnded(logs)
+    case 6012:
+      return new BribeClosed(logs)
+    case 6013:
+      return new BribeNotClaimable(logs)
+    case 6014:
+      return new BribeNotExpired(logs)
+    case 6015:
+      return new BribeVotesUnknown(logs)
+    case 6016:
+      return new InvalidRolloverBribe(logs)
+    case 6017:
+      return new InvalidReallocation(logs)
+    case 6018:
+      return new DuplicateGauge(logs)
+    case 6019:
+      return new InsufficientFreeVotes(logs)
+    case 6020:
+      return new PersonalGaugeNotEmpty(logs)
+    case 6021:
+      return new RewarderNotEmpty(logs)
+    case 6022:
+      return new VoteLedgerFull(logs)
+    case 6023:
+      return new VoteLedgerIncomplete(logs)
+    case 6024:
+      return new ClTimeTrackerRewardMissing(logs)
+    case 6025:
+      return new ClTimeTrackerMintAlreadySet(logs)
+    case 6026:
+      return new ClTimeTrackerRewardMisconfigured(logs)
+    case 6027:
+      return new NotCpSwapPool(logs)
+    case 6028:
+      return new NotVoteAuthority(logs)
+    case 6029:
+      return new DecayNotProRata(logs)
+    case 6030:
+      return new NoDueVotes(logs)
+    case 6031:
+      return new EpochRecordRequired(logs)
+  }
+
+  return null
+}
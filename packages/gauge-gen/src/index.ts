@@ -1,4 +1,5 @@
 export * as accounts from "./accounts";
 export * as errors from "./errors";
 export * as instructions from "./instructions";
+export * as types from "./types";
 export * from "./programId";
@@ -0,0 +1,32 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface AdvanceEpochAccounts {
+  payer: PublicKey
+  gaugeConfig: PublicKey
+  emissionSchedule: PublicKey
+  /** Record of the boundary at the start of the next epoch */
+  epochRecord: PublicKey
+  systemProgram: PublicKey
+}
+
+/** End the current voting epoch and apply the queued votes */
+export function advanceEpoch(
+  accounts: AdvanceEpochAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.payer, isSigner: true, isWritable: true },
+    { pubkey: accounts.gaugeConfig, isSigner: false, isWritable: true },
+    { pubkey: accounts.emissionSchedule, isSigner: false, isWritable: false },
+    { pubkey: accounts.epochRecord, isSigner: false, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([93, 138, 234, 218, 241, 230, 132, 38])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
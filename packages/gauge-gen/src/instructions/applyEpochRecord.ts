@@ -0,0 +1,30 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface ApplyEpochRecordAccounts {
+  gaugeConfig: PublicKey
+  emissionSchedule: PublicKey
+  poolGauge: PublicKey
+  /** Record of the boundary at which the queued votes take effect */
+  epochRecord: PublicKey
+}
+
+/** Apply a gauge's queued votes at an epoch boundary that left the epoch history */
+export function applyEpochRecord(
+  accounts: ApplyEpochRecordAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.gaugeConfig, isSigner: false, isWritable: true },
+    { pubkey: accounts.emissionSchedule, isSigner: false, isWritable: false },
+    { pubkey: accounts.poolGauge, isSigner: false, isWritable: true },
+    { pubkey: accounts.epochRecord, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([178, 91, 205, 236, 55, 69, 155, 156])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
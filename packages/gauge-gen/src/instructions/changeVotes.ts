@@ -9,15 +9,22 @@ export interface ChangeVotesArgs {
 }
 
 export interface ChangeVotesAccounts {
-  owner: PublicKey
+  /** Reactor owner, or its delegate when a vote delegation is provided */
+  authority: PublicKey
   /** Global config for Gauge instance */
   gaugeConfig: PublicKey
+  /** Emission schedule for Gauge instance */
+  emissionSchedule: PublicKey
   /** Gauge for the pool */
   poolGauge: PublicKey
-  /** Personal vote account for owner */
+  /** Personal vote account for the reactor owner */
   personalGauge: PublicKey
-  /** Personal reactor account for owner */
+  /** Personal reactor account */
   reactor: PublicKey
+  /** Delegation of the reactor's votes, required when the authority is not the owner */
+  voteDelegation?: PublicKey
+  /** Vote ledger of the reactor, kept in sync when it exists */
+  voteLedger: PublicKey
   reactorProg: PublicKey
   sysvarInstruction: PublicKey
 }
@@ -31,11 +38,16 @@ export function changeVotes(
   programId: PublicKey = PROGRAM_ID
 ) {
   const keys: Array<AccountMeta> = [
-    { pubkey: accounts.owner, isSigner: true, isWritable: true },
+    { pubkey: accounts.authority, isSigner: true, isWritable: true },
     { pubkey: accounts.gaugeConfig, isSigner: false, isWritable: true },
+    { pubkey: accounts.emissionSchedule, isSigner: false, isWritable: false },
     { pubkey: accounts.poolGauge, isSigner: false, isWritable: true },
     { pubkey: accounts.personalGauge, isSigner: false, isWritable: true },
     { pubkey: accounts.reactor, isSigner: false, isWritable: true },
+    accounts.voteDelegation
+      ? { pubkey: accounts.voteDelegation, isSigner: false, isWritable: false }
+      : { pubkey: programId, isSigner: false, isWritable: false },
+    { pubkey: accounts.voteLedger, isSigner: false, isWritable: true },
     { pubkey: accounts.reactorProg, isSigner: false, isWritable: false },
     { pubkey: accounts.sysvarInstruction, isSigner: false, isWritable: false },
   ]
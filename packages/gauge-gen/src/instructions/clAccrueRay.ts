@@ -7,6 +7,7 @@ import { PROGRAM_ID } from "../programId"
 export interface ClAccrueRayAccounts {
   payer: PublicKey
   gaugeConfig: PublicKey
+  emissionSchedule: PublicKey
   personalRewarder: PublicKey
   poolGauge: PublicKey
   poolPosition: PublicKey
@@ -26,6 +27,7 @@ export function clAccrueRay(
   const keys: Array<AccountMeta> = [
     { pubkey: accounts.payer, isSigner: true, isWritable: true },
     { pubkey: accounts.gaugeConfig, isSigner: false, isWritable: true },
+    { pubkey: accounts.emissionSchedule, isSigner: false, isWritable: false },
     { pubkey: accounts.personalRewarder, isSigner: false, isWritable: true },
     { pubkey: accounts.poolGauge, isSigner: false, isWritable: true },
     { pubkey: accounts.poolPosition, isSigner: false, isWritable: true },
@@ -0,0 +1,58 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface ClClosePersonalRewarderAccounts {
+  /** Prove ownership of the position by owning the NFT */
+  nftOwner: PublicKey
+  /** The token account for the NFT */
+  nftAccount: PublicKey
+  gaugeConfig: PublicKey
+  emissionSchedule: PublicKey
+  personalRewarder: PublicKey
+  poolGauge: PublicKey
+  poolPosition: PublicKey
+  poolState: PublicKey
+  /** The protocol position for the CLMM program */
+  protocolPosition: PublicKey
+  tickArrayLowerLoader: PublicKey
+  tickArrayUpperLoader: PublicKey
+  destination: PublicKey
+  clmmProgram: PublicKey
+}
+
+/** CL: Close an empty personal rewarder and refund its rent */
+export function clClosePersonalRewarder(
+  accounts: ClClosePersonalRewarderAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.nftOwner, isSigner: true, isWritable: false },
+    { pubkey: accounts.nftAccount, isSigner: false, isWritable: false },
+    { pubkey: accounts.gaugeConfig, isSigner: false, isWritable: true },
+    { pubkey: accounts.emissionSchedule, isSigner: false, isWritable: false },
+    { pubkey: accounts.personalRewarder, isSigner: false, isWritable: true },
+    { pubkey: accounts.poolGauge, isSigner: false, isWritable: true },
+    { pubkey: accounts.poolPosition, isSigner: false, isWritable: true },
+    { pubkey: accounts.poolState, isSigner: false, isWritable: true },
+    { pubkey: accounts.protocolPosition, isSigner: false, isWritable: true },
+    {
+      pubkey: accounts.tickArrayLowerLoader,
+      isSigner: false,
+      isWritable: true,
+    },
+    {
+      pubkey: accounts.tickArrayUpperLoader,
+      isSigner: false,
+      isWritable: true,
+    },
+    { pubkey: accounts.destination, isSigner: false, isWritable: true },
+    { pubkey: accounts.clmmProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([124, 69, 214, 209, 24, 215, 66, 109])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
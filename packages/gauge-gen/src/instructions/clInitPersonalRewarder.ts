@@ -12,6 +12,7 @@ export interface ClInitPersonalRewarderAccounts {
    * Must be mutable in order to update itself before initializing the personal rewarder
    */
   gaugeConfig: PublicKey
+  emissionSchedule: PublicKey
   /**
    * Global pool gauge for the pool
    * Must be mutable in order to update itself before initializing the personal rewarder
@@ -40,6 +41,7 @@ export function clInitPersonalRewarder(
   const keys: Array<AccountMeta> = [
     { pubkey: accounts.payer, isSigner: true, isWritable: true },
     { pubkey: accounts.gaugeConfig, isSigner: false, isWritable: true },
+    { pubkey: accounts.emissionSchedule, isSigner: false, isWritable: false },
     { pubkey: accounts.poolGauge, isSigner: false, isWritable: true },
     { pubkey: accounts.personalLiqPosition, isSigner: false, isWritable: true },
     { pubkey: accounts.poolState, isSigner: false, isWritable: true },
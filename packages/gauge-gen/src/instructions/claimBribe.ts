@@ -0,0 +1,41 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface ClaimBribeAccounts {
+  owner: PublicKey
+  gaugeConfig: PublicKey
+  poolGauge: PublicKey
+  personalGauge: PublicKey
+  bribe: PublicKey
+  vault: PublicKey
+  dst: PublicKey
+  bribeClaim: PublicKey
+  tokenProgram: PublicKey
+  systemProgram: PublicKey
+}
+
+/** Claim a share of a bribe, pro-rata to the votes during the bribed epoch */
+export function claimBribe(
+  accounts: ClaimBribeAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.owner, isSigner: true, isWritable: true },
+    { pubkey: accounts.gaugeConfig, isSigner: false, isWritable: false },
+    { pubkey: accounts.poolGauge, isSigner: false, isWritable: false },
+    { pubkey: accounts.personalGauge, isSigner: false, isWritable: false },
+    { pubkey: accounts.bribe, isSigner: false, isWritable: true },
+    { pubkey: accounts.vault, isSigner: false, isWritable: true },
+    { pubkey: accounts.dst, isSigner: false, isWritable: true },
+    { pubkey: accounts.bribeClaim, isSigner: false, isWritable: true },
+    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([215, 71, 221, 74, 40, 95, 159, 200])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
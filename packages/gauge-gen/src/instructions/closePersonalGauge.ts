@@ -0,0 +1,27 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface ClosePersonalGaugeAccounts {
+  owner: PublicKey
+  personalGauge: PublicKey
+  destination: PublicKey
+}
+
+/** Close a personal gauge without votes and refund its rent */
+export function closePersonalGauge(
+  accounts: ClosePersonalGaugeAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.owner, isSigner: true, isWritable: false },
+    { pubkey: accounts.personalGauge, isSigner: false, isWritable: true },
+    { pubkey: accounts.destination, isSigner: false, isWritable: true },
+  ]
+  const identifier = Buffer.from([46, 80, 28, 245, 51, 83, 240, 175])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
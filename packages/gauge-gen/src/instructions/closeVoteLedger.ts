@@ -0,0 +1,27 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface CloseVoteLedgerAccounts {
+  owner: PublicKey
+  reactor: PublicKey
+  voteLedger: PublicKey
+}
+
+/** Close the vote ledger of a reactor and refund its rent */
+export function closeVoteLedger(
+  accounts: CloseVoteLedgerAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.owner, isSigner: true, isWritable: true },
+    { pubkey: accounts.reactor, isSigner: false, isWritable: false },
+    { pubkey: accounts.voteLedger, isSigner: false, isWritable: true },
+  ]
+  const identifier = Buffer.from([253, 1, 233, 58, 22, 45, 214, 64])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -7,6 +7,7 @@ import { PROGRAM_ID } from "../programId"
 export interface CpAccrueRayAccounts {
   payer: PublicKey
   gaugeConfig: PublicKey
+  emissionSchedule: PublicKey
   personalRewarder: PublicKey
   poolGauge: PublicKey
   liqPosition: PublicKey
@@ -22,6 +23,7 @@ export function cpAccrueRay(
   const keys: Array<AccountMeta> = [
     { pubkey: accounts.payer, isSigner: true, isWritable: true },
     { pubkey: accounts.gaugeConfig, isSigner: false, isWritable: true },
+    { pubkey: accounts.emissionSchedule, isSigner: false, isWritable: false },
     { pubkey: accounts.personalRewarder, isSigner: false, isWritable: true },
     { pubkey: accounts.poolGauge, isSigner: false, isWritable: true },
     { pubkey: accounts.liqPosition, isSigner: false, isWritable: true },
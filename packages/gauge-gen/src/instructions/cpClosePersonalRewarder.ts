@@ -0,0 +1,39 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface CpClosePersonalRewarderAccounts {
+  owner: PublicKey
+  gaugeConfig: PublicKey
+  emissionSchedule: PublicKey
+  personalRewarder: PublicKey
+  poolGauge: PublicKey
+  liqPosition: PublicKey
+  timeTracker: PublicKey
+  destination: PublicKey
+  cpLpEscrowProgram: PublicKey
+}
+
+/** CP: Close an empty personal rewarder and refund its rent */
+export function cpClosePersonalRewarder(
+  accounts: CpClosePersonalRewarderAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.owner, isSigner: true, isWritable: false },
+    { pubkey: accounts.gaugeConfig, isSigner: false, isWritable: true },
+    { pubkey: accounts.emissionSchedule, isSigner: false, isWritable: false },
+    { pubkey: accounts.personalRewarder, isSigner: false, isWritable: true },
+    { pubkey: accounts.poolGauge, isSigner: false, isWritable: true },
+    { pubkey: accounts.liqPosition, isSigner: false, isWritable: true },
+    { pubkey: accounts.timeTracker, isSigner: false, isWritable: true },
+    { pubkey: accounts.destination, isSigner: false, isWritable: true },
+    { pubkey: accounts.cpLpEscrowProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([124, 163, 144, 94, 92, 166, 142, 21])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -8,6 +8,7 @@ export interface CpInitPersonalRewarderAccounts {
   /** This could be permissionless */
   owner: PublicKey
   gaugeConfig: PublicKey
+  emissionSchedule: PublicKey
   poolGauge: PublicKey
   personalRewarder: PublicKey
   personalLiqPosition: PublicKey
@@ -25,6 +26,7 @@ export function cpInitPersonalRewarder(
   const keys: Array<AccountMeta> = [
     { pubkey: accounts.owner, isSigner: true, isWritable: true },
     { pubkey: accounts.gaugeConfig, isSigner: false, isWritable: true },
+    { pubkey: accounts.emissionSchedule, isSigner: false, isWritable: false },
     { pubkey: accounts.poolGauge, isSigner: false, isWritable: true },
     { pubkey: accounts.personalRewarder, isSigner: false, isWritable: true },
     { pubkey: accounts.personalLiqPosition, isSigner: false, isWritable: true },
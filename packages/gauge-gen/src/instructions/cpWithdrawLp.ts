@@ -0,0 +1,62 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface CpWithdrawLpArgs {
+  amount: BN
+}
+
+export interface CpWithdrawLpAccounts {
+  owner: PublicKey
+  gaugeConfig: PublicKey
+  emissionSchedule: PublicKey
+  personalRewarder: PublicKey
+  poolGauge: PublicKey
+  liqPosition: PublicKey
+  timeTracker: PublicKey
+  /** LP escrow of the pool, constrained by the time tracker */
+  escrow: PublicKey
+  lpDst: PublicKey
+  rayHopper: PublicKey
+  rayDst: PublicKey
+  tokenProgram: PublicKey
+  cpLpEscrowProgram: PublicKey
+}
+
+export const layout = borsh.struct([borsh.u64("amount")])
+
+/** CP: Accrue RAY, withdraw LP from the escrow and withdraw the earned RAY in one instruction */
+export function cpWithdrawLp(
+  args: CpWithdrawLpArgs,
+  accounts: CpWithdrawLpAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.owner, isSigner: true, isWritable: true },
+    { pubkey: accounts.gaugeConfig, isSigner: false, isWritable: true },
+    { pubkey: accounts.emissionSchedule, isSigner: false, isWritable: false },
+    { pubkey: accounts.personalRewarder, isSigner: false, isWritable: true },
+    { pubkey: accounts.poolGauge, isSigner: false, isWritable: true },
+    { pubkey: accounts.liqPosition, isSigner: false, isWritable: true },
+    { pubkey: accounts.timeTracker, isSigner: false, isWritable: true },
+    { pubkey: accounts.escrow, isSigner: false, isWritable: true },
+    { pubkey: accounts.lpDst, isSigner: false, isWritable: true },
+    { pubkey: accounts.rayHopper, isSigner: false, isWritable: true },
+    { pubkey: accounts.rayDst, isSigner: false, isWritable: true },
+    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.cpLpEscrowProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([183, 179, 63, 226, 141, 194, 63, 0])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      amount: args.amount,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
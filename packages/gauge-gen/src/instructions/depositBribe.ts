@@ -0,0 +1,48 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface DepositBribeArgs {
+  amount: BN
+}
+
+export interface DepositBribeAccounts {
+  /** Permissionless depositor */
+  depositor: PublicKey
+  gaugeConfig: PublicKey
+  bribe: PublicKey
+  vault: PublicKey
+  src: PublicKey
+  tokenProgram: PublicKey
+}
+
+export const layout = borsh.struct([borsh.u64("amount")])
+
+/** Add tokens to a bribe, until the bribed epoch ends */
+export function depositBribe(
+  args: DepositBribeArgs,
+  accounts: DepositBribeAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.depositor, isSigner: true, isWritable: false },
+    { pubkey: accounts.gaugeConfig, isSigner: false, isWritable: false },
+    { pubkey: accounts.bribe, isSigner: false, isWritable: true },
+    { pubkey: accounts.vault, isSigner: false, isWritable: true },
+    { pubkey: accounts.src, isSigner: false, isWritable: true },
+    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([159, 44, 249, 110, 78, 209, 126, 9])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      amount: args.amount,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,48 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface FundGaugeHopperArgs {
+  amount: BN
+}
+
+export interface FundGaugeHopperAccounts {
+  /** Permissionless funder */
+  funder: PublicKey
+  raySrc: PublicKey
+  gaugeConfig: PublicKey
+  emissionSchedule: PublicKey
+  rayHopper: PublicKey
+  tokenProgram: PublicKey
+}
+
+export const layout = borsh.struct([borsh.u64("amount")])
+
+/** Fund the RAY hopper, raising the budget that gauges may emit */
+export function fundGaugeHopper(
+  args: FundGaugeHopperArgs,
+  accounts: FundGaugeHopperAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.funder, isSigner: true, isWritable: false },
+    { pubkey: accounts.raySrc, isSigner: false, isWritable: true },
+    { pubkey: accounts.gaugeConfig, isSigner: false, isWritable: true },
+    { pubkey: accounts.emissionSchedule, isSigner: false, isWritable: false },
+    { pubkey: accounts.rayHopper, isSigner: false, isWritable: true },
+    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([248, 102, 159, 252, 100, 27, 149, 248])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      amount: args.amount,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,25 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface GetBudgetRunOutTsAccounts {
+  gaugeConfig: PublicKey
+  emissionSchedule: PublicKey
+}
+
+/** View the projected timestamp at which the emission budget runs out */
+export function getBudgetRunOutTs(
+  accounts: GetBudgetRunOutTsAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.gaugeConfig, isSigner: false, isWritable: false },
+    { pubkey: accounts.emissionSchedule, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([71, 51, 229, 249, 120, 60, 218, 139])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -3,23 +3,106 @@ export type {
   InitGlobalConfigArgs,
   InitGlobalConfigAccounts,
 } from "./initGlobalConfig"
+export { proposeAdmin } from "./proposeAdmin"
+export type { ProposeAdminArgs, ProposeAdminAccounts } from "./proposeAdmin"
+export { acceptAdmin } from "./acceptAdmin"
+export type { AcceptAdminAccounts } from "./acceptAdmin"
+export { setPauseFlags } from "./setPauseFlags"
+export type { SetPauseFlagsArgs, SetPauseFlagsAccounts } from "./setPauseFlags"
+export { setEmissionSchedule } from "./setEmissionSchedule"
+export type {
+  SetEmissionScheduleArgs,
+  SetEmissionScheduleAccounts,
+} from "./setEmissionSchedule"
+export { setMaxVoteShareBps } from "./setMaxVoteShareBps"
+export type {
+  SetMaxVoteShareBpsArgs,
+  SetMaxVoteShareBpsAccounts,
+} from "./setMaxVoteShareBps"
+export { setGaugeMaxVoteShareBps } from "./setGaugeMaxVoteShareBps"
+export type {
+  SetGaugeMaxVoteShareBpsArgs,
+  SetGaugeMaxVoteShareBpsAccounts,
+} from "./setGaugeMaxVoteShareBps"
+export { setGaugeStatus } from "./setGaugeStatus"
+export type {
+  SetGaugeStatusArgs,
+  SetGaugeStatusAccounts,
+} from "./setGaugeStatus"
+export { setClTimeTrackerMint } from "./setClTimeTrackerMint"
+export type {
+  SetClTimeTrackerMintArgs,
+  SetClTimeTrackerMintAccounts,
+} from "./setClTimeTrackerMint"
+export { setEpochLength } from "./setEpochLength"
+export type {
+  SetEpochLengthArgs,
+  SetEpochLengthAccounts,
+} from "./setEpochLength"
 export { initPersonalGauge } from "./initPersonalGauge"
 export type { InitPersonalGaugeAccounts } from "./initPersonalGauge"
+export { closePersonalGauge } from "./closePersonalGauge"
+export type { ClosePersonalGaugeAccounts } from "./closePersonalGauge"
 export { initPoolGauge } from "./initPoolGauge"
 export type { InitPoolGaugeAccounts } from "./initPoolGauge"
+export { initClPoolGauge } from "./initClPoolGauge"
+export type {
+  InitClPoolGaugeArgs,
+  InitClPoolGaugeAccounts,
+} from "./initClPoolGauge"
 export { changeVotes } from "./changeVotes"
 export type { ChangeVotesArgs, ChangeVotesAccounts } from "./changeVotes"
+export { initVoteLedger } from "./initVoteLedger"
+export type { InitVoteLedgerAccounts } from "./initVoteLedger"
+export { closeVoteLedger } from "./closeVoteLedger"
+export type { CloseVoteLedgerAccounts } from "./closeVoteLedger"
+export { reallocateVotes } from "./reallocateVotes"
+export type {
+  ReallocateVotesArgs,
+  ReallocateVotesAccounts,
+} from "./reallocateVotes"
+export { syncDecayedVotes } from "./syncDecayedVotes"
+export type { SyncDecayedVotesAccounts } from "./syncDecayedVotes"
+export { advanceEpoch } from "./advanceEpoch"
+export type { AdvanceEpochAccounts } from "./advanceEpoch"
+export { applyEpochRecord } from "./applyEpochRecord"
+export type { ApplyEpochRecordAccounts } from "./applyEpochRecord"
+export { initBribe } from "./initBribe"
+export type { InitBribeArgs, InitBribeAccounts } from "./initBribe"
+export { depositBribe } from "./depositBribe"
+export type { DepositBribeArgs, DepositBribeAccounts } from "./depositBribe"
+export { claimBribe } from "./claimBribe"
+export type { ClaimBribeAccounts } from "./claimBribe"
+export { rolloverBribe } from "./rolloverBribe"
+export type { RolloverBribeAccounts } from "./rolloverBribe"
+export { refundBribe } from "./refundBribe"
+export type { RefundBribeAccounts } from "./refundBribe"
 export { syncPoolIndex } from "./syncPoolIndex"
 export type { SyncPoolIndexAccounts } from "./syncPoolIndex"
+export { syncGauges } from "./syncGauges"
+export type { SyncGaugesAccounts } from "./syncGauges"
+export { fundGaugeHopper } from "./fundGaugeHopper"
+export type {
+  FundGaugeHopperArgs,
+  FundGaugeHopperAccounts,
+} from "./fundGaugeHopper"
+export { getBudgetRunOutTs } from "./getBudgetRunOutTs"
+export type { GetBudgetRunOutTsAccounts } from "./getBudgetRunOutTs"
 export { cpInitPersonalRewarder } from "./cpInitPersonalRewarder"
 export type { CpInitPersonalRewarderAccounts } from "./cpInitPersonalRewarder"
 export { cpAccrueRay } from "./cpAccrueRay"
 export type { CpAccrueRayAccounts } from "./cpAccrueRay"
 export { cpWithdrawRay } from "./cpWithdrawRay"
 export type { CpWithdrawRayAccounts } from "./cpWithdrawRay"
+export { cpWithdrawLp } from "./cpWithdrawLp"
+export type { CpWithdrawLpArgs, CpWithdrawLpAccounts } from "./cpWithdrawLp"
+export { cpClosePersonalRewarder } from "./cpClosePersonalRewarder"
+export type { CpClosePersonalRewarderAccounts } from "./cpClosePersonalRewarder"
 export { clWithdrawRay } from "./clWithdrawRay"
 export type { ClWithdrawRayAccounts } from "./clWithdrawRay"
 export { clAccrueRay } from "./clAccrueRay"
 export type { ClAccrueRayAccounts } from "./clAccrueRay"
 export { clInitPersonalRewarder } from "./clInitPersonalRewarder"
 export type { ClInitPersonalRewarderAccounts } from "./clInitPersonalRewarder"
+export { clClosePersonalRewarder } from "./clClosePersonalRewarder"
+export type { ClClosePersonalRewarderAccounts } from "./clClosePersonalRewarder"
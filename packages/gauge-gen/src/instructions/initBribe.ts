@@ -0,0 +1,51 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface InitBribeArgs {
+  epoch: BN
+}
+
+export interface InitBribeAccounts {
+  payer: PublicKey
+  gaugeConfig: PublicKey
+  poolGauge: PublicKey
+  mint: PublicKey
+  bribe: PublicKey
+  vault: PublicKey
+  tokenProgram: PublicKey
+  systemProgram: PublicKey
+}
+
+export const layout = borsh.struct([borsh.u64("epoch")])
+
+/** Create the bribe of a pool gauge for an epoch, in any SPL mint */
+export function initBribe(
+  args: InitBribeArgs,
+  accounts: InitBribeAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.payer, isSigner: true, isWritable: true },
+    { pubkey: accounts.gaugeConfig, isSigner: false, isWritable: false },
+    { pubkey: accounts.poolGauge, isSigner: false, isWritable: false },
+    { pubkey: accounts.mint, isSigner: false, isWritable: false },
+    { pubkey: accounts.bribe, isSigner: false, isWritable: true },
+    { pubkey: accounts.vault, isSigner: false, isWritable: true },
+    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([63, 4, 17, 73, 155, 46, 44, 47])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      epoch: args.epoch,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,69 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface InitClPoolGaugeArgs {
+  rewardOpenTime: BN
+  rewardEndTime: BN
+}
+
+export interface InitClPoolGaugeAccounts {
+  /** Also funds the time-tracker reward when it is initialized */
+  admin: PublicKey
+  gaugeConfig: PublicKey
+  emissionSchedule: PublicKey
+  poolGauge: PublicKey
+  poolState: PublicKey
+  timeTrackerMint: PublicKey
+  funderTokenAccount: PublicKey
+  ammConfig: PublicKey
+  operationState: PublicKey
+  rewardTokenVault: PublicKey
+  rewardTokenProgram: PublicKey
+  clmmProgram: PublicKey
+  systemProgram: PublicKey
+  rent: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.u64("rewardOpenTime"),
+  borsh.u64("rewardEndTime"),
+])
+
+/** Create the gauge of a CLMM pool, provisioning its time-tracker reward if needed */
+export function initClPoolGauge(
+  args: InitClPoolGaugeArgs,
+  accounts: InitClPoolGaugeAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.admin, isSigner: true, isWritable: true },
+    { pubkey: accounts.gaugeConfig, isSigner: false, isWritable: true },
+    { pubkey: accounts.emissionSchedule, isSigner: false, isWritable: false },
+    { pubkey: accounts.poolGauge, isSigner: false, isWritable: true },
+    { pubkey: accounts.poolState, isSigner: false, isWritable: true },
+    { pubkey: accounts.timeTrackerMint, isSigner: false, isWritable: false },
+    { pubkey: accounts.funderTokenAccount, isSigner: false, isWritable: true },
+    { pubkey: accounts.ammConfig, isSigner: false, isWritable: false },
+    { pubkey: accounts.operationState, isSigner: false, isWritable: false },
+    { pubkey: accounts.rewardTokenVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.rewardTokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.clmmProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.rent, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([118, 233, 7, 246, 34, 159, 132, 24])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      rewardOpenTime: args.rewardOpenTime,
+      rewardEndTime: args.rewardEndTime,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
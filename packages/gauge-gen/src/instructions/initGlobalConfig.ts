@@ -9,10 +9,11 @@ export interface InitGlobalConfigArgs {
 }
 
 export interface InitGlobalConfigAccounts {
-  /** must be admin */
+  /** The deployer key bootstraps the config and becomes its first admin */
   payer: PublicKey
   gaugeConfig: PublicKey
   rayHopper: PublicKey
+  emissionSchedule: PublicKey
   rayMint: PublicKey
   tokenProgram: PublicKey
   systemProgram: PublicKey
@@ -29,6 +30,7 @@ export function initGlobalConfig(
     { pubkey: accounts.payer, isSigner: true, isWritable: true },
     { pubkey: accounts.gaugeConfig, isSigner: false, isWritable: true },
     { pubkey: accounts.rayHopper, isSigner: false, isWritable: true },
+    { pubkey: accounts.emissionSchedule, isSigner: false, isWritable: true },
     { pubkey: accounts.rayMint, isSigner: false, isWritable: false },
     { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
     { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
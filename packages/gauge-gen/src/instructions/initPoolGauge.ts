@@ -7,12 +7,14 @@ import { PROGRAM_ID } from "../programId"
 export interface InitPoolGaugeAccounts {
   payer: PublicKey
   gaugeConfig: PublicKey
+  emissionSchedule: PublicKey
   poolGauge: PublicKey
-  /** There will not be a CP escrow nor CL position for them anyway */
+  /** CLMM pools are onboarded by the admin with init_cl_pool_gauge, who provisions their time-tracker reward */
   poolId: PublicKey
   systemProgram: PublicKey
 }
 
+/** Create the gauge of a CP swap pool, pending until the admin whitelists it with `set_gauge_status` */
 export function initPoolGauge(
   accounts: InitPoolGaugeAccounts,
   programId: PublicKey = PROGRAM_ID
@@ -20,6 +22,7 @@ export function initPoolGauge(
   const keys: Array<AccountMeta> = [
     { pubkey: accounts.payer, isSigner: true, isWritable: true },
     { pubkey: accounts.gaugeConfig, isSigner: false, isWritable: true },
+    { pubkey: accounts.emissionSchedule, isSigner: false, isWritable: false },
     { pubkey: accounts.poolGauge, isSigner: false, isWritable: true },
     { pubkey: accounts.poolId, isSigner: false, isWritable: false },
     { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
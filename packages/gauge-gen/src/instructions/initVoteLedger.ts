@@ -0,0 +1,29 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface InitVoteLedgerAccounts {
+  owner: PublicKey
+  reactor: PublicKey
+  voteLedger: PublicKey
+  systemProgram: PublicKey
+}
+
+/** Create the ledger listing the votes of a reactor per gauge, for up to 16 gauges */
+export function initVoteLedger(
+  accounts: InitVoteLedgerAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.owner, isSigner: true, isWritable: true },
+    { pubkey: accounts.reactor, isSigner: false, isWritable: false },
+    { pubkey: accounts.voteLedger, isSigner: false, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([137, 21, 199, 24, 106, 25, 210, 97])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,59 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface ReallocateVotesArgs {
+  deltas: Array<BN>
+}
+
+export interface ReallocateVotesAccounts {
+  /** Reactor owner, or its delegate when a vote delegation is provided */
+  authority: PublicKey
+  /** Global config for Gauge instance */
+  gaugeConfig: PublicKey
+  /** Emission schedule for Gauge instance */
+  emissionSchedule: PublicKey
+  /** Personal reactor account */
+  reactor: PublicKey
+  /** Delegation of the reactor's votes, required when the authority is not the owner */
+  voteDelegation?: PublicKey
+  /** Vote ledger of the reactor, kept in sync when it exists */
+  voteLedger: PublicKey
+  reactorProg: PublicKey
+  sysvarInstruction: PublicKey
+}
+
+export const layout = borsh.struct([borsh.vec(borsh.i64(), "deltas")])
+
+/** Pledge/Unpledge votes on many gauges, un/locking only the net change in the reactor */
+export function reallocateVotes(
+  args: ReallocateVotesArgs,
+  accounts: ReallocateVotesAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.authority, isSigner: true, isWritable: true },
+    { pubkey: accounts.gaugeConfig, isSigner: false, isWritable: true },
+    { pubkey: accounts.emissionSchedule, isSigner: false, isWritable: false },
+    { pubkey: accounts.reactor, isSigner: false, isWritable: true },
+    accounts.voteDelegation
+      ? { pubkey: accounts.voteDelegation, isSigner: false, isWritable: false }
+      : { pubkey: programId, isSigner: false, isWritable: false },
+    { pubkey: accounts.voteLedger, isSigner: false, isWritable: true },
+    { pubkey: accounts.reactorProg, isSigner: false, isWritable: false },
+    { pubkey: accounts.sysvarInstruction, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([228, 100, 73, 233, 23, 21, 253, 252])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      deltas: args.deltas,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,33 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface RefundBribeAccounts {
+  refundAuthority: PublicKey
+  gaugeConfig: PublicKey
+  bribe: PublicKey
+  vault: PublicKey
+  dst: PublicKey
+  tokenProgram: PublicKey
+}
+
+/** Refund the unclaimed tokens of an expired bribe to its creator */
+export function refundBribe(
+  accounts: RefundBribeAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.refundAuthority, isSigner: true, isWritable: false },
+    { pubkey: accounts.gaugeConfig, isSigner: false, isWritable: false },
+    { pubkey: accounts.bribe, isSigner: false, isWritable: true },
+    { pubkey: accounts.vault, isSigner: false, isWritable: true },
+    { pubkey: accounts.dst, isSigner: false, isWritable: true },
+    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([212, 56, 10, 70, 41, 227, 124, 86])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
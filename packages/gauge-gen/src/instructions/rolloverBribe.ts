@@ -0,0 +1,33 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface RolloverBribeAccounts {
+  gaugeConfig: PublicKey
+  bribe: PublicKey
+  vault: PublicKey
+  targetBribe: PublicKey
+  targetVault: PublicKey
+  tokenProgram: PublicKey
+}
+
+/** Move the unclaimed tokens of an expired bribe into an open bribe of the same creator */
+export function rolloverBribe(
+  accounts: RolloverBribeAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.gaugeConfig, isSigner: false, isWritable: false },
+    { pubkey: accounts.bribe, isSigner: false, isWritable: true },
+    { pubkey: accounts.vault, isSigner: false, isWritable: true },
+    { pubkey: accounts.targetBribe, isSigner: false, isWritable: true },
+    { pubkey: accounts.targetVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([212, 250, 42, 31, 23, 191, 181, 216])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,39 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface SetClTimeTrackerMintArgs {
+  mint: PublicKey
+}
+
+export interface SetClTimeTrackerMintAccounts {
+  admin: PublicKey
+  gaugeConfig: PublicKey
+}
+
+export const layout = borsh.struct([borsh.publicKey("mint")])
+
+/** Designate the mint of the CLMM reward that tracks time units, once */
+export function setClTimeTrackerMint(
+  args: SetClTimeTrackerMintArgs,
+  accounts: SetClTimeTrackerMintAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.admin, isSigner: true, isWritable: false },
+    { pubkey: accounts.gaugeConfig, isSigner: false, isWritable: true },
+  ]
+  const identifier = Buffer.from([234, 165, 121, 56, 93, 239, 166, 73])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      mint: args.mint,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
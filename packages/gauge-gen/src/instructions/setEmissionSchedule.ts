@@ -0,0 +1,45 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface SetEmissionScheduleArgs {
+  segments: Array<types.EmissionSegmentFields>
+}
+
+export interface SetEmissionScheduleAccounts {
+  admin: PublicKey
+  gaugeConfig: PublicKey
+  emissionSchedule: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.vec(types.EmissionSegment.layout(), "segments"),
+])
+
+/** Replace the emission schedule, settling emission under the old schedule first */
+export function setEmissionSchedule(
+  args: SetEmissionScheduleArgs,
+  accounts: SetEmissionScheduleAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.admin, isSigner: true, isWritable: false },
+    { pubkey: accounts.gaugeConfig, isSigner: false, isWritable: true },
+    { pubkey: accounts.emissionSchedule, isSigner: false, isWritable: true },
+  ]
+  const identifier = Buffer.from([224, 103, 62, 205, 138, 102, 25, 69])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      segments: args.segments.map(
+        (item) => types.EmissionSegment.toEncodable(item)
+      ),
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
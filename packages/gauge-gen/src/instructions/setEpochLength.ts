@@ -0,0 +1,41 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface SetEpochLengthArgs {
+  epochLength: BN
+}
+
+export interface SetEpochLengthAccounts {
+  admin: PublicKey
+  gaugeConfig: PublicKey
+  emissionSchedule: PublicKey
+}
+
+export const layout = borsh.struct([borsh.u64("epochLength")])
+
+/** Turn on epoch mode, or change the length of the following epochs */
+export function setEpochLength(
+  args: SetEpochLengthArgs,
+  accounts: SetEpochLengthAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.admin, isSigner: true, isWritable: false },
+    { pubkey: accounts.gaugeConfig, isSigner: false, isWritable: true },
+    { pubkey: accounts.emissionSchedule, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([219, 34, 224, 120, 139, 47, 250, 238])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      epochLength: args.epochLength,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,43 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface SetGaugeMaxVoteShareBpsArgs {
+  maxVoteShareBps: number
+}
+
+export interface SetGaugeMaxVoteShareBpsAccounts {
+  admin: PublicKey
+  gaugeConfig: PublicKey
+  emissionSchedule: PublicKey
+  poolGauge: PublicKey
+}
+
+export const layout = borsh.struct([borsh.u16("maxVoteShareBps")])
+
+/** Set the cap on the share of emission a single gauge may receive, overriding the global cap */
+export function setGaugeMaxVoteShareBps(
+  args: SetGaugeMaxVoteShareBpsArgs,
+  accounts: SetGaugeMaxVoteShareBpsAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.admin, isSigner: true, isWritable: false },
+    { pubkey: accounts.gaugeConfig, isSigner: false, isWritable: true },
+    { pubkey: accounts.emissionSchedule, isSigner: false, isWritable: false },
+    { pubkey: accounts.poolGauge, isSigner: false, isWritable: true },
+  ]
+  const identifier = Buffer.from([231, 141, 55, 218, 19, 69, 65, 214])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      maxVoteShareBps: args.maxVoteShareBps,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,43 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface SetGaugeStatusArgs {
+  status: types.GaugeStatusKind
+}
+
+export interface SetGaugeStatusAccounts {
+  admin: PublicKey
+  gaugeConfig: PublicKey
+  emissionSchedule: PublicKey
+  poolGauge: PublicKey
+}
+
+export const layout = borsh.struct([types.GaugeStatus.layout("status")])
+
+/** Whitelist, kill or revive a pool gauge */
+export function setGaugeStatus(
+  args: SetGaugeStatusArgs,
+  accounts: SetGaugeStatusAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.admin, isSigner: true, isWritable: false },
+    { pubkey: accounts.gaugeConfig, isSigner: false, isWritable: true },
+    { pubkey: accounts.emissionSchedule, isSigner: false, isWritable: false },
+    { pubkey: accounts.poolGauge, isSigner: false, isWritable: true },
+  ]
+  const identifier = Buffer.from([145, 209, 36, 84, 115, 155, 52, 58])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      status: args.status.toEncodable(),
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
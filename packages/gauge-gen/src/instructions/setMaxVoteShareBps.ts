@@ -0,0 +1,41 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface SetMaxVoteShareBpsArgs {
+  maxVoteShareBps: number
+}
+
+export interface SetMaxVoteShareBpsAccounts {
+  admin: PublicKey
+  gaugeConfig: PublicKey
+  emissionSchedule: PublicKey
+}
+
+export const layout = borsh.struct([borsh.u16("maxVoteShareBps")])
+
+/** Set the global cap on the share of emission a gauge may receive */
+export function setMaxVoteShareBps(
+  args: SetMaxVoteShareBpsArgs,
+  accounts: SetMaxVoteShareBpsAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.admin, isSigner: true, isWritable: false },
+    { pubkey: accounts.gaugeConfig, isSigner: false, isWritable: true },
+    { pubkey: accounts.emissionSchedule, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([180, 169, 181, 104, 22, 218, 64, 18])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      maxVoteShareBps: args.maxVoteShareBps,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
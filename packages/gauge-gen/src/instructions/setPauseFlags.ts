@@ -0,0 +1,39 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface SetPauseFlagsArgs {
+  pauseFlags: number
+}
+
+export interface SetPauseFlagsAccounts {
+  admin: PublicKey
+  gaugeConfig: PublicKey
+}
+
+export const layout = borsh.struct([borsh.u8("pauseFlags")])
+
+/** Set the bitmask of paused actions */
+export function setPauseFlags(
+  args: SetPauseFlagsArgs,
+  accounts: SetPauseFlagsAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.admin, isSigner: true, isWritable: false },
+    { pubkey: accounts.gaugeConfig, isSigner: false, isWritable: true },
+  ]
+  const identifier = Buffer.from([205, 167, 85, 237, 144, 202, 248, 175])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      pauseFlags: args.pauseFlags,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,47 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface SyncDecayedVotesAccounts {
+  /** Reactor owner or its delegate, omitted for the permissionless pro-rata crank */
+  authority?: PublicKey
+  /** Global config for Gauge instance */
+  gaugeConfig: PublicKey
+  /** Emission schedule for Gauge instance */
+  emissionSchedule: PublicKey
+  /** Personal reactor account whose votes have decayed */
+  reactor: PublicKey
+  /** Delegation of the reactor's votes, required when the authority is not the owner */
+  voteDelegation?: PublicKey
+  /** Vote ledger of the reactor, kept in sync when it exists */
+  voteLedger: PublicKey
+  reactorProg: PublicKey
+  sysvarInstruction: PublicKey
+}
+
+/** Remove decayed ve-mode votes from a reactor's gauges, rebalancing the vote totals */
+export function syncDecayedVotes(
+  accounts: SyncDecayedVotesAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    accounts.authority
+      ? { pubkey: accounts.authority, isSigner: true, isWritable: false }
+      : { pubkey: programId, isSigner: false, isWritable: false },
+    { pubkey: accounts.gaugeConfig, isSigner: false, isWritable: true },
+    { pubkey: accounts.emissionSchedule, isSigner: false, isWritable: false },
+    { pubkey: accounts.reactor, isSigner: false, isWritable: true },
+    accounts.voteDelegation
+      ? { pubkey: accounts.voteDelegation, isSigner: false, isWritable: false }
+      : { pubkey: programId, isSigner: false, isWritable: false },
+    { pubkey: accounts.voteLedger, isSigner: false, isWritable: true },
+    { pubkey: accounts.reactorProg, isSigner: false, isWritable: false },
+    { pubkey: accounts.sysvarInstruction, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([45, 179, 46, 114, 130, 134, 185, 11])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -6,6 +6,7 @@ import { PROGRAM_ID } from "../programId"
 
 export interface SyncPoolIndexAccounts {
   gaugeConfig: PublicKey
+  emissionSchedule: PublicKey
   poolGauge: PublicKey
 }
 
@@ -16,6 +17,7 @@ export function syncPoolIndex(
 ) {
   const keys: Array<AccountMeta> = [
     { pubkey: accounts.gaugeConfig, isSigner: false, isWritable: true },
+    { pubkey: accounts.emissionSchedule, isSigner: false, isWritable: false },
     { pubkey: accounts.poolGauge, isSigner: false, isWritable: true },
   ]
   const identifier = Buffer.from([254, 161, 93, 153, 213, 127, 4, 203])
@@ -0,0 +1,71 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface EmissionSegmentFields {
+  /** Timestamp at which this rate takes effect */
+  startTs: BN
+  /** Total RAY emission per day during this segment */
+  rayPerDay: BN
+}
+
+export interface EmissionSegmentJSON {
+  /** Timestamp at which this rate takes effect */
+  startTs: string
+  /** Total RAY emission per day during this segment */
+  rayPerDay: string
+}
+
+/** A period of constant emission, lasting until the next segment starts */
+export class EmissionSegment {
+  /** Timestamp at which this rate takes effect */
+  readonly startTs: BN
+  /** Total RAY emission per day during this segment */
+  readonly rayPerDay: BN
+
+  constructor(fields: EmissionSegmentFields) {
+    this.startTs = fields.startTs
+    this.rayPerDay = fields.rayPerDay
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [borsh.u64("startTs"), borsh.u64("rayPerDay")],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new EmissionSegment({
+      startTs: obj.startTs,
+      rayPerDay: obj.rayPerDay,
+    })
+  }
+
+  static toEncodable(fields: EmissionSegmentFields) {
+    return {
+      startTs: fields.startTs,
+      rayPerDay: fields.rayPerDay,
+    }
+  }
+
+  toJSON(): EmissionSegmentJSON {
+    return {
+      startTs: this.startTs.toString(),
+      rayPerDay: this.rayPerDay.toString(),
+    }
+  }
+
+  static fromJSON(obj: EmissionSegmentJSON): EmissionSegment {
+    return new EmissionSegment({
+      startTs: new BN(obj.startTs),
+      rayPerDay: new BN(obj.rayPerDay),
+    })
+  }
+
+  toEncodable() {
+    return EmissionSegment.toEncodable(this)
+  }
+}
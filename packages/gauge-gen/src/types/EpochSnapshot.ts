@@ -0,0 +1,86 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface EpochSnapshotFields {
+  /** Epoch that starts at this boundary */
+  epoch: BN
+  /** Global index at the boundary */
+  index: types.NumberRawFields
+  /** Global lifetime emission at the boundary */
+  totalEmission: types.NumberRawFields
+}
+
+export interface EpochSnapshotJSON {
+  /** Epoch that starts at this boundary */
+  epoch: string
+  /** Global index at the boundary */
+  index: types.NumberRawJSON
+  /** Global lifetime emission at the boundary */
+  totalEmission: types.NumberRawJSON
+}
+
+/** Global index values at the start of an epoch */
+export class EpochSnapshot {
+  /** Epoch that starts at this boundary */
+  readonly epoch: BN
+  /** Global index at the boundary */
+  readonly index: types.NumberRaw
+  /** Global lifetime emission at the boundary */
+  readonly totalEmission: types.NumberRaw
+
+  constructor(fields: EpochSnapshotFields) {
+    this.epoch = fields.epoch
+    this.index = new types.NumberRaw({ ...fields.index })
+    this.totalEmission = new types.NumberRaw({ ...fields.totalEmission })
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.u64("epoch"),
+        types.NumberRaw.layout("index"),
+        types.NumberRaw.layout("totalEmission"),
+      ],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new EpochSnapshot({
+      epoch: obj.epoch,
+      index: types.NumberRaw.fromDecoded(obj.index),
+      totalEmission: types.NumberRaw.fromDecoded(obj.totalEmission),
+    })
+  }
+
+  static toEncodable(fields: EpochSnapshotFields) {
+    return {
+      epoch: fields.epoch,
+      index: types.NumberRaw.toEncodable(fields.index),
+      totalEmission: types.NumberRaw.toEncodable(fields.totalEmission),
+    }
+  }
+
+  toJSON(): EpochSnapshotJSON {
+    return {
+      epoch: this.epoch.toString(),
+      index: this.index.toJSON(),
+      totalEmission: this.totalEmission.toJSON(),
+    }
+  }
+
+  static fromJSON(obj: EpochSnapshotJSON): EpochSnapshot {
+    return new EpochSnapshot({
+      epoch: new BN(obj.epoch),
+      index: types.NumberRaw.fromJSON(obj.index),
+      totalEmission: types.NumberRaw.fromJSON(obj.totalEmission),
+    })
+  }
+
+  toEncodable() {
+    return EpochSnapshot.toEncodable(this)
+  }
+}
@@ -0,0 +1,118 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface PendingJSON {
+  kind: "Pending"
+}
+
+export class Pending {
+  static readonly discriminator = 0
+  static readonly kind = "Pending"
+  readonly discriminator = 0
+  readonly kind = "Pending"
+
+  toJSON(): PendingJSON {
+    return {
+      kind: "Pending",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Pending: {},
+    }
+  }
+}
+
+export interface WhitelistedJSON {
+  kind: "Whitelisted"
+}
+
+export class Whitelisted {
+  static readonly discriminator = 1
+  static readonly kind = "Whitelisted"
+  readonly discriminator = 1
+  readonly kind = "Whitelisted"
+
+  toJSON(): WhitelistedJSON {
+    return {
+      kind: "Whitelisted",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Whitelisted: {},
+    }
+  }
+}
+
+export interface KilledJSON {
+  kind: "Killed"
+}
+
+export class Killed {
+  static readonly discriminator = 2
+  static readonly kind = "Killed"
+  readonly discriminator = 2
+  readonly kind = "Killed"
+
+  toJSON(): KilledJSON {
+    return {
+      kind: "Killed",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Killed: {},
+    }
+  }
+}
+
+// eslint-disable-next-line @typescript-eslint/no-explicit-any
+export function fromDecoded(obj: any): types.GaugeStatusKind {
+  if (typeof obj !== "object") {
+    throw new Error("Invalid enum object")
+  }
+
+  if ("Pending" in obj) {
+    return new Pending()
+  }
+  if ("Whitelisted" in obj) {
+    return new Whitelisted()
+  }
+  if ("Killed" in obj) {
+    return new Killed()
+  }
+
+  throw new Error("Invalid enum object")
+}
+
+export function fromJSON(obj: types.GaugeStatusJSON): types.GaugeStatusKind {
+  switch (obj.kind) {
+    case "Pending": {
+      return new Pending()
+    }
+    case "Whitelisted": {
+      return new Whitelisted()
+    }
+    case "Killed": {
+      return new Killed()
+    }
+  }
+}
+
+export function layout(property?: string) {
+  const ret = borsh.rustEnum([
+    borsh.struct([], "Pending"),
+    borsh.struct([], "Whitelisted"),
+    borsh.struct([], "Killed"),
+  ])
+  if (property !== undefined) {
+    return ret.replicate(property)
+  }
+  return ret
+}
@@ -0,0 +1,83 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface GaugeSyncedFields {
+  gauge: PublicKey
+  /** Total votes in the gauge */
+  totalVotes: BN
+  /** Lifetime total RAY emitted by the gauge */
+  totalRayEmitted: BN
+}
+
+export interface GaugeSyncedJSON {
+  gauge: string
+  /** Total votes in the gauge */
+  totalVotes: string
+  /** Lifetime total RAY emitted by the gauge */
+  totalRayEmitted: string
+}
+
+/** Per-gauge summary of a sync */
+export class GaugeSynced {
+  readonly gauge: PublicKey
+  /** Total votes in the gauge */
+  readonly totalVotes: BN
+  /** Lifetime total RAY emitted by the gauge */
+  readonly totalRayEmitted: BN
+
+  constructor(fields: GaugeSyncedFields) {
+    this.gauge = fields.gauge
+    this.totalVotes = fields.totalVotes
+    this.totalRayEmitted = fields.totalRayEmitted
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.publicKey("gauge"),
+        borsh.u64("totalVotes"),
+        borsh.u64("totalRayEmitted"),
+      ],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new GaugeSynced({
+      gauge: obj.gauge,
+      totalVotes: obj.totalVotes,
+      totalRayEmitted: obj.totalRayEmitted,
+    })
+  }
+
+  static toEncodable(fields: GaugeSyncedFields) {
+    return {
+      gauge: fields.gauge,
+      totalVotes: fields.totalVotes,
+      totalRayEmitted: fields.totalRayEmitted,
+    }
+  }
+
+  toJSON(): GaugeSyncedJSON {
+    return {
+      gauge: this.gauge.toString(),
+      totalVotes: this.totalVotes.toString(),
+      totalRayEmitted: this.totalRayEmitted.toString(),
+    }
+  }
+
+  static fromJSON(obj: GaugeSyncedJSON): GaugeSynced {
+    return new GaugeSynced({
+      gauge: new PublicKey(obj.gauge),
+      totalVotes: new BN(obj.totalVotes),
+      totalRayEmitted: new BN(obj.totalRayEmitted),
+    })
+  }
+
+  toEncodable() {
+    return GaugeSynced.toEncodable(this)
+  }
+}
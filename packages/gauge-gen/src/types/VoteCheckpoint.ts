@@ -0,0 +1,68 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface VoteCheckpointFields {
+  /** Epoch from which the votes are in effect */
+  fromEpoch: BN
+  /** Votes in effect */
+  votes: BN
+}
+
+export interface VoteCheckpointJSON {
+  /** Epoch from which the votes are in effect */
+  fromEpoch: string
+  /** Votes in effect */
+  votes: string
+}
+
+/** Votes in effect from the start of an epoch */
+export class VoteCheckpoint {
+  /** Epoch from which the votes are in effect */
+  readonly fromEpoch: BN
+  /** Votes in effect */
+  readonly votes: BN
+
+  constructor(fields: VoteCheckpointFields) {
+    this.fromEpoch = fields.fromEpoch
+    this.votes = fields.votes
+  }
+
+  static layout(property?: string) {
+    return borsh.struct([borsh.u64("fromEpoch"), borsh.u64("votes")], property)
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new VoteCheckpoint({
+      fromEpoch: obj.fromEpoch,
+      votes: obj.votes,
+    })
+  }
+
+  static toEncodable(fields: VoteCheckpointFields) {
+    return {
+      fromEpoch: fields.fromEpoch,
+      votes: fields.votes,
+    }
+  }
+
+  toJSON(): VoteCheckpointJSON {
+    return {
+      fromEpoch: this.fromEpoch.toString(),
+      votes: this.votes.toString(),
+    }
+  }
+
+  static fromJSON(obj: VoteCheckpointJSON): VoteCheckpoint {
+    return new VoteCheckpoint({
+      fromEpoch: new BN(obj.fromEpoch),
+      votes: new BN(obj.votes),
+    })
+  }
+
+  toEncodable() {
+    return VoteCheckpoint.toEncodable(this)
+  }
+}
@@ -0,0 +1,81 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface VoteHistoryFields {
+  numCheckpoints: number
+  checkpoints: Array<types.VoteCheckpointFields>
+}
+
+export interface VoteHistoryJSON {
+  numCheckpoints: number
+  checkpoints: Array<types.VoteCheckpointJSON>
+}
+
+/**
+ * Recent epoch-mode vote changes of a gauge, oldest first
+ * Used to look up the votes in effect during a past epoch
+ */
+export class VoteHistory {
+  readonly numCheckpoints: number
+  readonly checkpoints: Array<types.VoteCheckpoint>
+
+  constructor(fields: VoteHistoryFields) {
+    this.numCheckpoints = fields.numCheckpoints
+    this.checkpoints = fields.checkpoints.map(
+      (item) => new types.VoteCheckpoint({ ...item })
+    )
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.u8("numCheckpoints"),
+        borsh.array(types.VoteCheckpoint.layout(), 4, "checkpoints"),
+      ],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new VoteHistory({
+      numCheckpoints: obj.numCheckpoints,
+      checkpoints: obj.checkpoints.map(
+        (
+          item: any /* eslint-disable-line @typescript-eslint/no-explicit-any */
+        ) => types.VoteCheckpoint.fromDecoded(item)
+      ),
+    })
+  }
+
+  static toEncodable(fields: VoteHistoryFields) {
+    return {
+      numCheckpoints: fields.numCheckpoints,
+      checkpoints: fields.checkpoints.map(
+        (item) => types.VoteCheckpoint.toEncodable(item)
+      ),
+    }
+  }
+
+  toJSON(): VoteHistoryJSON {
+    return {
+      numCheckpoints: this.numCheckpoints,
+      checkpoints: this.checkpoints.map((item) => item.toJSON()),
+    }
+  }
+
+  static fromJSON(obj: VoteHistoryJSON): VoteHistory {
+    return new VoteHistory({
+      numCheckpoints: obj.numCheckpoints,
+      checkpoints: obj.checkpoints.map(
+        (item) => types.VoteCheckpoint.fromJSON(item)
+      ),
+    })
+  }
+
+  toEncodable() {
+    return VoteHistory.toEncodable(this)
+  }
+}
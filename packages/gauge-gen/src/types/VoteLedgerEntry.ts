@@ -0,0 +1,65 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface VoteLedgerEntryFields {
+  poolGauge: PublicKey
+  votes: BN
+}
+
+export interface VoteLedgerEntryJSON {
+  poolGauge: string
+  votes: string
+}
+
+/** Votes of a reactor on one gauge */
+export class VoteLedgerEntry {
+  readonly poolGauge: PublicKey
+  readonly votes: BN
+
+  constructor(fields: VoteLedgerEntryFields) {
+    this.poolGauge = fields.poolGauge
+    this.votes = fields.votes
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [borsh.publicKey("poolGauge"), borsh.u64("votes")],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new VoteLedgerEntry({
+      poolGauge: obj.poolGauge,
+      votes: obj.votes,
+    })
+  }
+
+  static toEncodable(fields: VoteLedgerEntryFields) {
+    return {
+      poolGauge: fields.poolGauge,
+      votes: fields.votes,
+    }
+  }
+
+  toJSON(): VoteLedgerEntryJSON {
+    return {
+      poolGauge: this.poolGauge.toString(),
+      votes: this.votes.toString(),
+    }
+  }
+
+  static fromJSON(obj: VoteLedgerEntryJSON): VoteLedgerEntry {
+    return new VoteLedgerEntry({
+      poolGauge: new PublicKey(obj.poolGauge),
+      votes: new BN(obj.votes),
+    })
+  }
+
+  toEncodable() {
+    return VoteLedgerEntry.toEncodable(this)
+  }
+}
@@ -0,0 +1,86 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface VoteShareCapChangeFields {
+  /** Global index at the change */
+  index: types.NumberRawFields
+  /** Global lifetime emission at the change */
+  totalEmission: types.NumberRawFields
+  /** Global cap in force until the change */
+  oldMaxVoteShareBps: number
+}
+
+export interface VoteShareCapChangeJSON {
+  /** Global index at the change */
+  index: types.NumberRawJSON
+  /** Global lifetime emission at the change */
+  totalEmission: types.NumberRawJSON
+  /** Global cap in force until the change */
+  oldMaxVoteShareBps: number
+}
+
+/** Global index values when the global vote share cap changed */
+export class VoteShareCapChange {
+  /** Global index at the change */
+  readonly index: types.NumberRaw
+  /** Global lifetime emission at the change */
+  readonly totalEmission: types.NumberRaw
+  /** Global cap in force until the change */
+  readonly oldMaxVoteShareBps: number
+
+  constructor(fields: VoteShareCapChangeFields) {
+    this.index = new types.NumberRaw({ ...fields.index })
+    this.totalEmission = new types.NumberRaw({ ...fields.totalEmission })
+    this.oldMaxVoteShareBps = fields.oldMaxVoteShareBps
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        types.NumberRaw.layout("index"),
+        types.NumberRaw.layout("totalEmission"),
+        borsh.u16("oldMaxVoteShareBps"),
+      ],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new VoteShareCapChange({
+      index: types.NumberRaw.fromDecoded(obj.index),
+      totalEmission: types.NumberRaw.fromDecoded(obj.totalEmission),
+      oldMaxVoteShareBps: obj.oldMaxVoteShareBps,
+    })
+  }
+
+  static toEncodable(fields: VoteShareCapChangeFields) {
+    return {
+      index: types.NumberRaw.toEncodable(fields.index),
+      totalEmission: types.NumberRaw.toEncodable(fields.totalEmission),
+      oldMaxVoteShareBps: fields.oldMaxVoteShareBps,
+    }
+  }
+
+  toJSON(): VoteShareCapChangeJSON {
+    return {
+      index: this.index.toJSON(),
+      totalEmission: this.totalEmission.toJSON(),
+      oldMaxVoteShareBps: this.oldMaxVoteShareBps,
+    }
+  }
+
+  static fromJSON(obj: VoteShareCapChangeJSON): VoteShareCapChange {
+    return new VoteShareCapChange({
+      index: types.NumberRaw.fromJSON(obj.index),
+      totalEmission: types.NumberRaw.fromJSON(obj.totalEmission),
+      oldMaxVoteShareBps: obj.oldMaxVoteShareBps,
+    })
+  }
+
+  toEncodable() {
+    return VoteShareCapChange.toEncodable(this)
+  }
+}
@@ -1,7 +1,42 @@
+import * as GaugeStatus from "./GaugeStatus"
+export { GaugeSynced } from "./GaugeSynced"
+export type { GaugeSyncedFields, GaugeSyncedJSON } from "./GaugeSynced"
 export { PersonalRewarderState } from "./PersonalRewarderState"
 export type {
   PersonalRewarderStateFields,
   PersonalRewarderStateJSON,
 } from "./PersonalRewarderState"
+export { EmissionSegment } from "./EmissionSegment"
+export type {
+  EmissionSegmentFields,
+  EmissionSegmentJSON,
+} from "./EmissionSegment"
+export { EpochSnapshot } from "./EpochSnapshot"
+export type { EpochSnapshotFields, EpochSnapshotJSON } from "./EpochSnapshot"
+export { VoteShareCapChange } from "./VoteShareCapChange"
+export type {
+  VoteShareCapChangeFields,
+  VoteShareCapChangeJSON,
+} from "./VoteShareCapChange"
 export { NumberRaw } from "./NumberRaw"
 export type { NumberRawFields, NumberRawJSON } from "./NumberRaw"
+export { VoteCheckpoint } from "./VoteCheckpoint"
+export type { VoteCheckpointFields, VoteCheckpointJSON } from "./VoteCheckpoint"
+export { VoteHistory } from "./VoteHistory"
+export type { VoteHistoryFields, VoteHistoryJSON } from "./VoteHistory"
+export { VoteLedgerEntry } from "./VoteLedgerEntry"
+export type {
+  VoteLedgerEntryFields,
+  VoteLedgerEntryJSON,
+} from "./VoteLedgerEntry"
+
+export { GaugeStatus }
+
+export type GaugeStatusKind =
+  | GaugeStatus.Pending
+  | GaugeStatus.Whitelisted
+  | GaugeStatus.Killed
+export type GaugeStatusJSON =
+  | GaugeStatus.PendingJSON
+  | GaugeStatus.WhitelistedJSON
+  | GaugeStatus.KilledJSON
@@ -37,4 +37,13 @@ export class GaugePDA {
   globalConfig() {
     return this.findProgramAddressSync([Buffer.from("global-config")])
   }
+
+  emissionSchedule() {
+    return this.findProgramAddressSync([Buffer.from("emission-schedule")])
+  }
+
+  /** Ledger of the votes of a reactor, per pool gauge */
+  voteLedger({ reactor }: { reactor: web3.PublicKey }) {
+    return this.findProgramAddressSync([Buffer.from("vote-ledger"), reactor.toBuffer()])
+  }
 }
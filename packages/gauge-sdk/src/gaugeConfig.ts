@@ -1,7 +1,10 @@
-import { web3 } from "@coral-xyz/anchor"
-import { accounts, instructions } from "@raygauge/gauge-gen"
+import { BN, web3 } from "@coral-xyz/anchor"
+import { accounts, instructions, types } from "@raygauge/gauge-gen"
 import { GaugePDA } from "@raygauge/gauge-pda"
 import { PreciseNumber } from "@raygauge/number"
+import { CL_SWAP_PROGRAM_ID } from "@raygauge/ray-cl-idl"
+import { RayClPda } from "@raygauge/ray-cl-pda"
+import { getAssociatedTokenAddressSync, TOKEN_PROGRAM_ID } from "@solana/spl-token"
 
 type GaugeConfigData = {
   programId?: web3.PublicKey
@@ -52,6 +55,19 @@ export class GaugeConfig {
     return this.account.rayHopper
   }
 
+  get admin() {
+    return this.account.admin
+  }
+
+  get emissionSchedule() {
+    return this.account.emissionSchedule
+  }
+
+  /** Mint of the CLMM reward that tracks time units for CL pool gauges */
+  get clTimeTrackerMint() {
+    return this.account.clTimeTrackerMint
+  }
+
   get rayEmissionPerDay(): bigint {
     return BigInt(this.account.rayEmissionPerDay.toString())
   }
@@ -69,17 +85,86 @@ export class GaugeConfig {
     return PreciseNumber.fromRaw(this.account.index.val).valueString
   }
 
+  /** Create the gauge of a CP swap pool, pending until whitelisted with `setGaugeStatusIx` */
   initPoolGaugeIx({ poolId, payer }: { poolId: web3.PublicKey; payer: web3.PublicKey }) {
     const pda = this.pda
     const poolGauge = pda.poolGauge({ poolId })
     return instructions.initPoolGauge({
       payer,
       gaugeConfig: this.address,
+      emissionSchedule: this.emissionSchedule,
       poolGauge,
       poolId,
       systemProgram: web3.SystemProgram.programId,
     })
   }
+
+  /** Create the gauge of a CLMM pool, signed by the admin
+   *
+   * The time-tracker reward is initialized on the pool, funded by the admin, if the pool does not emit it yet
+   */
+  initClPoolGaugeIx({
+    poolId,
+    rewardOpenTime,
+    rewardEndTime,
+    ammConfigIndex = 0,
+    clmmProgramId = CL_SWAP_PROGRAM_ID,
+  }: {
+    poolId: web3.PublicKey
+    rewardOpenTime: bigint
+    rewardEndTime: bigint
+    ammConfigIndex?: number
+    clmmProgramId?: web3.PublicKey
+  }) {
+    const rayClPda = new RayClPda(clmmProgramId)
+    const timeTrackerMint = this.clTimeTrackerMint
+    return instructions.initClPoolGauge(
+      {
+        rewardOpenTime: new BN(rewardOpenTime.toString()),
+        rewardEndTime: new BN(rewardEndTime.toString()),
+      },
+      {
+        admin: this.admin,
+        gaugeConfig: this.address,
+        emissionSchedule: this.emissionSchedule,
+        poolGauge: this.pda.poolGauge({ poolId }),
+        poolState: poolId,
+        timeTrackerMint,
+        funderTokenAccount: getAssociatedTokenAddressSync(timeTrackerMint, this.admin),
+        ammConfig: rayClPda.ammConfig({ index: ammConfigIndex }),
+        operationState: rayClPda.operation(),
+        rewardTokenVault: rayClPda.rewardVault({ pool: poolId, mint: timeTrackerMint }),
+        rewardTokenProgram: TOKEN_PROGRAM_ID,
+        clmmProgram: clmmProgramId,
+        systemProgram: web3.SystemProgram.programId,
+        rent: web3.SYSVAR_RENT_PUBKEY,
+      },
+    )
+  }
+
+  /** Designate the mint of the CLMM reward that tracks time units, signed by the admin */
+  setClTimeTrackerMintIx({ mint }: { mint: web3.PublicKey }) {
+    return instructions.setClTimeTrackerMint(
+      { mint },
+      {
+        admin: this.admin,
+        gaugeConfig: this.address,
+      },
+    )
+  }
+
+  /** Whitelist, kill or revive a pool gauge, signed by the admin */
+  setGaugeStatusIx({ poolId, status }: { poolId: web3.PublicKey; status: types.GaugeStatusKind }) {
+    return instructions.setGaugeStatus(
+      { status },
+      {
+        admin: this.admin,
+        gaugeConfig: this.address,
+        emissionSchedule: this.emissionSchedule,
+        poolGauge: this.pda.poolGauge({ poolId }),
+      },
+    )
+  }
 }
 
 async function loadState(connection: web3.Connection, address: web3.PublicKey) {
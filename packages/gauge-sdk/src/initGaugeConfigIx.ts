@@ -19,6 +19,7 @@ export function initGaugeConfigIx({
   const pda = new GaugePDA(programId)
   const gaugeConfig = pda.globalConfig()
   const rayHopper = pda.globalRayHopper()
+  const emissionSchedule = pda.emissionSchedule()
 
   return instructions.initGlobalConfig(
     { rayEmissionPerDay },
@@ -27,6 +28,7 @@ export function initGaugeConfigIx({
       rayMint,
       gaugeConfig,
       rayHopper,
+      emissionSchedule,
       systemProgram: web3.SystemProgram.programId,
       tokenProgram: TOKEN_PROGRAM_ID,
     },
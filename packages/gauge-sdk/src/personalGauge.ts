@@ -57,6 +57,11 @@ export class PersonalGauge {
     return pda.globalConfig()
   }
 
+  get emissionScheduleAddress() {
+    const pda = new GaugePDA(this.data.programId)
+    return pda.emissionSchedule()
+  }
+
   get account() {
     return this.data.state.account
   }
@@ -77,20 +82,28 @@ export class PersonalGauge {
     return BigInt(this.account.votes.toString())
   }
 
-  changeVotesIx(amount: bigint) {
+  /** Change the votes of the owner, or of a delegate when the vote delegation is given */
+  changeVotesIx(
+    amount: bigint,
+    { delegate, voteDelegation }: { delegate?: web3.PublicKey; voteDelegation?: web3.PublicKey } = {},
+  ) {
     const reactorPda = new ReactorPda({ programId: this.data.reactorProgramId })
     // Get the address of the reactor for this owner
     const reactor = reactorPda.reactor({ owner: this.owner })
+    const pda = new GaugePDA(this.data.programId)
     return instructions.changeVotes(
       {
         amount: new BN(amount.toString()),
       },
       {
-        owner: this.owner,
+        authority: delegate ?? this.owner,
         gaugeConfig: this.gaugeConfigAddress,
+        emissionSchedule: this.emissionScheduleAddress,
         poolGauge: this.poolGaugeAddress,
         personalGauge: this.address,
         reactor,
+        voteDelegation,
+        voteLedger: pda.voteLedger({ reactor }),
         reactorProg: this.data.reactorProgramId,
         sysvarInstruction: SYSVAR_INSTRUCTIONS_PUBKEY,
       },
@@ -112,6 +112,11 @@ export class PersonalRewarderClSdk {
     return gaugePda.globalConfig()
   }
 
+  get emissionScheduleAddress() {
+    const gaugePda = new GaugePDA(this.data.programId)
+    return gaugePda.emissionSchedule()
+  }
+
   get address() {
     const pda = new GaugePDA(this.data.programId)
     return pda.personalRewarderCl({ personalLiqPosition: this.personalPosition })
@@ -152,6 +157,7 @@ export class PersonalRewarderClSdk {
       protocolPosition: this.protocolPosition,
       payer,
       gaugeConfig: this.gaugeConfigAddress,
+      emissionSchedule: this.emissionScheduleAddress,
       clmmProgram: this.clmmProgramId,
       tickArrayLowerLoader: this.tickArrayLowerAccount,
       tickArrayUpperLoader: this.tickArrayUpperAccount,
@@ -61,6 +61,11 @@ export class PersonalRewarderCpSdk {
     return gaugePda.globalConfig()
   }
 
+  get emissionScheduleAddress() {
+    const gaugePda = new GaugePDA(this.data.programId)
+    return gaugePda.emissionSchedule()
+  }
+
   get owner() {
     return this.account.owner
   }
@@ -106,6 +111,7 @@ export class PersonalRewarderCpSdk {
       personalRewarder: this.address,
       payer: this.owner,
       gaugeConfig: this.gaugeConfigAddress,
+      emissionSchedule: this.emissionScheduleAddress,
       liqPosition: liqPositionAddress,
       timeTracker: timeTrackerAddress,
       cpLpEscrowProgram: this.data.cpLpEscrowProgramId,
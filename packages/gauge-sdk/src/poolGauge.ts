@@ -74,6 +74,11 @@ export class PoolGauge {
     return BigInt(this.account.totalRayEmitted.toString())
   }
 
+  /** Pending, Whitelisted or Killed */
+  get status() {
+    return this.account.status.kind
+  }
+
   get address() {
     return this.pda.poolGauge({ poolId: this.poolId })
   }
@@ -82,6 +87,10 @@ export class PoolGauge {
     return this.pda.globalConfig()
   }
 
+  get emissionSchedule() {
+    return this.pda.emissionSchedule()
+  }
+
   async reload(connection: web3.Connection) {
     const state = await loadState(connection, this.address)
     this.data.state = state
@@ -92,6 +101,7 @@ export class PoolGauge {
     return instructions.syncPoolIndex({
       poolGauge: this.address,
       gaugeConfig: this.gaugeConfig,
+      emissionSchedule: this.emissionSchedule,
     })
   }
 
@@ -122,6 +132,7 @@ export class PoolGauge {
       timeTracker,
       cpLpEscrowProgram: this.data.cpLpEscrowProgramId,
       gaugeConfig: this.gaugeConfig,
+      emissionSchedule: this.emissionSchedule,
     })
   }
 
@@ -155,6 +166,7 @@ export class PoolGauge {
     return instructions.clInitPersonalRewarder({
       payer,
       gaugeConfig: this.gaugeConfig,
+      emissionSchedule: this.emissionSchedule,
       personalRewarder,
       personalLiqPosition,
       protocolPosition,
@@ -16,6 +16,19 @@ export interface ReactorFields {
   rayStakeRewards: types.RayStakeRewardsFields
   /** The last seen index for isoRAY accrual */
   lastSeenIndexIsoRay: types.NumberRawFields
+  /** Timestamp of the last isoRAY accrual */
+  lastAccruedTs: BN
+  /** Amount of RAY in active locks, which cannot be withdrawn */
+  lockedRay: BN
+  /** Time-locked portions of the deposited RAY */
+  rayLocks: Array<types.RayLockFields>
+  /**
+   * Whether voting power is derived from RAY locks, decaying to zero at their unlock times,
+   * instead of from RAY + isoRAY
+   */
+  veMode: boolean
+  /** RAY that has left the stake and is waiting out the unstake cooldown */
+  pendingUnstake: types.PendingUnstakeFields
 }
 
 export interface ReactorJSON {
@@ -30,6 +43,19 @@ export interface ReactorJSON {
   rayStakeRewards: types.RayStakeRewardsJSON
   /** The last seen index for isoRAY accrual */
   lastSeenIndexIsoRay: types.NumberRawJSON
+  /** Timestamp of the last isoRAY accrual */
+  lastAccruedTs: string
+  /** Amount of RAY in active locks, which cannot be withdrawn */
+  lockedRay: string
+  /** Time-locked portions of the deposited RAY */
+  rayLocks: Array<types.RayLockJSON>
+  /**
+   * Whether voting power is derived from RAY locks, decaying to zero at their unlock times,
+   * instead of from RAY + isoRAY
+   */
+  veMode: boolean
+  /** RAY that has left the stake and is waiting out the unstake cooldown */
+  pendingUnstake: types.PendingUnstakeJSON
 }
 
 export class Reactor {
@@ -44,6 +70,19 @@ export class Reactor {
   readonly rayStakeRewards: types.RayStakeRewards
   /** The last seen index for isoRAY accrual */
   readonly lastSeenIndexIsoRay: types.NumberRaw
+  /** Timestamp of the last isoRAY accrual */
+  readonly lastAccruedTs: BN
+  /** Amount of RAY in active locks, which cannot be withdrawn */
+  readonly lockedRay: BN
+  /** Time-locked portions of the deposited RAY */
+  readonly rayLocks: Array<types.RayLock>
+  /**
+   * Whether voting power is derived from RAY locks, decaying to zero at their unlock times,
+   * instead of from RAY + isoRAY
+   */
+  readonly veMode: boolean
+  /** RAY that has left the stake and is waiting out the unstake cooldown */
+  readonly pendingUnstake: types.PendingUnstake
 
   static readonly discriminator = Buffer.from([23, 95, 9, 66, 41, 244, 37, 71])
 
@@ -54,6 +93,11 @@ export class Reactor {
     borsh.u64("isoRay"),
     types.RayStakeRewards.layout("rayStakeRewards"),
     types.NumberRaw.layout("lastSeenIndexIsoRay"),
+    borsh.u64("lastAccruedTs"),
+    borsh.u64("lockedRay"),
+    borsh.array(types.RayLock.layout(), 8, "rayLocks"),
+    borsh.bool("veMode"),
+    types.PendingUnstake.layout("pendingUnstake"),
   ])
 
   constructor(fields: ReactorFields) {
@@ -67,6 +111,13 @@ export class Reactor {
     this.lastSeenIndexIsoRay = new types.NumberRaw({
       ...fields.lastSeenIndexIsoRay,
     })
+    this.lastAccruedTs = fields.lastAccruedTs
+    this.lockedRay = fields.lockedRay
+    this.rayLocks = fields.rayLocks.map(
+      (item) => new types.RayLock({ ...item })
+    )
+    this.veMode = fields.veMode
+    this.pendingUnstake = new types.PendingUnstake({ ...fields.pendingUnstake })
   }
 
   static async fetch(
@@ -119,6 +170,15 @@ export class Reactor {
       isoRay: dec.isoRay,
       rayStakeRewards: types.RayStakeRewards.fromDecoded(dec.rayStakeRewards),
       lastSeenIndexIsoRay: types.NumberRaw.fromDecoded(dec.lastSeenIndexIsoRay),
+      lastAccruedTs: dec.lastAccruedTs,
+      lockedRay: dec.lockedRay,
+      rayLocks: dec.rayLocks.map(
+        (
+          item: any /* eslint-disable-line @typescript-eslint/no-explicit-any */
+        ) => types.RayLock.fromDecoded(item)
+      ),
+      veMode: dec.veMode,
+      pendingUnstake: types.PendingUnstake.fromDecoded(dec.pendingUnstake),
     })
   }
 
@@ -130,6 +190,11 @@ export class Reactor {
       isoRay: this.isoRay.toString(),
       rayStakeRewards: this.rayStakeRewards.toJSON(),
       lastSeenIndexIsoRay: this.lastSeenIndexIsoRay.toJSON(),
+      lastAccruedTs: this.lastAccruedTs.toString(),
+      lockedRay: this.lockedRay.toString(),
+      rayLocks: this.rayLocks.map((item) => item.toJSON()),
+      veMode: this.veMode,
+      pendingUnstake: this.pendingUnstake.toJSON(),
     }
   }
 
@@ -141,6 +206,11 @@ export class Reactor {
       isoRay: new BN(obj.isoRay),
       rayStakeRewards: types.RayStakeRewards.fromJSON(obj.rayStakeRewards),
       lastSeenIndexIsoRay: types.NumberRaw.fromJSON(obj.lastSeenIndexIsoRay),
+      lastAccruedTs: new BN(obj.lastAccruedTs),
+      lockedRay: new BN(obj.lockedRay),
+      rayLocks: obj.rayLocks.map((item) => types.RayLock.fromJSON(item)),
+      veMode: obj.veMode,
+      pendingUnstake: types.PendingUnstake.fromJSON(obj.pendingUnstake),
     })
   }
 }
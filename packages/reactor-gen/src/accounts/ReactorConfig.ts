@@ -28,6 +28,24 @@ export interface ReactorConfigFields {
   isoRayIndex: types.NumberRawFields
   /** Bump seed for the PDA */
   bump: Array<number>
+  /** Authority for admin-gated instructions */
+  admin: PublicKey
+  /** Admin proposed by the current admin, pending acceptance */
+  pendingAdmin: PublicKey
+  /** Bitmask of paused actions */
+  pauseFlags: number
+  /** Lifetime RAY funded into the reward hopper */
+  totalRayFunded: BN
+  /**
+   * Lifetime RAY emitted through the reward index
+   * Never exceeds `total_ray_funded`
+   */
+  totalRayEmitted: BN
+  /**
+   * Seconds between requesting an unstake and being able to complete it
+   * While non-zero, RAY can only leave through the unstake queue
+   */
+  unstakeCooldown: BN
 }
 
 export interface ReactorConfigJSON {
@@ -54,6 +72,24 @@ export interface ReactorConfigJSON {
   isoRayIndex: types.NumberRawJSON
   /** Bump seed for the PDA */
   bump: Array<number>
+  /** Authority for admin-gated instructions */
+  admin: string
+  /** Admin proposed by the current admin, pending acceptance */
+  pendingAdmin: string
+  /** Bitmask of paused actions */
+  pauseFlags: number
+  /** Lifetime RAY funded into the reward hopper */
+  totalRayFunded: string
+  /**
+   * Lifetime RAY emitted through the reward index
+   * Never exceeds `total_ray_funded`
+   */
+  totalRayEmitted: string
+  /**
+   * Seconds between requesting an unstake and being able to complete it
+   * While non-zero, RAY can only leave through the unstake queue
+   */
+  unstakeCooldown: string
 }
 
 export class ReactorConfig {
@@ -80,6 +116,24 @@ export class ReactorConfig {
   readonly isoRayIndex: types.NumberRaw
   /** Bump seed for the PDA */
   readonly bump: Array<number>
+  /** Authority for admin-gated instructions */
+  readonly admin: PublicKey
+  /** Admin proposed by the current admin, pending acceptance */
+  readonly pendingAdmin: PublicKey
+  /** Bitmask of paused actions */
+  readonly pauseFlags: number
+  /** Lifetime RAY funded into the reward hopper */
+  readonly totalRayFunded: BN
+  /**
+   * Lifetime RAY emitted through the reward index
+   * Never exceeds `total_ray_funded`
+   */
+  readonly totalRayEmitted: BN
+  /**
+   * Seconds between requesting an unstake and being able to complete it
+   * While non-zero, RAY can only leave through the unstake queue
+   */
+  readonly unstakeCooldown: BN
 
   static readonly discriminator = Buffer.from([
     45, 60, 88, 98, 145, 54, 88, 171,
@@ -96,6 +150,12 @@ export class ReactorConfig {
     borsh.u16("isoRayAprBps"),
     types.NumberRaw.layout("isoRayIndex"),
     borsh.array(borsh.u8(), 1, "bump"),
+    borsh.publicKey("admin"),
+    borsh.publicKey("pendingAdmin"),
+    borsh.u8("pauseFlags"),
+    borsh.u64("totalRayFunded"),
+    borsh.u64("totalRayEmitted"),
+    borsh.u64("unstakeCooldown"),
   ])
 
   constructor(fields: ReactorConfigFields) {
@@ -109,6 +169,12 @@ export class ReactorConfig {
     this.isoRayAprBps = fields.isoRayAprBps
     this.isoRayIndex = new types.NumberRaw({ ...fields.isoRayIndex })
     this.bump = fields.bump
+    this.admin = fields.admin
+    this.pendingAdmin = fields.pendingAdmin
+    this.pauseFlags = fields.pauseFlags
+    this.totalRayFunded = fields.totalRayFunded
+    this.totalRayEmitted = fields.totalRayEmitted
+    this.unstakeCooldown = fields.unstakeCooldown
   }
 
   static async fetch(
@@ -165,6 +231,12 @@ export class ReactorConfig {
       isoRayAprBps: dec.isoRayAprBps,
       isoRayIndex: types.NumberRaw.fromDecoded(dec.isoRayIndex),
       bump: dec.bump,
+      admin: dec.admin,
+      pendingAdmin: dec.pendingAdmin,
+      pauseFlags: dec.pauseFlags,
+      totalRayFunded: dec.totalRayFunded,
+      totalRayEmitted: dec.totalRayEmitted,
+      unstakeCooldown: dec.unstakeCooldown,
     })
   }
 
@@ -180,6 +252,12 @@ export class ReactorConfig {
       isoRayAprBps: this.isoRayAprBps,
       isoRayIndex: this.isoRayIndex.toJSON(),
       bump: this.bump,
+      admin: this.admin.toString(),
+      pendingAdmin: this.pendingAdmin.toString(),
+      pauseFlags: this.pauseFlags,
+      totalRayFunded: this.totalRayFunded.toString(),
+      totalRayEmitted: this.totalRayEmitted.toString(),
+      unstakeCooldown: this.unstakeCooldown.toString(),
     }
   }
 
@@ -195,6 +273,12 @@ export class ReactorConfig {
       isoRayAprBps: obj.isoRayAprBps,
       isoRayIndex: types.NumberRaw.fromJSON(obj.isoRayIndex),
       bump: obj.bump,
+      admin: new PublicKey(obj.admin),
+      pendingAdmin: new PublicKey(obj.pendingAdmin),
+      pauseFlags: obj.pauseFlags,
+      totalRayFunded: new BN(obj.totalRayFunded),
+      totalRayEmitted: new BN(obj.totalRayEmitted),
+      unstakeCooldown: new BN(obj.unstakeCooldown),
     })
   }
 }
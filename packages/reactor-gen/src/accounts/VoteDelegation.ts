@@ -0,0 +1,158 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface VoteDelegationFields {
+  /** Reactor whose votes are delegated */
+  reactor: PublicKey
+  /** Wallet allowed to allocate the reactor's votes */
+  delegate: PublicKey
+  /**
+   * Timestamp after which the delegation no longer applies
+   * 0 means the delegation never expires
+   */
+  expiresAt: BN
+  /**
+   * Number of gauges in the allow-list
+   * 0 means the delegate may vote on any gauge
+   */
+  numAllowedGauges: number
+  /** Pool gauges the delegate may change votes on */
+  allowedGauges: Array<PublicKey>
+}
+
+export interface VoteDelegationJSON {
+  /** Reactor whose votes are delegated */
+  reactor: string
+  /** Wallet allowed to allocate the reactor's votes */
+  delegate: string
+  /**
+   * Timestamp after which the delegation no longer applies
+   * 0 means the delegation never expires
+   */
+  expiresAt: string
+  /**
+   * Number of gauges in the allow-list
+   * 0 means the delegate may vote on any gauge
+   */
+  numAllowedGauges: number
+  /** Pool gauges the delegate may change votes on */
+  allowedGauges: Array<string>
+}
+
+/**
+ * Authorizes a delegate to lock and unlock a reactor's votes through the gauge program
+ * Created by the reactor owner, one per reactor
+ */
+export class VoteDelegation {
+  /** Reactor whose votes are delegated */
+  readonly reactor: PublicKey
+  /** Wallet allowed to allocate the reactor's votes */
+  readonly delegate: PublicKey
+  /**
+   * Timestamp after which the delegation no longer applies
+   * 0 means the delegation never expires
+   */
+  readonly expiresAt: BN
+  /**
+   * Number of gauges in the allow-list
+   * 0 means the delegate may vote on any gauge
+   */
+  readonly numAllowedGauges: number
+  /** Pool gauges the delegate may change votes on */
+  readonly allowedGauges: Array<PublicKey>
+
+  static readonly discriminator = Buffer.from([
+    193, 49, 139, 13, 145, 199, 236, 31,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.publicKey("reactor"),
+    borsh.publicKey("delegate"),
+    borsh.u64("expiresAt"),
+    borsh.u8("numAllowedGauges"),
+    borsh.array(borsh.publicKey(), 8, "allowedGauges"),
+  ])
+
+  constructor(fields: VoteDelegationFields) {
+    this.reactor = fields.reactor
+    this.delegate = fields.delegate
+    this.expiresAt = fields.expiresAt
+    this.numAllowedGauges = fields.numAllowedGauges
+    this.allowedGauges = fields.allowedGauges
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<VoteDelegation | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<VoteDelegation | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): VoteDelegation {
+    if (!data.slice(0, 8).equals(VoteDelegation.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = VoteDelegation.layout.decode(data.slice(8))
+
+    return new VoteDelegation({
+      reactor: dec.reactor,
+      delegate: dec.delegate,
+      expiresAt: dec.expiresAt,
+      numAllowedGauges: dec.numAllowedGauges,
+      allowedGauges: dec.allowedGauges,
+    })
+  }
+
+  toJSON(): VoteDelegationJSON {
+    return {
+      reactor: this.reactor.toString(),
+      delegate: this.delegate.toString(),
+      expiresAt: this.expiresAt.toString(),
+      numAllowedGauges: this.numAllowedGauges,
+      allowedGauges: this.allowedGauges.map((item) => item.toString()),
+    }
+  }
+
+  static fromJSON(obj: VoteDelegationJSON): VoteDelegation {
+    return new VoteDelegation({
+      reactor: new PublicKey(obj.reactor),
+      delegate: new PublicKey(obj.delegate),
+      expiresAt: new BN(obj.expiresAt),
+      numAllowedGauges: obj.numAllowedGauges,
+      allowedGauges: obj.allowedGauges.map((item) => new PublicKey(item)),
+    })
+  }
+}
@@ -2,3 +2,5 @@ export { ReactorConfig } from "./ReactorConfig"
 export type { ReactorConfigFields, ReactorConfigJSON } from "./ReactorConfig"
 export { Reactor } from "./Reactor"
 export type { ReactorFields, ReactorJSON } from "./Reactor"
+export { VoteDelegation } from "./VoteDelegation"
+export type { VoteDelegationFields, VoteDelegationJSON } from "./VoteDelegation"
@@ -7,6 +7,24 @@ export type CustomError =
   | InsufficientVotesToUnlock
   | InsufficientVotesToWithdraw
   | NotAdmin
+  | NotPendingAdmin
+  | InvalidPauseFlags
+  | DepositsPaused
+  | WithdrawalsPaused
+  | ClaimsPaused
+  | RayLocked
+  | InsufficientRayToLock
+  | InvalidRayLockDuration
+  | TooManyRayLocks
+  | VotesLockedDuringModeChange
+  | InsufficientDecayedVotes
+  | UnstakeCooldownRequired
+  | NoPendingUnstake
+  | UnstakeCooldownActive
+  | NotVoteAuthority
+  | TooManyDelegationGauges
+  | ReactorNotEmpty
+  | VoteDelegationActive
 
 export class IsoRayNotUpToDate extends Error {
   static readonly code = 6000
@@ -96,6 +114,207 @@ export class NotAdmin extends Error {
   }
 }
 
+export class NotPendingAdmin extends Error {
+  static readonly code = 6008
+  readonly code = 6008
+  readonly name = "NotPendingAdmin"
+  readonly msg = "Not pending admin"
+
+  constructor(readonly logs?: string[]) {
+    super("6008: Not pending admin")
+  }
+}
+
+export class InvalidPauseFlags extends Error {
+  static readonly code = 6009
+  readonly code = 6009
+  readonly name = "InvalidPauseFlags"
+  readonly msg = "Unknown pause flags"
+
+  constructor(readonly logs?: string[]) {
+    super("6009: Unknown pause flags")
+  }
+}
+
+export class DepositsPaused extends Error {
+  static readonly code = 6010
+  readonly code = 6010
+  readonly name = "DepositsPaused"
+  readonly msg = "Deposits are paused"
+
+  constructor(readonly logs?: string[]) {
+    super("6010: Deposits are paused")
+  }
+}
+
+export class WithdrawalsPaused extends Error {
+  static readonly code = 6011
+  readonly code = 6011
+  readonly name = "WithdrawalsPaused"
+  readonly msg = "Withdrawals are paused"
+
+  constructor(readonly logs?: string[]) {
+    super("6011: Withdrawals are paused")
+  }
+}
+
+export class ClaimsPaused extends Error {
+  static readonly code = 6012
+  readonly code = 6012
+  readonly name = "ClaimsPaused"
+  readonly msg = "Reward claims are paused"
+
+  constructor(readonly logs?: string[]) {
+    super("6012: Reward claims are paused")
+  }
+}
+
+export class RayLocked extends Error {
+  static readonly code = 6013
+  readonly code = 6013
+  readonly name = "RayLocked"
+  readonly msg = "RAY is locked until its unlock time"
+
+  constructor(readonly logs?: string[]) {
+    super("6013: RAY is locked until its unlock time")
+  }
+}
+
+export class InsufficientRayToLock extends Error {
+  static readonly code = 6014
+  readonly code = 6014
+  readonly name = "InsufficientRayToLock"
+  readonly msg = "Insufficient unlocked RAY to lock"
+
+  constructor(readonly logs?: string[]) {
+    super("6014: Insufficient unlocked RAY to lock")
+  }
+}
+
+export class InvalidRayLockDuration extends Error {
+  static readonly code = 6015
+  readonly code = 6015
+  readonly name = "InvalidRayLockDuration"
+  readonly msg =
+    "Lock duration must be positive and at most the maximum duration"
+
+  constructor(readonly logs?: string[]) {
+    super(
+      "6015: Lock duration must be positive and at most the maximum duration"
+    )
+  }
+}
+
+export class TooManyRayLocks extends Error {
+  static readonly code = 6016
+  readonly code = 6016
+  readonly name = "TooManyRayLocks"
+  readonly msg = "Too many active RAY locks"
+
+  constructor(readonly logs?: string[]) {
+    super("6016: Too many active RAY locks")
+  }
+}
+
+export class VotesLockedDuringModeChange extends Error {
+  static readonly code = 6017
+  readonly code = 6017
+  readonly name = "VotesLockedDuringModeChange"
+  readonly msg = "Cannot change vote mode while votes are locked"
+
+  constructor(readonly logs?: string[]) {
+    super("6017: Cannot change vote mode while votes are locked")
+  }
+}
+
+export class InsufficientDecayedVotes extends Error {
+  static readonly code = 6018
+  readonly code = 6018
+  readonly name = "InsufficientDecayedVotes"
+  readonly msg = "Insufficient decayed votes to release"
+
+  constructor(readonly logs?: string[]) {
+    super("6018: Insufficient decayed votes to release")
+  }
+}
+
+export class UnstakeCooldownRequired extends Error {
+  static readonly code = 6019
+  readonly code = 6019
+  readonly name = "UnstakeCooldownRequired"
+  readonly msg = "Withdrawals must go through the unstake cooldown"
+
+  constructor(readonly logs?: string[]) {
+    super("6019: Withdrawals must go through the unstake cooldown")
+  }
+}
+
+export class NoPendingUnstake extends Error {
+  static readonly code = 6020
+  readonly code = 6020
+  readonly name = "NoPendingUnstake"
+  readonly msg = "No pending unstake"
+
+  constructor(readonly logs?: string[]) {
+    super("6020: No pending unstake")
+  }
+}
+
+export class UnstakeCooldownActive extends Error {
+  static readonly code = 6021
+  readonly code = 6021
+  readonly name = "UnstakeCooldownActive"
+  readonly msg = "Unstake cooldown has not passed"
+
+  constructor(readonly logs?: string[]) {
+    super("6021: Unstake cooldown has not passed")
+  }
+}
+
+export class NotVoteAuthority extends Error {
+  static readonly code = 6022
+  readonly code = 6022
+  readonly name = "NotVoteAuthority"
+  readonly msg = "Signer is neither the reactor owner nor its vote delegate"
+
+  constructor(readonly logs?: string[]) {
+    super("6022: Signer is neither the reactor owner nor its vote delegate")
+  }
+}
+
+export class TooManyDelegationGauges extends Error {
+  static readonly code = 6023
+  readonly code = 6023
+  readonly name = "TooManyDelegationGauges"
+  readonly msg = "Too many gauges in the delegation allow-list"
+
+  constructor(readonly logs?: string[]) {
+    super("6023: Too many gauges in the delegation allow-list")
+  }
+}
+
+export class ReactorNotEmpty extends Error {
+  static readonly code = 6024
+  readonly code = 6024
+  readonly name = "ReactorNotEmpty"
+  readonly msg = "Reactor still holds RAY, isoRAY, votes or rewards"
+
+  constructor(readonly logs?: string[]) {
+    super("6024: Reactor still holds RAY, isoRAY, votes or rewards")
+  }
+}
+
+export class VoteDelegationActive extends Error {
+  static readonly code = 6025
+  readonly code = 6025
+  readonly name = "VoteDelegationActive"
+  readonly msg = "Vote delegation must be revoked first"
+
+  constructor(readonly logs?: string[]) {
+    super("6025: Vote delegation must be revoked first")
+  }
+}
+
 export function fromCode(code: number, logs?: string[]): CustomError | null {
   switch (code) {
     case 6000:
@@ -114,6 +333,42 @@ export function fromCode(code: number, logs?: string[]): CustomError | null {
       return new InsufficientVotesToWithdraw(logs)
     case 6007:
       return new NotAdmin(logs)
+    case 6008:
+      return new NotPendingAdmin(logs)
+    case 6009:
+      return new InvalidPauseFlags(logs)
+    case 6010:
+      return new DepositsPaused(logs)
+    case 6011:
+      return new WithdrawalsPaused(logs)
+    case 6012:
+      return new ClaimsPaused(logs)
+    case 6013:
+      return new RayLocked(logs)
+    case 6014:
+      return new InsufficientRayToLock(logs)
+    case 6015:
+      return new InvalidRayLockDuration(logs)
+    case 6016:
+      return new TooManyRayLocks(logs)
+    case 6017:
+      return new VotesLockedDuringModeChange(logs)
+    case 6018:
+      return new InsufficientDecayedVotes(logs)
+    case 6019:
+      return new UnstakeCooldownRequired(logs)
+    case 6020:
+      return new NoPendingUnstake(logs)
+    case 6021:
+      return new UnstakeCooldownActive(logs)
+    case 6022:
+      return new NotVoteAuthority(logs)
+    case 6023:
+      return new TooManyDelegationGauges(logs)
+    case 6024:
+      return new ReactorNotEmpty(logs)
+    case 6025:
+      return new VoteDelegationActive(logs)
   }
 
   return null
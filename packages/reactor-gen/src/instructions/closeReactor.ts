@@ -0,0 +1,32 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface CloseReactorAccounts {
+  owner: PublicKey
+  reactor: PublicKey
+  reactorConfig: PublicKey
+  /** A vote delegation must be revoked first, so that it cannot govern a re-opened reactor */
+  voteDelegation: PublicKey
+  destination: PublicKey
+}
+
+/** Close an empty reactor and refund its rent */
+export function closeReactor(
+  accounts: CloseReactorAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.owner, isSigner: true, isWritable: false },
+    { pubkey: accounts.reactor, isSigner: false, isWritable: true },
+    { pubkey: accounts.reactorConfig, isSigner: false, isWritable: true },
+    { pubkey: accounts.voteDelegation, isSigner: false, isWritable: false },
+    { pubkey: accounts.destination, isSigner: false, isWritable: true },
+  ]
+  const identifier = Buffer.from([38, 223, 4, 166, 81, 81, 144, 150])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,33 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface CompleteUnstakeAccounts {
+  owner: PublicKey
+  reactor: PublicKey
+  rayVault: PublicKey
+  rayDst: PublicKey
+  reactorConfig: PublicKey
+  tokenProgram: PublicKey
+}
+
+/** Release RAY whose unstake cooldown has passed */
+export function completeUnstake(
+  accounts: CompleteUnstakeAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.owner, isSigner: true, isWritable: true },
+    { pubkey: accounts.reactor, isSigner: false, isWritable: true },
+    { pubkey: accounts.rayVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.rayDst, isSigner: false, isWritable: true },
+    { pubkey: accounts.reactorConfig, isSigner: false, isWritable: false },
+    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([79, 98, 40, 241, 100, 30, 25, 234])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
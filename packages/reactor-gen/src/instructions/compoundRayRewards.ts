@@ -0,0 +1,33 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface CompoundRayRewardsAccounts {
+  owner: PublicKey
+  reactor: PublicKey
+  reactorConfig: PublicKey
+  rayRewardHopper: PublicKey
+  rayVault: PublicKey
+  tokenProgram: PublicKey
+}
+
+/** Update the reactor's global indexes and restake the earned RAY rewards */
+export function compoundRayRewards(
+  accounts: CompoundRayRewardsAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.owner, isSigner: true, isWritable: false },
+    { pubkey: accounts.reactor, isSigner: false, isWritable: true },
+    { pubkey: accounts.reactorConfig, isSigner: false, isWritable: true },
+    { pubkey: accounts.rayRewardHopper, isSigner: false, isWritable: true },
+    { pubkey: accounts.rayVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([244, 141, 136, 136, 57, 134, 147, 40])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
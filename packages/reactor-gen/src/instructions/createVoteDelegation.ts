@@ -0,0 +1,51 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface CreateVoteDelegationArgs {
+  delegate: PublicKey
+  expiresAt: BN
+  allowedGauges: Array<PublicKey>
+}
+
+export interface CreateVoteDelegationAccounts {
+  owner: PublicKey
+  reactor: PublicKey
+  voteDelegation: PublicKey
+  systemProgram: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.publicKey("delegate"),
+  borsh.u64("expiresAt"),
+  borsh.vec(borsh.publicKey(), "allowedGauges"),
+])
+
+/** Authorize a delegate to lock and unlock the reactor's votes through the gauge program */
+export function createVoteDelegation(
+  args: CreateVoteDelegationArgs,
+  accounts: CreateVoteDelegationAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.owner, isSigner: true, isWritable: true },
+    { pubkey: accounts.reactor, isSigner: false, isWritable: false },
+    { pubkey: accounts.voteDelegation, isSigner: false, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([186, 252, 91, 127, 162, 111, 170, 232])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      delegate: args.delegate,
+      expiresAt: args.expiresAt,
+      allowedGauges: args.allowedGauges,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,46 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface FundRewardHopperArgs {
+  amount: BN
+}
+
+export interface FundRewardHopperAccounts {
+  /** Permissionless funder */
+  funder: PublicKey
+  raySrc: PublicKey
+  reactorConfig: PublicKey
+  rayRewardHopper: PublicKey
+  tokenProgram: PublicKey
+}
+
+export const layout = borsh.struct([borsh.u64("amount")])
+
+/** Fund the reward hopper with RAY to be emitted to stakers */
+export function fundRewardHopper(
+  args: FundRewardHopperArgs,
+  accounts: FundRewardHopperAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.funder, isSigner: true, isWritable: false },
+    { pubkey: accounts.raySrc, isSigner: false, isWritable: true },
+    { pubkey: accounts.reactorConfig, isSigner: false, isWritable: true },
+    { pubkey: accounts.rayRewardHopper, isSigner: false, isWritable: true },
+    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([96, 147, 191, 164, 69, 174, 165, 128])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      amount: args.amount,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
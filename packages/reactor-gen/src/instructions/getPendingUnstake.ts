@@ -0,0 +1,23 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface GetPendingUnstakeAccounts {
+  reactor: PublicKey
+}
+
+/** View the RAY waiting out the unstake cooldown */
+export function getPendingUnstake(
+  accounts: GetPendingUnstakeAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.reactor, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([201, 44, 68, 92, 15, 208, 102, 159])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -1,18 +1,73 @@
 export { initConfig } from "./initConfig"
 export type { InitConfigArgs, InitConfigAccounts } from "./initConfig"
+export { setRayRewardDailyEmission } from "./setRayRewardDailyEmission"
+export type {
+  SetRayRewardDailyEmissionArgs,
+  SetRayRewardDailyEmissionAccounts,
+} from "./setRayRewardDailyEmission"
+export { setIsoRayAprBps } from "./setIsoRayAprBps"
+export type {
+  SetIsoRayAprBpsArgs,
+  SetIsoRayAprBpsAccounts,
+} from "./setIsoRayAprBps"
+export { proposeAdmin } from "./proposeAdmin"
+export type { ProposeAdminArgs, ProposeAdminAccounts } from "./proposeAdmin"
+export { acceptAdmin } from "./acceptAdmin"
+export type { AcceptAdminAccounts } from "./acceptAdmin"
+export { setPauseFlags } from "./setPauseFlags"
+export type { SetPauseFlagsArgs, SetPauseFlagsAccounts } from "./setPauseFlags"
+export { setUnstakeCooldown } from "./setUnstakeCooldown"
+export type {
+  SetUnstakeCooldownArgs,
+  SetUnstakeCooldownAccounts,
+} from "./setUnstakeCooldown"
 export { initReactor } from "./initReactor"
 export type { InitReactorAccounts } from "./initReactor"
 export { depositRay } from "./depositRay"
 export type { DepositRayArgs, DepositRayAccounts } from "./depositRay"
 export { withdrawRay } from "./withdrawRay"
 export type { WithdrawRayArgs, WithdrawRayAccounts } from "./withdrawRay"
+export { requestUnstake } from "./requestUnstake"
+export type {
+  RequestUnstakeArgs,
+  RequestUnstakeAccounts,
+} from "./requestUnstake"
+export { completeUnstake } from "./completeUnstake"
+export type { CompleteUnstakeAccounts } from "./completeUnstake"
+export { getPendingUnstake } from "./getPendingUnstake"
+export type { GetPendingUnstakeAccounts } from "./getPendingUnstake"
+export { lockRay } from "./lockRay"
+export type { LockRayArgs, LockRayAccounts } from "./lockRay"
+export { createVoteDelegation } from "./createVoteDelegation"
+export type {
+  CreateVoteDelegationArgs,
+  CreateVoteDelegationAccounts,
+} from "./createVoteDelegation"
+export { revokeVoteDelegation } from "./revokeVoteDelegation"
+export type { RevokeVoteDelegationAccounts } from "./revokeVoteDelegation"
 export { lockVotes } from "./lockVotes"
 export type { LockVotesArgs, LockVotesAccounts } from "./lockVotes"
 export { unlockVotes } from "./unlockVotes"
 export type { UnlockVotesArgs, UnlockVotesAccounts } from "./unlockVotes"
+export { releaseDecayedVotes } from "./releaseDecayedVotes"
+export type {
+  ReleaseDecayedVotesArgs,
+  ReleaseDecayedVotesAccounts,
+} from "./releaseDecayedVotes"
+export { setVeMode } from "./setVeMode"
+export type { SetVeModeArgs, SetVeModeAccounts } from "./setVeMode"
+export { closeReactor } from "./closeReactor"
+export type { CloseReactorAccounts } from "./closeReactor"
 export { syncReactor } from "./syncReactor"
 export type { SyncReactorAccounts } from "./syncReactor"
 export { syncAndCollectRayRewards } from "./syncAndCollectRayRewards"
 export type { SyncAndCollectRayRewardsAccounts } from "./syncAndCollectRayRewards"
+export { fundRewardHopper } from "./fundRewardHopper"
+export type {
+  FundRewardHopperArgs,
+  FundRewardHopperAccounts,
+} from "./fundRewardHopper"
 export { collectRayRewards } from "./collectRayRewards"
 export type { CollectRayRewardsAccounts } from "./collectRayRewards"
+export { compoundRayRewards } from "./compoundRayRewards"
+export type { CompoundRayRewardsAccounts } from "./compoundRayRewards"
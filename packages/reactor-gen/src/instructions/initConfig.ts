@@ -10,6 +10,7 @@ export interface InitConfigArgs {
 }
 
 export interface InitConfigAccounts {
+  /** The deployer key bootstraps the config and becomes its first admin */
   payer: PublicKey
   config: PublicKey
   /** Vault to hold RAY tokens that are staked by Reactor depositors */
@@ -0,0 +1,43 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface LockRayArgs {
+  amount: BN
+  duration: BN
+}
+
+export interface LockRayAccounts {
+  owner: PublicKey
+  reactor: PublicKey
+  reactorConfig: PublicKey
+}
+
+export const layout = borsh.struct([borsh.u64("amount"), borsh.u64("duration")])
+
+/** Lock deposited RAY until `duration` seconds from now, boosting its isoRAY accrual */
+export function lockRay(
+  args: LockRayArgs,
+  accounts: LockRayAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.owner, isSigner: true, isWritable: false },
+    { pubkey: accounts.reactor, isSigner: false, isWritable: true },
+    { pubkey: accounts.reactorConfig, isSigner: false, isWritable: true },
+  ]
+  const identifier = Buffer.from([133, 118, 19, 74, 15, 102, 191, 92])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      amount: args.amount,
+      duration: args.duration,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
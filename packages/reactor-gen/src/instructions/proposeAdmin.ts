@@ -0,0 +1,39 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface ProposeAdminArgs {
+  newAdmin: PublicKey
+}
+
+export interface ProposeAdminAccounts {
+  admin: PublicKey
+  reactorConfig: PublicKey
+}
+
+export const layout = borsh.struct([borsh.publicKey("newAdmin")])
+
+/** Propose a new admin for the reactor config */
+export function proposeAdmin(
+  args: ProposeAdminArgs,
+  accounts: ProposeAdminAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.admin, isSigner: true, isWritable: false },
+    { pubkey: accounts.reactorConfig, isSigner: false, isWritable: true },
+  ]
+  const identifier = Buffer.from([121, 214, 199, 212, 87, 39, 117, 234])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      newAdmin: args.newAdmin,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,39 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface ReleaseDecayedVotesArgs {
+  amount: BN
+}
+
+export interface ReleaseDecayedVotesAccounts {
+  reactor: PublicKey
+  sysvarInstruction: PublicKey
+}
+
+export const layout = borsh.struct([borsh.u64("amount")])
+
+/** Release Reactor votes that have decayed in ve-mode */
+export function releaseDecayedVotes(
+  args: ReleaseDecayedVotesArgs,
+  accounts: ReleaseDecayedVotesAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.reactor, isSigner: false, isWritable: true },
+    { pubkey: accounts.sysvarInstruction, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([252, 139, 22, 134, 74, 68, 200, 172])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      amount: args.amount,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
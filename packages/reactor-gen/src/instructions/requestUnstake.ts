@@ -0,0 +1,41 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface RequestUnstakeArgs {
+  amount: BN
+}
+
+export interface RequestUnstakeAccounts {
+  owner: PublicKey
+  reactor: PublicKey
+  reactorConfig: PublicKey
+}
+
+export const layout = borsh.struct([borsh.u64("amount")])
+
+/** Move RAY out of the stake, to be released after the unstake cooldown */
+export function requestUnstake(
+  args: RequestUnstakeArgs,
+  accounts: RequestUnstakeAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.owner, isSigner: true, isWritable: false },
+    { pubkey: accounts.reactor, isSigner: false, isWritable: true },
+    { pubkey: accounts.reactorConfig, isSigner: false, isWritable: true },
+  ]
+  const identifier = Buffer.from([44, 154, 110, 253, 160, 202, 54, 34])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      amount: args.amount,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,27 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface RevokeVoteDelegationAccounts {
+  owner: PublicKey
+  reactor: PublicKey
+  voteDelegation: PublicKey
+}
+
+/** Revoke the reactor's vote delegation */
+export function revokeVoteDelegation(
+  accounts: RevokeVoteDelegationAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.owner, isSigner: true, isWritable: true },
+    { pubkey: accounts.reactor, isSigner: false, isWritable: false },
+    { pubkey: accounts.voteDelegation, isSigner: false, isWritable: true },
+  ]
+  const identifier = Buffer.from([16, 242, 6, 134, 204, 214, 127, 184])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,39 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface SetIsoRayAprBpsArgs {
+  isoRayAprBps: number
+}
+
+export interface SetIsoRayAprBpsAccounts {
+  admin: PublicKey
+  reactorConfig: PublicKey
+}
+
+export const layout = borsh.struct([borsh.u16("isoRayAprBps")])
+
+/** Change the isoRAY APR, settling the indexes at the old rate first */
+export function setIsoRayAprBps(
+  args: SetIsoRayAprBpsArgs,
+  accounts: SetIsoRayAprBpsAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.admin, isSigner: true, isWritable: false },
+    { pubkey: accounts.reactorConfig, isSigner: false, isWritable: true },
+  ]
+  const identifier = Buffer.from([171, 176, 119, 64, 255, 148, 17, 78])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      isoRayAprBps: args.isoRayAprBps,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,39 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface SetRayRewardDailyEmissionArgs {
+  rayRewardDailyEmission: BN
+}
+
+export interface SetRayRewardDailyEmissionAccounts {
+  admin: PublicKey
+  reactorConfig: PublicKey
+}
+
+export const layout = borsh.struct([borsh.u64("rayRewardDailyEmission")])
+
+/** Change the daily RAY reward emission, settling the indexes at the old rate first */
+export function setRayRewardDailyEmission(
+  args: SetRayRewardDailyEmissionArgs,
+  accounts: SetRayRewardDailyEmissionAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.admin, isSigner: true, isWritable: false },
+    { pubkey: accounts.reactorConfig, isSigner: false, isWritable: true },
+  ]
+  const identifier = Buffer.from([220, 75, 83, 83, 30, 195, 250, 217])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      rayRewardDailyEmission: args.rayRewardDailyEmission,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,39 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface SetUnstakeCooldownArgs {
+  unstakeCooldown: BN
+}
+
+export interface SetUnstakeCooldownAccounts {
+  admin: PublicKey
+  reactorConfig: PublicKey
+}
+
+export const layout = borsh.struct([borsh.u64("unstakeCooldown")])
+
+/** Set the cooldown between requesting and completing an unstake */
+export function setUnstakeCooldown(
+  args: SetUnstakeCooldownArgs,
+  accounts: SetUnstakeCooldownAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.admin, isSigner: true, isWritable: false },
+    { pubkey: accounts.reactorConfig, isSigner: false, isWritable: true },
+  ]
+  const identifier = Buffer.from([207, 135, 220, 96, 48, 146, 197, 126])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      unstakeCooldown: args.unstakeCooldown,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
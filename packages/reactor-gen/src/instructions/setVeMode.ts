@@ -0,0 +1,39 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface SetVeModeArgs {
+  veMode: boolean
+}
+
+export interface SetVeModeAccounts {
+  owner: PublicKey
+  reactor: PublicKey
+}
+
+export const layout = borsh.struct([borsh.bool("veMode")])
+
+/** Opt in or out of ve-mode voting power */
+export function setVeMode(
+  args: SetVeModeArgs,
+  accounts: SetVeModeAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.owner, isSigner: true, isWritable: false },
+    { pubkey: accounts.reactor, isSigner: false, isWritable: true },
+  ]
+  const identifier = Buffer.from([214, 51, 123, 138, 88, 68, 141, 14])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      veMode: args.veMode,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
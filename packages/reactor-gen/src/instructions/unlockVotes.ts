@@ -9,8 +9,10 @@ export interface UnlockVotesArgs {
 }
 
 export interface UnlockVotesAccounts {
-  owner: PublicKey
+  /** Reactor owner, or its delegate when a vote delegation is provided */
+  authority: PublicKey
   reactor: PublicKey
+  voteDelegation?: PublicKey
   sysvarInstruction: PublicKey
 }
 
@@ -23,8 +25,11 @@ export function unlockVotes(
   programId: PublicKey = PROGRAM_ID
 ) {
   const keys: Array<AccountMeta> = [
-    { pubkey: accounts.owner, isSigner: true, isWritable: true },
+    { pubkey: accounts.authority, isSigner: true, isWritable: true },
     { pubkey: accounts.reactor, isSigner: false, isWritable: true },
+    accounts.voteDelegation
+      ? { pubkey: accounts.voteDelegation, isSigner: false, isWritable: false }
+      : { pubkey: programId, isSigner: false, isWritable: false },
     { pubkey: accounts.sysvarInstruction, isSigner: false, isWritable: false },
   ]
   const identifier = Buffer.from([31, 4, 235, 111, 176, 15, 112, 106])
@@ -0,0 +1,77 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface PendingUnstakeFields {
+  /** Amount of RAY waiting to be released */
+  amount: BN
+  /**
+   * Timestamp at which the RAY can be released
+   * Each new request restarts the cooldown for the whole amount
+   */
+  unlockTs: BN
+}
+
+export interface PendingUnstakeJSON {
+  /** Amount of RAY waiting to be released */
+  amount: string
+  /**
+   * Timestamp at which the RAY can be released
+   * Each new request restarts the cooldown for the whole amount
+   */
+  unlockTs: string
+}
+
+/** RAY requested for unstaking, which no longer accrues isoRAY nor rewards */
+export class PendingUnstake {
+  /** Amount of RAY waiting to be released */
+  readonly amount: BN
+  /**
+   * Timestamp at which the RAY can be released
+   * Each new request restarts the cooldown for the whole amount
+   */
+  readonly unlockTs: BN
+
+  constructor(fields: PendingUnstakeFields) {
+    this.amount = fields.amount
+    this.unlockTs = fields.unlockTs
+  }
+
+  static layout(property?: string) {
+    return borsh.struct([borsh.u64("amount"), borsh.u64("unlockTs")], property)
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new PendingUnstake({
+      amount: obj.amount,
+      unlockTs: obj.unlockTs,
+    })
+  }
+
+  static toEncodable(fields: PendingUnstakeFields) {
+    return {
+      amount: fields.amount,
+      unlockTs: fields.unlockTs,
+    }
+  }
+
+  toJSON(): PendingUnstakeJSON {
+    return {
+      amount: this.amount.toString(),
+      unlockTs: this.unlockTs.toString(),
+    }
+  }
+
+  static fromJSON(obj: PendingUnstakeJSON): PendingUnstake {
+    return new PendingUnstake({
+      amount: new BN(obj.amount),
+      unlockTs: new BN(obj.unlockTs),
+    })
+  }
+
+  toEncodable() {
+    return PendingUnstake.toEncodable(this)
+  }
+}
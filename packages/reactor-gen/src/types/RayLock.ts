@@ -0,0 +1,85 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface RayLockFields {
+  /** Amount of RAY locked */
+  amount: BN
+  /** Timestamp at which the lock expires */
+  unlockTs: BN
+  /** Bonus on isoRAY accrual for the locked RAY while the lock is active */
+  bonusBps: number
+}
+
+export interface RayLockJSON {
+  /** Amount of RAY locked */
+  amount: string
+  /** Timestamp at which the lock expires */
+  unlockTs: string
+  /** Bonus on isoRAY accrual for the locked RAY while the lock is active */
+  bonusBps: number
+}
+
+/**
+ * A portion of the reactor's RAY that cannot be withdrawn until `unlock_ts`
+ * An empty slot has an `amount` of 0
+ */
+export class RayLock {
+  /** Amount of RAY locked */
+  readonly amount: BN
+  /** Timestamp at which the lock expires */
+  readonly unlockTs: BN
+  /** Bonus on isoRAY accrual for the locked RAY while the lock is active */
+  readonly bonusBps: number
+
+  constructor(fields: RayLockFields) {
+    this.amount = fields.amount
+    this.unlockTs = fields.unlockTs
+    this.bonusBps = fields.bonusBps
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [borsh.u64("amount"), borsh.u64("unlockTs"), borsh.u16("bonusBps")],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new RayLock({
+      amount: obj.amount,
+      unlockTs: obj.unlockTs,
+      bonusBps: obj.bonusBps,
+    })
+  }
+
+  static toEncodable(fields: RayLockFields) {
+    return {
+      amount: fields.amount,
+      unlockTs: fields.unlockTs,
+      bonusBps: fields.bonusBps,
+    }
+  }
+
+  toJSON(): RayLockJSON {
+    return {
+      amount: this.amount.toString(),
+      unlockTs: this.unlockTs.toString(),
+      bonusBps: this.bonusBps,
+    }
+  }
+
+  static fromJSON(obj: RayLockJSON): RayLock {
+    return new RayLock({
+      amount: new BN(obj.amount),
+      unlockTs: new BN(obj.unlockTs),
+      bonusBps: obj.bonusBps,
+    })
+  }
+
+  toEncodable() {
+    return RayLock.toEncodable(this)
+  }
+}
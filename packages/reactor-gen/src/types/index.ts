@@ -1,5 +1,9 @@
 export { NumberRaw } from "./NumberRaw"
 export type { NumberRawFields, NumberRawJSON } from "./NumberRaw"
+export { PendingUnstake } from "./PendingUnstake"
+export type { PendingUnstakeFields, PendingUnstakeJSON } from "./PendingUnstake"
+export { RayLock } from "./RayLock"
+export type { RayLockFields, RayLockJSON } from "./RayLock"
 export { RayStakeRewards } from "./RayStakeRewards"
 export type {
   RayStakeRewardsFields,
@@ -123,7 +123,7 @@ export class ReactorSdk {
   ixLockVotes({ amount }: { amount: bigint }) {
     return instructions.lockVotes(
       { amount: new BN(amount.toString()) },
-      { owner: this.owner, reactor: this.selfAddress, sysvarInstruction: SYSVAR_INSTRUCTIONS_PUBKEY },
+      { authority: this.owner, reactor: this.selfAddress, sysvarInstruction: SYSVAR_INSTRUCTIONS_PUBKEY },
     )
   }
 
@@ -131,7 +131,7 @@ export class ReactorSdk {
   ixUnlockVotes({ amount }: { amount: bigint }) {
     return instructions.unlockVotes(
       { amount: new BN(amount.toString()) },
-      { owner: this.owner, reactor: this.selfAddress, sysvarInstruction: SYSVAR_INSTRUCTIONS_PUBKEY },
+      { authority: this.owner, reactor: this.selfAddress, sysvarInstruction: SYSVAR_INSTRUCTIONS_PUBKEY },
     )
   }
 
@@ -0,0 +1,226 @@
+//! Pretty-printing of the program accounts, with `Number` values shown as decimals
+
+use anchor_lang::{prelude::Pubkey, Discriminator};
+use anyhow::{bail, Result};
+use precise_number::Number;
+use ray_gauges_client::{
+    accounts::{deserialize, Gauge, GaugeConfig, Reactor, TimeTracker},
+    projection::ReactorRewards,
+};
+use std::fmt::Display;
+
+/// Decode an account of any supported type from its discriminator and print it
+pub fn print_account(address: &Pubkey, data: &[u8]) -> Result<()> {
+    let discriminator = data.get(..8).unwrap_or_default();
+
+    if discriminator == Reactor::DISCRIMINATOR {
+        print_reactor(&deserialize(data)?);
+    } else if discriminator == Gauge::DISCRIMINATOR {
+        print_gauge(&deserialize(data)?);
+    } else if discriminator == GaugeConfig::DISCRIMINATOR {
+        print_gauge_config(&deserialize(data)?);
+    } else if discriminator == TimeTracker::DISCRIMINATOR {
+        print_time_tracker(&deserialize(data)?);
+    } else {
+        bail!("{address} is not a Reactor, Gauge, GaugeConfig or TimeTracker account");
+    }
+
+    Ok(())
+}
+
+pub fn print_reactor(reactor: &Reactor) {
+    title("Reactor");
+    field("owner", reactor.owner);
+    field("ray", reactor.ray);
+    field("locked_votes", reactor.locked_votes);
+    field("iso_ray", reactor.iso_ray);
+    field(
+        "uncollected_ray_reward",
+        reactor.ray_stake_rewards.uncollected_ray_reward,
+    );
+    field(
+        "ray_reward_last_seen_index",
+        decimal(reactor.ray_stake_rewards.last_seen_index.into()),
+    );
+    field(
+        "last_seen_index_iso_ray",
+        decimal(reactor.last_seen_index_iso_ray.into()),
+    );
+    field("last_accrued_ts", reactor.last_accrued_ts);
+    field("locked_ray", reactor.locked_ray);
+    for (i, lock) in reactor
+        .ray_locks
+        .iter()
+        .enumerate()
+        .filter(|(_, l)| !l.is_empty())
+    {
+        field(
+            &format!("ray_locks[{i}]"),
+            format!(
+                "{} until {} (+{} bps)",
+                lock.amount, lock.unlock_ts, lock.bonus_bps
+            ),
+        );
+    }
+    field("ve_mode", reactor.ve_mode);
+    field(
+        "pending_unstake",
+        format!(
+            "{} at {}",
+            reactor.pending_unstake.amount, reactor.pending_unstake.unlock_ts
+        ),
+    );
+}
+
+/// Rewards of a reactor projected to the cluster time
+pub fn print_reactor_rewards(rewards: &ReactorRewards, now: u64) {
+    title(&format!("Projected at {now}"));
+    field("iso_ray", rewards.iso_ray);
+    field("ray_rewards", rewards.ray_rewards);
+}
+
+pub fn print_gauge(gauge: &Gauge) {
+    title("Gauge");
+    field("pool_id", gauge.pool_id);
+    field("status", format!("{:?}", gauge.status));
+    field("total_votes", gauge.total_votes);
+    field(
+        "last_seen_global_index",
+        decimal(gauge.last_seen_global_index.into()),
+    );
+    field("total_ray_emitted", gauge.total_ray_emitted);
+    field("max_vote_share_bps", gauge.max_vote_share_bps);
+    field(
+        "last_seen_total_emission",
+        decimal(gauge.last_seen_total_emission.into()),
+    );
+    field("pending_votes_delta", gauge.pending_votes_delta);
+    field("pending_epoch", gauge.pending_epoch);
+    let history = &gauge.vote_history;
+    for checkpoint in &history.checkpoints[..history.num_checkpoints as usize] {
+        field(
+            "vote_history",
+            format!("{} from epoch {}", checkpoint.votes, checkpoint.from_epoch),
+        );
+    }
+    field("time_tracker_reward_index", gauge.time_tracker_reward_index);
+}
+
+pub fn print_gauge_config(gauge_config: &GaugeConfig) {
+    title("GaugeConfig");
+    field("admin", gauge_config.admin);
+    field("pending_admin", gauge_config.pending_admin);
+    field("pause_flags", format!("{:#010b}", gauge_config.pause_flags));
+    field("ray_hopper", gauge_config.ray_hopper);
+    field("emission_schedule", gauge_config.emission_schedule);
+    field("ray_emission_per_day", gauge_config.ray_emission_per_day);
+    field("remaining_budget", gauge_config.remaining_budget);
+    field("total_votes", gauge_config.total_votes);
+    field("index", decimal(gauge_config.index.into()));
+    field(
+        "total_emission",
+        decimal(gauge_config.total_emission.into()),
+    );
+    field("last_updated_ts", gauge_config.last_updated_ts);
+    field("max_vote_share_bps", gauge_config.max_vote_share_bps);
+    field("epoch_length", gauge_config.epoch_length);
+    field("current_epoch", gauge_config.current_epoch);
+    field("epoch_end_ts", gauge_config.epoch_end_ts);
+    field("pending_votes_delta", gauge_config.pending_votes_delta);
+    field("cl_time_tracker_mint", gauge_config.cl_time_tracker_mint);
+}
+
+pub fn print_time_tracker(time_tracker: &TimeTracker) {
+    title("TimeTracker");
+    field("pool_id", time_tracker.pool_id);
+    field("escrow", time_tracker.escrow);
+    field("index", decimal(time_tracker.get_index().into()));
+    field("total_lp_deposited", time_tracker.total_lp_deposited);
+    field("last_seen_ts", time_tracker.last_seen_ts);
+}
+
+/// Decimal representation of a `Number`, without trailing zeros
+pub fn decimal(number: Number) -> String {
+    let scale = Number::DENOM.ilog10() as usize;
+    let digits = format!("{:0>width$}", number.to_string(), width = scale + 1);
+    let (int, frac) = digits.split_at(digits.len() - scale);
+    let frac = frac.trim_end_matches('0');
+
+    if frac.is_empty() {
+        int.to_string()
+    } else {
+        format!("{int}.{frac}")
+    }
+}
+
+fn title(name: &str) {
+    println!("{name}");
+}
+
+fn field(name: &str, value: impl Display) {
+    println!("  {name:<28}{value}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::AccountSerialize;
+    use ray_gauges_client::reactor::state::{PendingUnstake, RayStakeRewards};
+
+    fn serialize(account: &impl AccountSerialize) -> Vec<u8> {
+        let mut data = Vec::new();
+        account.try_serialize(&mut data).unwrap();
+        data
+    }
+
+    #[test]
+    fn test_print_account() {
+        let address = Pubkey::new_unique();
+
+        let reactor = Reactor {
+            owner: Pubkey::new_unique(),
+            ray: 100,
+            locked_votes: 0,
+            iso_ray: 0,
+            last_seen_index_iso_ray: Number::ZERO.into(),
+            ray_stake_rewards: RayStakeRewards {
+                last_seen_index: Number::ZERO.into(),
+                uncollected_ray_reward: 0,
+            },
+            last_accrued_ts: 0,
+            locked_ray: 0,
+            ray_locks: Default::default(),
+            ve_mode: false,
+            pending_unstake: PendingUnstake::default(),
+        };
+        let time_tracker = TimeTracker::new(Pubkey::new_unique(), Pubkey::new_unique(), [0], 0);
+
+        for data in [
+            serialize(&reactor),
+            serialize(&Gauge::default()),
+            serialize(&GaugeConfig::default()),
+            serialize(&time_tracker),
+        ] {
+            print_account(&address, &data).unwrap();
+
+            // truncated accounts do not decode
+            assert!(print_account(&address, &data[..data.len() - 1]).is_err());
+        }
+
+        // unsupported or missing discriminators
+        let mut data = serialize(&Gauge::default());
+        data[0] ^= 1;
+        assert!(print_account(&address, &data).is_err());
+        assert!(print_account(&address, &[]).is_err());
+    }
+
+    #[test]
+    fn test_decimal() {
+        assert_eq!(decimal(Number::ZERO), "0");
+        assert_eq!(decimal(Number::from_natural_u64(42)), "42");
+        assert_eq!(decimal(Number::from_ratio(1, 4)), "0.25");
+        assert_eq!(decimal(Number::from_ratio(180, 300)), "0.6");
+        assert_eq!(decimal(Number::from_ratio(5, 2)), "2.5");
+        assert_eq!(decimal(Number([1, 0, 0, 0])), "0.000000000001");
+    }
+}
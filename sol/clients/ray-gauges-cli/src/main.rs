@@ -0,0 +1,436 @@
+//! Command-line tool for the gauge, reactor and cp-lp-escrow programs
+//!
+//! Amounts are in base units of the token. Pools are given by their pool state.
+
+mod inspect;
+mod rpc;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_spl::{associated_token::get_associated_token_address, token::TokenAccount};
+use anyhow::Result;
+use clap::{Args, Parser, Subcommand};
+use ray_gauges_client::{
+    accounts::{
+        EmissionSchedule, Gauge, GaugeConfig, PersonalPosition, PersonalRewarderCp, Reactor,
+        ReactorConfig, TimeTracker,
+    },
+    instructions::{escrow, gauge, reactor},
+    pda,
+    projection::{cp_staged_ray, reactor_rewards, CpRewardAccounts},
+    reactor::ray_mint,
+};
+use rpc::Rpc;
+
+#[derive(Parser)]
+#[command(name = "ray-gauges", version, about)]
+struct Cli {
+    /// RPC endpoint
+    #[arg(
+        long,
+        short = 'u',
+        global = true,
+        default_value = "http://127.0.0.1:8899"
+    )]
+    url: String,
+
+    /// Keypair signing and paying for transactions, only read by commands that need it
+    #[arg(
+        long,
+        short = 'k',
+        global = true,
+        default_value = "~/.config/solana/id.json"
+    )]
+    keypair: String,
+
+    /// Simulate the transaction instead of sending it
+    #[arg(long, global = true)]
+    dry_run: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Stake RAY in the reactor
+    #[command(subcommand)]
+    Reactor(ReactorCommand),
+
+    /// Vote on and sync pool gauges
+    #[command(subcommand)]
+    Gauge(GaugeCommand),
+
+    /// Escrow CP LP tokens
+    #[command(subcommand)]
+    Escrow(EscrowCommand),
+
+    /// Earn RAY on escrowed CP LP tokens
+    #[command(subcommand)]
+    Rewarder(RewarderCommand),
+
+    /// Admin instructions
+    #[command(subcommand)]
+    Admin(AdminCommand),
+
+    /// Print a Reactor, Gauge, GaugeConfig or TimeTracker account
+    Inspect { address: Pubkey },
+}
+
+#[derive(Subcommand)]
+enum ReactorCommand {
+    /// Create the reactor of the keypair
+    Init,
+    /// Deposit RAY from the associated token account
+    Deposit { amount: u64 },
+    /// Withdraw RAY to the associated token account, only while there is no unstake cooldown
+    Withdraw { amount: u64 },
+    /// Start unstaking RAY, released once the unstake cooldown has passed
+    RequestUnstake { amount: u64 },
+    /// Release the unstaked RAY to the associated token account once the cooldown has passed
+    CompleteUnstake,
+    /// Sync and collect the RAY rewards to the associated token account
+    Collect,
+    /// Print a reactor with its rewards projected to now, the keypair's by default
+    Show { owner: Option<Pubkey> },
+}
+
+#[derive(Subcommand)]
+enum GaugeCommand {
+    /// Change the votes on a pool, negative to remove votes
+    Vote {
+        #[arg(long)]
+        pool: Pubkey,
+        /// Votes to add, negative to remove
+        #[arg(allow_hyphen_values = true)]
+        amount: i64,
+        /// Owner of the reactor, when voting as its delegate
+        #[arg(long)]
+        owner: Option<Pubkey>,
+    },
+    /// Create the personal gauge of the keypair on a pool
+    InitPersonal {
+        #[arg(long)]
+        pool: Pubkey,
+    },
+    /// Sync the global index and the gauges of the pools
+    Sync {
+        #[arg(long = "pool")]
+        pools: Vec<Pubkey>,
+    },
+    /// Print the gauge of a pool
+    Show {
+        #[arg(long)]
+        pool: Pubkey,
+    },
+    /// Print the gauge config
+    Config,
+}
+
+#[derive(Subcommand)]
+enum EscrowCommand {
+    /// Create the personal position of the keypair on a pool
+    Init {
+        #[arg(long)]
+        pool: Pubkey,
+    },
+    /// Deposit LP from the associated token account
+    Deposit {
+        #[arg(long)]
+        pool: Pubkey,
+        amount: u64,
+    },
+    /// Withdraw LP to the associated token account
+    Withdraw {
+        #[arg(long)]
+        pool: Pubkey,
+        amount: u64,
+        /// Also collect the RAY earned on the pool, through the personal rewarder
+        #[arg(long)]
+        collect_ray: bool,
+    },
+    /// Print the time tracker of a pool
+    Show {
+        #[arg(long)]
+        pool: Pubkey,
+    },
+}
+
+#[derive(Subcommand)]
+enum RewarderCommand {
+    /// Create the personal rewarder of the keypair on a pool
+    Init {
+        #[arg(long)]
+        pool: Pubkey,
+    },
+    /// Accrue the RAY earned on a pool
+    Accrue {
+        #[arg(long)]
+        pool: Pubkey,
+        /// Owner of the position, the keypair's by default
+        #[arg(long)]
+        owner: Option<Pubkey>,
+    },
+    /// Accrue and claim the RAY earned on a pool to the associated token account
+    Claim {
+        #[arg(long)]
+        pool: Pubkey,
+    },
+    /// Print the RAY earned on a pool, projected to now
+    Pending {
+        #[arg(long)]
+        pool: Pubkey,
+        /// Owner of the position, the keypair's by default
+        #[arg(long)]
+        owner: Option<Pubkey>,
+    },
+}
+
+#[derive(Subcommand)]
+enum AdminCommand {
+    /// Initialize the config of a program
+    #[command(subcommand)]
+    InitConfig(InitConfigCommand),
+}
+
+#[derive(Subcommand)]
+enum InitConfigCommand {
+    Gauge(GaugeConfigArgs),
+    Reactor(ReactorConfigArgs),
+}
+
+#[derive(Args)]
+struct GaugeConfigArgs {
+    #[arg(long)]
+    ray_emission_per_day: u64,
+}
+
+#[derive(Args)]
+struct ReactorConfigArgs {
+    #[arg(long)]
+    ray_reward_daily_emission: u64,
+    #[arg(long)]
+    iso_ray_apr_bps: u16,
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+
+    let rpc = Rpc::new(cli.url, cli.keypair, cli.dry_run);
+
+    match cli.command {
+        Command::Reactor(command) => run_reactor(&rpc, command),
+        Command::Gauge(command) => run_gauge(&rpc, command),
+        Command::Escrow(command) => run_escrow(&rpc, command),
+        Command::Rewarder(command) => run_rewarder(&rpc, command),
+        Command::Admin(AdminCommand::InitConfig(command)) => run_init_config(&rpc, command),
+        Command::Inspect { address } => {
+            inspect::print_account(&address, &rpc.fetch_data(&address)?)
+        }
+    }
+}
+
+fn run_reactor(rpc: &Rpc, command: ReactorCommand) -> Result<()> {
+    let ray_ata = |owner: &Pubkey| get_associated_token_address(owner, &ray_mint::ID);
+
+    match command {
+        ReactorCommand::Init => {
+            let owner = rpc.payer()?;
+            rpc.send(&[reactor::init_reactor(owner, owner)])
+        }
+        ReactorCommand::Deposit { amount } => {
+            let owner = rpc.payer()?;
+            rpc.send(&[reactor::deposit_ray(owner, ray_ata(&owner), amount)])
+        }
+        ReactorCommand::Withdraw { amount } => {
+            let owner = rpc.payer()?;
+            rpc.send(&[reactor::withdraw_ray(owner, ray_ata(&owner), amount)])
+        }
+        ReactorCommand::RequestUnstake { amount } => {
+            let owner = rpc.payer()?;
+            rpc.send(&[reactor::request_unstake(owner, amount)])
+        }
+        ReactorCommand::CompleteUnstake => {
+            let owner = rpc.payer()?;
+            rpc.send(&[reactor::complete_unstake(owner, ray_ata(&owner))])
+        }
+        ReactorCommand::Collect => {
+            let owner = rpc.payer()?;
+            rpc.send(&[reactor::sync_and_collect_ray_rewards(
+                owner,
+                ray_ata(&owner),
+            )])
+        }
+        ReactorCommand::Show { owner } => {
+            let owner = match owner {
+                Some(owner) => owner,
+                None => rpc.payer()?,
+            };
+            let reactor: Reactor = rpc.fetch(&pda::reactor(&owner).0)?;
+            let reactor_config: ReactorConfig = rpc.fetch(&pda::reactor_config().0)?;
+            let now = rpc.now()?;
+
+            inspect::print_reactor(&reactor);
+            inspect::print_reactor_rewards(&reactor_rewards(&reactor, &reactor_config, now)?, now);
+            Ok(())
+        }
+    }
+}
+
+fn run_gauge(rpc: &Rpc, command: GaugeCommand) -> Result<()> {
+    match command {
+        GaugeCommand::Vote {
+            pool,
+            amount,
+            owner,
+        } => {
+            let authority = rpc.payer()?;
+            rpc.send(&[gauge::change_votes(
+                authority,
+                owner.unwrap_or(authority),
+                pool,
+                amount,
+            )])
+        }
+        GaugeCommand::InitPersonal { pool } => {
+            let authority = rpc.payer()?;
+            rpc.send(&[gauge::init_personal_gauge(authority, authority, pool)])
+        }
+        GaugeCommand::Sync { pools } => rpc.send(&[gauge::sync_gauges(&pools)]),
+        GaugeCommand::Show { pool } => {
+            inspect::print_gauge(&rpc.fetch(&pda::pool_gauge(&pool).0)?);
+            Ok(())
+        }
+        GaugeCommand::Config => {
+            inspect::print_gauge_config(&rpc.fetch(&pda::gauge_config().0)?);
+            Ok(())
+        }
+    }
+}
+
+fn run_escrow(rpc: &Rpc, command: EscrowCommand) -> Result<()> {
+    match command {
+        EscrowCommand::Init { pool } => {
+            let owner = rpc.payer()?;
+            rpc.send(&[escrow::init_personal_position(owner, pool)])
+        }
+        EscrowCommand::Deposit { pool, amount } => {
+            let owner = rpc.payer()?;
+            let lp_src = lp_ata(rpc, &owner, &pool)?;
+            rpc.send(&[escrow::deposit(owner, pool, lp_src, amount)])
+        }
+        EscrowCommand::Withdraw {
+            pool,
+            amount,
+            collect_ray,
+        } => {
+            let owner = rpc.payer()?;
+            let lp_dst = lp_ata(rpc, &owner, &pool)?;
+            if !collect_ray {
+                return rpc.send(&[escrow::withdraw(owner, pool, lp_dst, amount)]);
+            }
+
+            let ray_dst = get_associated_token_address(&owner, &ray_mint::ID);
+            rpc.send(&[gauge::cp_withdraw_lp(owner, pool, lp_dst, ray_dst, amount)])
+        }
+        EscrowCommand::Show { pool } => {
+            inspect::print_time_tracker(&rpc.fetch(&pda::time_tracker(&pool).0)?);
+            Ok(())
+        }
+    }
+}
+
+fn run_rewarder(rpc: &Rpc, command: RewarderCommand) -> Result<()> {
+    match command {
+        RewarderCommand::Init { pool } => {
+            let payer = rpc.payer()?;
+            rpc.send(&[gauge::cp_init_personal_rewarder(payer, pool)])
+        }
+        RewarderCommand::Accrue { pool, owner } => {
+            let payer = rpc.payer()?;
+            rpc.send(&[gauge::cp_accrue_ray(payer, owner.unwrap_or(payer), pool)])
+        }
+        RewarderCommand::Claim { pool } => {
+            let payer = rpc.payer()?;
+            let ray_dst = get_associated_token_address(&payer, &ray_mint::ID);
+            rpc.send(&[
+                gauge::cp_accrue_ray(payer, payer, pool),
+                gauge::cp_withdraw_ray(payer, pool, ray_dst),
+            ])
+        }
+        RewarderCommand::Pending { pool, owner } => {
+            let owner = match owner {
+                Some(owner) => owner,
+                None => rpc.payer()?,
+            };
+            let pool_gauge = pda::pool_gauge(&pool).0;
+            let time_tracker = pda::time_tracker(&pool).0;
+
+            let gauge_config: GaugeConfig = rpc.fetch(&pda::gauge_config().0)?;
+            let emission_schedule: EmissionSchedule = rpc.fetch(&pda::emission_schedule().0)?;
+            let pool_gauge_account: Gauge = rpc.fetch(&pool_gauge)?;
+            let time_tracker_account: TimeTracker = rpc.fetch(&time_tracker)?;
+            let personal_position: PersonalPosition =
+                rpc.fetch(&pda::personal_position(&time_tracker, &owner).0)?;
+            let personal_rewarder: PersonalRewarderCp =
+                rpc.fetch(&pda::personal_rewarder_cp(&pool_gauge, &owner).0)?;
+            let now = rpc.now()?;
+
+            let staged_ray = cp_staged_ray(
+                &CpRewardAccounts {
+                    gauge_config: &gauge_config,
+                    emission_schedule: &emission_schedule,
+                    pool_gauge: &pool_gauge_account,
+                    time_tracker: &time_tracker_account,
+                    personal_position: &personal_position,
+                    personal_rewarder: &personal_rewarder,
+                },
+                now,
+            )?;
+            println!("{staged_ray}");
+            Ok(())
+        }
+    }
+}
+
+fn run_init_config(rpc: &Rpc, command: InitConfigCommand) -> Result<()> {
+    let payer = rpc.payer()?;
+
+    match command {
+        InitConfigCommand::Gauge(args) => {
+            rpc.send(&[gauge::init_global_config(payer, args.ray_emission_per_day)])
+        }
+        InitConfigCommand::Reactor(args) => rpc.send(&[reactor::init_config(
+            payer,
+            args.ray_reward_daily_emission,
+            args.iso_ray_apr_bps,
+        )]),
+    }
+}
+
+/// Associated token account of the LP mint held by the pool's escrow
+fn lp_ata(rpc: &Rpc, owner: &Pubkey, pool: &Pubkey) -> Result<Pubkey> {
+    let escrow: TokenAccount = rpc.fetch(&pda::lp_escrow(pool).0)?;
+    Ok(get_associated_token_address(owner, &escrow.mint))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_unstake() {
+        let cli = Cli::try_parse_from(["ray-gauges", "reactor", "request-unstake", "5"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Command::Reactor(ReactorCommand::RequestUnstake { amount: 5 })
+        ));
+
+        let cli = Cli::try_parse_from(["ray-gauges", "reactor", "complete-unstake"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Command::Reactor(ReactorCommand::CompleteUnstake)
+        ));
+
+        assert!(Cli::try_parse_from(["ray-gauges", "reactor", "request-unstake"]).is_err());
+    }
+}
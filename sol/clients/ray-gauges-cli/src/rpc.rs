@@ -0,0 +1,100 @@
+use anchor_lang::{prelude::Pubkey, AccountDeserialize};
+use anyhow::{anyhow, bail, Context, Result};
+use ray_gauges_client::{accounts::deserialize, Instruction};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    account::from_account,
+    clock::Clock,
+    commitment_config::CommitmentConfig,
+    signature::{read_keypair_file, Keypair, Signer},
+    sysvar,
+    transaction::Transaction,
+};
+use std::cell::OnceCell;
+
+/// RPC connection and the keypair signing and paying for transactions
+/// The keypair is only read once a command needs it, so read-only commands work without it
+pub struct Rpc {
+    client: RpcClient,
+    keypair_path: String,
+    payer: OnceCell<Keypair>,
+    dry_run: bool,
+}
+
+impl Rpc {
+    pub fn new(url: String, keypair_path: String, dry_run: bool) -> Self {
+        Self {
+            client: RpcClient::new_with_commitment(url, CommitmentConfig::confirmed()),
+            keypair_path,
+            payer: OnceCell::new(),
+            dry_run,
+        }
+    }
+
+    fn keypair(&self) -> Result<&Keypair> {
+        if let Some(payer) = self.payer.get() {
+            return Ok(payer);
+        }
+
+        let payer = read_keypair_file(expand_home(&self.keypair_path))
+            .map_err(|err| anyhow!("cannot read keypair {}: {err}", self.keypair_path))?;
+        Ok(self.payer.get_or_init(|| payer))
+    }
+
+    pub fn payer(&self) -> Result<Pubkey> {
+        Ok(self.keypair()?.pubkey())
+    }
+
+    /// Send the instructions in one transaction, or only simulate it in dry-run mode
+    pub fn send(&self, ixs: &[Instruction]) -> Result<()> {
+        let payer = self.keypair()?;
+        let blockhash = self.client.get_latest_blockhash()?;
+        let tx =
+            Transaction::new_signed_with_payer(ixs, Some(&payer.pubkey()), &[payer], blockhash);
+
+        if !self.dry_run {
+            let signature = self.client.send_and_confirm_transaction_with_spinner(&tx)?;
+            println!("{signature}");
+            return Ok(());
+        }
+
+        let result = self.client.simulate_transaction(&tx)?.value;
+        for log in result.logs.unwrap_or_default() {
+            println!("{log}");
+        }
+        if let Some(units) = result.units_consumed {
+            println!("compute units: {units}");
+        }
+        if let Some(err) = result.err {
+            bail!("simulation failed: {err}");
+        }
+
+        Ok(())
+    }
+
+    pub fn fetch<T: AccountDeserialize>(&self, address: &Pubkey) -> Result<T> {
+        let data = self.fetch_data(address)?;
+        deserialize(&data).map_err(|err| anyhow!("cannot decode {address}: {err}"))
+    }
+
+    pub fn fetch_data(&self, address: &Pubkey) -> Result<Vec<u8>> {
+        self.client
+            .get_account_data(address)
+            .with_context(|| format!("cannot fetch {address}"))
+    }
+
+    /// Cluster time, from the clock sysvar
+    pub fn now(&self) -> Result<u64> {
+        let account = self.client.get_account(&sysvar::clock::ID)?;
+        let clock: Clock = from_account(&account).context("cannot decode the clock")?;
+
+        Ok(clock.unix_timestamp as u64)
+    }
+}
+
+fn expand_home(path: &str) -> String {
+    match (path.strip_prefix("~/"), std::env::var("HOME")) {
+        (Some(rest), Ok(home)) => format!("{home}/{rest}"),
+        _ => path.to_string(),
+    }
+}
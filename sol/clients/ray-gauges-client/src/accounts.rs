@@ -0,0 +1,41 @@
+//! Deserializers for the on-chain state
+//!
+//! The state types are re-exported from the programs
+
+use anchor_lang::{AccountDeserialize, Result};
+
+pub use cp_lp_escrow::state::{PersonalPosition, TimeTracker};
+pub use gauge::state::{
+    personal_rewarder_cl::PersonalRewarderCl, Bribe, BribeClaim, EmissionSchedule, Gauge,
+    GaugeConfig, PersonalGauge, PersonalRewarderCp, VoteLedger,
+};
+pub use reactor::state::{Reactor, ReactorConfig, VoteDelegation};
+
+/// Deserialize an account, checking its discriminator
+pub fn deserialize<T: AccountDeserialize>(data: &[u8]) -> Result<T> {
+    T::try_deserialize(&mut &data[..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::{prelude::Pubkey, AccountSerialize};
+
+    #[test]
+    fn test_deserialize() {
+        let claim = BribeClaim {
+            bribe: Pubkey::new_unique(),
+            personal_gauge: Pubkey::new_unique(),
+            amount: 42,
+        };
+        let mut data = Vec::new();
+        claim.try_serialize(&mut data).unwrap();
+
+        let decoded: BribeClaim = deserialize(&data).unwrap();
+        assert_eq!(decoded.bribe, claim.bribe);
+        assert_eq!(decoded.amount, 42);
+
+        // wrong discriminator
+        assert!(deserialize::<Bribe>(&data).is_err());
+    }
+}
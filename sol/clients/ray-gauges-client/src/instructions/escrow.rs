@@ -0,0 +1,107 @@
+//! cp-lp-escrow instructions, keyed by the CP pool state
+
+use super::build;
+use crate::pda;
+use anchor_lang::{prelude::Pubkey, solana_program::instruction::Instruction, system_program};
+use anchor_spl::token;
+use cp_lp_escrow::{accounts, instruction};
+
+pub fn init_escrow(payer: Pubkey, pool_state: Pubkey, lp_mint: Pubkey) -> Instruction {
+    build(
+        cp_lp_escrow::ID,
+        accounts::InitEscrow {
+            payer,
+            pool_state,
+            lp_mint,
+            time_tracker: pda::time_tracker(&pool_state).0,
+            escrow: pda::lp_escrow(&pool_state).0,
+            system_program: system_program::ID,
+            token_program: token::ID,
+        },
+        instruction::InitEscrow,
+    )
+}
+
+pub fn init_personal_position(owner: Pubkey, pool_state: Pubkey) -> Instruction {
+    let time_tracker = pda::time_tracker(&pool_state).0;
+    build(
+        cp_lp_escrow::ID,
+        accounts::InitPersonalPosition {
+            owner,
+            time_tracker,
+            personal_position: pda::personal_position(&time_tracker, &owner).0,
+            system_program: system_program::ID,
+        },
+        instruction::InitPersonalPosition,
+    )
+}
+
+/// Deposit `amount` LP from `lp_src`
+pub fn deposit(owner: Pubkey, pool_state: Pubkey, lp_src: Pubkey, amount: u64) -> Instruction {
+    let time_tracker = pda::time_tracker(&pool_state).0;
+    build(
+        cp_lp_escrow::ID,
+        accounts::Deposit {
+            owner,
+            time_tracker,
+            personal_position: pda::personal_position(&time_tracker, &owner).0,
+            escrow: pda::lp_escrow(&pool_state).0,
+            lp_src,
+            token_program: token::ID,
+        },
+        instruction::Deposit { amount },
+    )
+}
+
+/// Withdraw `amount` LP to `lp_dst`
+/// Positions with a gauge rewarder should use `gauge::cp_withdraw_lp` instead, so their RAY is accrued
+pub fn withdraw(owner: Pubkey, pool_state: Pubkey, lp_dst: Pubkey, amount: u64) -> Instruction {
+    let time_tracker = pda::time_tracker(&pool_state).0;
+    build(
+        cp_lp_escrow::ID,
+        accounts::Withdraw {
+            owner,
+            time_tracker,
+            personal_position: pda::personal_position(&time_tracker, &owner).0,
+            escrow: pda::lp_escrow(&pool_state).0,
+            lp_dst,
+            token_program: token::ID,
+        },
+        instruction::Withdraw { amount },
+    )
+}
+
+/// Close an empty personal position, refunding the rent to `destination`
+/// The gauge personal rewarder of the position must be closed first
+pub fn close_personal_position(
+    owner: Pubkey,
+    pool_state: Pubkey,
+    destination: Pubkey,
+) -> Instruction {
+    let time_tracker = pda::time_tracker(&pool_state).0;
+    let pool_gauge = pda::pool_gauge(&pool_state).0;
+    build(
+        cp_lp_escrow::ID,
+        accounts::ClosePersonalPosition {
+            owner,
+            time_tracker,
+            personal_position: pda::personal_position(&time_tracker, &owner).0,
+            pool_gauge,
+            personal_rewarder: pda::personal_rewarder_cp(&pool_gauge, &owner).0,
+            destination,
+        },
+        instruction::ClosePersonalPosition,
+    )
+}
+
+pub fn update_personal_position(owner: Pubkey, pool_state: Pubkey) -> Instruction {
+    let time_tracker = pda::time_tracker(&pool_state).0;
+    build(
+        cp_lp_escrow::ID,
+        accounts::UpdatePersonalPosition {
+            time_tracker,
+            personal_position: pda::personal_position(&time_tracker, &owner).0,
+        },
+        instruction::UpdatePersonalPosition,
+    )
+}
@@ -0,0 +1,852 @@
+//! Gauge instructions
+//!
+//! Pools are passed by their pool state, the CP or CLMM pool the gauge is keyed by
+
+use super::{build, with_remaining};
+use crate::pda;
+use anchor_lang::{
+    prelude::{AccountMeta, Pubkey},
+    solana_program::{instruction::Instruction, sysvar},
+    system_program,
+};
+use anchor_spl::token;
+use gauge::{
+    accounts, instruction,
+    state::{EmissionSegment, GaugeStatus},
+};
+
+/// Accounts of a CLMM personal position, updated by the CLMM program before accruing
+pub struct ClPosition {
+    pub pool_state: Pubkey,
+    pub personal_position: Pubkey,
+    pub protocol_position: Pubkey,
+    pub tick_array_lower: Pubkey,
+    pub tick_array_upper: Pubkey,
+}
+
+/// CLMM accounts used to initialize the time-tracker reward of a pool
+pub struct ClTimeTrackerReward {
+    pub time_tracker_mint: Pubkey,
+    pub funder_token_account: Pubkey,
+    pub amm_config: Pubkey,
+    pub operation_state: Pubkey,
+    pub reward_token_vault: Pubkey,
+    pub reward_token_program: Pubkey,
+}
+
+pub fn init_global_config(payer: Pubkey, ray_emission_per_day: u64) -> Instruction {
+    build(
+        gauge::ID,
+        accounts::InitGaugeConfig {
+            payer,
+            gauge_config: pda::gauge_config().0,
+            ray_hopper: pda::gauge_ray_hopper().0,
+            emission_schedule: pda::emission_schedule().0,
+            ray_mint: reactor::ray_mint::ID,
+            token_program: token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::InitGlobalConfig {
+            ray_emission_per_day,
+        },
+    )
+}
+
+pub fn propose_admin(admin: Pubkey, new_admin: Pubkey) -> Instruction {
+    build(
+        gauge::ID,
+        accounts::ProposeAdmin {
+            admin,
+            gauge_config: pda::gauge_config().0,
+        },
+        instruction::ProposeAdmin { new_admin },
+    )
+}
+
+pub fn accept_admin(pending_admin: Pubkey) -> Instruction {
+    build(
+        gauge::ID,
+        accounts::AcceptAdmin {
+            pending_admin,
+            gauge_config: pda::gauge_config().0,
+        },
+        instruction::AcceptAdmin,
+    )
+}
+
+pub fn set_pause_flags(admin: Pubkey, pause_flags: u8) -> Instruction {
+    build(
+        gauge::ID,
+        accounts::SetPauseFlags {
+            admin,
+            gauge_config: pda::gauge_config().0,
+        },
+        instruction::SetPauseFlags { pause_flags },
+    )
+}
+
+pub fn set_emission_schedule(admin: Pubkey, segments: Vec<EmissionSegment>) -> Instruction {
+    build(
+        gauge::ID,
+        accounts::SetEmissionSchedule {
+            admin,
+            gauge_config: pda::gauge_config().0,
+            emission_schedule: pda::emission_schedule().0,
+        },
+        instruction::SetEmissionSchedule { segments },
+    )
+}
+
+/// Set the global cap, syncing the given pools under the old cap first
+pub fn set_max_vote_share_bps(
+    admin: Pubkey,
+    max_vote_share_bps: u16,
+    pools: &[Pubkey],
+) -> Instruction {
+    let ix = build(
+        gauge::ID,
+        accounts::SetMaxVoteShareBps {
+            admin,
+            gauge_config: pda::gauge_config().0,
+            emission_schedule: pda::emission_schedule().0,
+        },
+        instruction::SetMaxVoteShareBps { max_vote_share_bps },
+    );
+
+    with_remaining(
+        ix,
+        pools
+            .iter()
+            .map(|pool| AccountMeta::new(pda::pool_gauge(pool).0, false)),
+    )
+}
+
+pub fn set_gauge_max_vote_share_bps(
+    admin: Pubkey,
+    pool: Pubkey,
+    max_vote_share_bps: u16,
+) -> Instruction {
+    build(
+        gauge::ID,
+        accounts::SetGaugeMaxVoteShareBps {
+            admin,
+            gauge_config: pda::gauge_config().0,
+            emission_schedule: pda::emission_schedule().0,
+            pool_gauge: pda::pool_gauge(&pool).0,
+        },
+        instruction::SetGaugeMaxVoteShareBps { max_vote_share_bps },
+    )
+}
+
+pub fn set_gauge_status(admin: Pubkey, pool: Pubkey, status: GaugeStatus) -> Instruction {
+    build(
+        gauge::ID,
+        accounts::SetGaugeStatus {
+            admin,
+            gauge_config: pda::gauge_config().0,
+            emission_schedule: pda::emission_schedule().0,
+            pool_gauge: pda::pool_gauge(&pool).0,
+        },
+        instruction::SetGaugeStatus { status },
+    )
+}
+
+pub fn set_cl_time_tracker_mint(admin: Pubkey, mint: Pubkey) -> Instruction {
+    build(
+        gauge::ID,
+        accounts::SetClTimeTrackerMint {
+            admin,
+            gauge_config: pda::gauge_config().0,
+        },
+        instruction::SetClTimeTrackerMint { mint },
+    )
+}
+
+pub fn set_epoch_length(admin: Pubkey, epoch_length: u64) -> Instruction {
+    build(
+        gauge::ID,
+        accounts::SetEpochLength {
+            admin,
+            gauge_config: pda::gauge_config().0,
+            emission_schedule: pda::emission_schedule().0,
+        },
+        instruction::SetEpochLength { epoch_length },
+    )
+}
+
+pub fn init_personal_gauge(fee_payer: Pubkey, owner: Pubkey, pool: Pubkey) -> Instruction {
+    let pool_gauge = pda::pool_gauge(&pool).0;
+    build(
+        gauge::ID,
+        accounts::InitPersonalGauge {
+            fee_payer,
+            owner,
+            pool_gauge,
+            personal_gauge: pda::personal_gauge(&pool_gauge, &owner).0,
+            system_program: system_program::ID,
+        },
+        instruction::InitPersonalGauge,
+    )
+}
+
+/// Close a personal gauge without votes, refunding the rent to `destination`
+pub fn close_personal_gauge(owner: Pubkey, pool: Pubkey, destination: Pubkey) -> Instruction {
+    let pool_gauge = pda::pool_gauge(&pool).0;
+    build(
+        gauge::ID,
+        accounts::ClosePersonalGauge {
+            owner,
+            personal_gauge: pda::personal_gauge(&pool_gauge, &owner).0,
+            destination,
+        },
+        instruction::ClosePersonalGauge,
+    )
+}
+
+/// Create the gauge of a CP pool
+pub fn init_pool_gauge(payer: Pubkey, pool: Pubkey) -> Instruction {
+    build(
+        gauge::ID,
+        accounts::InitConstantGauge {
+            payer,
+            gauge_config: pda::gauge_config().0,
+            emission_schedule: pda::emission_schedule().0,
+            pool_gauge: pda::pool_gauge(&pool).0,
+            pool_id: pool,
+            system_program: system_program::ID,
+        },
+        instruction::InitPoolGauge,
+    )
+}
+
+/// Create the gauge of a CLMM pool, initializing its time-tracker reward if missing
+pub fn init_cl_pool_gauge(
+    admin: Pubkey,
+    pool: Pubkey,
+    reward: &ClTimeTrackerReward,
+    reward_open_time: u64,
+    reward_end_time: u64,
+) -> Instruction {
+    build(
+        gauge::ID,
+        accounts::InitClPoolGauge {
+            admin,
+            gauge_config: pda::gauge_config().0,
+            emission_schedule: pda::emission_schedule().0,
+            pool_gauge: pda::pool_gauge(&pool).0,
+            pool_state: pool,
+            time_tracker_mint: reward.time_tracker_mint,
+            funder_token_account: reward.funder_token_account,
+            amm_config: reward.amm_config,
+            operation_state: reward.operation_state,
+            reward_token_vault: reward.reward_token_vault,
+            reward_token_program: reward.reward_token_program,
+            clmm_program: raydium_amm_v3::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        instruction::InitClPoolGauge {
+            reward_open_time,
+            reward_end_time,
+        },
+    )
+}
+
+/// Change the votes of `owner`'s reactor on a pool by `amount`
+/// `authority` is the owner or the delegate of the reactor's vote delegation
+pub fn change_votes(authority: Pubkey, owner: Pubkey, pool: Pubkey, amount: i64) -> Instruction {
+    let reactor = pda::reactor(&owner).0;
+    let pool_gauge = pda::pool_gauge(&pool).0;
+    build(
+        gauge::ID,
+        accounts::ChangeVotes {
+            authority,
+            gauge_config: pda::gauge_config().0,
+            emission_schedule: pda::emission_schedule().0,
+            pool_gauge,
+            personal_gauge: pda::personal_gauge(&pool_gauge, &owner).0,
+            reactor,
+            vote_delegation: vote_delegation(authority, owner, &reactor),
+            vote_ledger: pda::vote_ledger(&reactor).0,
+            reactor_prog: reactor::ID,
+            sysvar_instruction: sysvar::instructions::ID,
+        },
+        instruction::ChangeVotes { amount },
+    )
+}
+
+/// Create the vote ledger of `owner`'s reactor
+/// `pools` must cover every pool the reactor votes on
+pub fn init_vote_ledger(owner: Pubkey, pools: &[Pubkey]) -> Instruction {
+    let reactor = pda::reactor(&owner).0;
+    let ix = build(
+        gauge::ID,
+        accounts::InitVoteLedger {
+            owner,
+            reactor,
+            vote_ledger: pda::vote_ledger(&reactor).0,
+            system_program: system_program::ID,
+        },
+        instruction::InitVoteLedger,
+    );
+
+    with_remaining(
+        ix,
+        pools.iter().map(|pool| {
+            let pool_gauge = pda::pool_gauge(pool).0;
+            AccountMeta::new_readonly(pda::personal_gauge(&pool_gauge, &owner).0, false)
+        }),
+    )
+}
+
+pub fn close_vote_ledger(owner: Pubkey) -> Instruction {
+    let reactor = pda::reactor(&owner).0;
+    build(
+        gauge::ID,
+        accounts::CloseVoteLedger {
+            owner,
+            reactor,
+            vote_ledger: pda::vote_ledger(&reactor).0,
+        },
+        instruction::CloseVoteLedger,
+    )
+}
+
+/// Move votes of `owner`'s reactor between pools, one `(pool, delta)` per pool
+pub fn reallocate_votes(
+    authority: Pubkey,
+    owner: Pubkey,
+    changes: &[(Pubkey, i64)],
+) -> Instruction {
+    let reactor = pda::reactor(&owner).0;
+    let ix = build(
+        gauge::ID,
+        accounts::ReallocateVotes {
+            authority,
+            gauge_config: pda::gauge_config().0,
+            emission_schedule: pda::emission_schedule().0,
+            reactor,
+            vote_delegation: vote_delegation(authority, owner, &reactor),
+            vote_ledger: pda::vote_ledger(&reactor).0,
+            reactor_prog: reactor::ID,
+            sysvar_instruction: sysvar::instructions::ID,
+        },
+        instruction::ReallocateVotes {
+            deltas: changes.iter().map(|(_, delta)| *delta).collect(),
+        },
+    );
+
+    with_remaining(
+        ix,
+        changes.iter().flat_map(|(pool, _)| {
+            let pool_gauge = pda::pool_gauge(pool).0;
+            [
+                AccountMeta::new(pool_gauge, false),
+                AccountMeta::new(pda::personal_gauge(&pool_gauge, &owner).0, false),
+            ]
+        }),
+    )
+}
+
+/// Release the decayed votes of `owner`'s reactor from pools picked by the owner or its delegate,
+/// taken from the pools in order
+pub fn sync_decayed_votes(authority: Pubkey, owner: Pubkey, pools: &[Pubkey]) -> Instruction {
+    let reactor = pda::reactor(&owner).0;
+    let ix = build(
+        gauge::ID,
+        sync_decayed_votes_accounts(Some(authority), owner, &reactor),
+        instruction::SyncDecayedVotes,
+    );
+
+    let pool_gauges: Vec<Pubkey> = pools.iter().map(|pool| pda::pool_gauge(pool).0).collect();
+    with_remaining(ix, decay_pairs(owner, &pool_gauges))
+}
+
+/// Permissionless crank spreading the decayed votes of `owner`'s reactor over its pool gauges
+/// `pool_gauges` must be every gauge the reactor votes on, as listed by its vote ledger
+/// or found by scanning the owner's personal gauges
+pub fn sync_decayed_votes_pro_rata(owner: Pubkey, pool_gauges: &[Pubkey]) -> Instruction {
+    let reactor = pda::reactor(&owner).0;
+    let ix = build(
+        gauge::ID,
+        sync_decayed_votes_accounts(None, owner, &reactor),
+        instruction::SyncDecayedVotes,
+    );
+
+    with_remaining(ix, decay_pairs(owner, pool_gauges))
+}
+
+fn sync_decayed_votes_accounts(
+    authority: Option<Pubkey>,
+    owner: Pubkey,
+    reactor: &Pubkey,
+) -> accounts::SyncDecayedVotes {
+    accounts::SyncDecayedVotes {
+        authority,
+        gauge_config: pda::gauge_config().0,
+        emission_schedule: pda::emission_schedule().0,
+        reactor: *reactor,
+        vote_delegation: authority.and_then(|a| vote_delegation(a, owner, reactor)),
+        vote_ledger: pda::vote_ledger(reactor).0,
+        reactor_prog: reactor::ID,
+        sysvar_instruction: sysvar::instructions::ID,
+    }
+}
+
+fn decay_pairs(owner: Pubkey, pool_gauges: &[Pubkey]) -> Vec<AccountMeta> {
+    pool_gauges
+        .iter()
+        .flat_map(|pool_gauge| {
+            [
+                AccountMeta::new(*pool_gauge, false),
+                AccountMeta::new(pda::personal_gauge(pool_gauge, &owner).0, false),
+            ]
+        })
+        .collect()
+}
+
+/// End `current_epoch`, the payer funds the record of the new epoch's boundary
+pub fn advance_epoch(payer: Pubkey, current_epoch: u64) -> Instruction {
+    build(
+        gauge::ID,
+        accounts::AdvanceEpoch {
+            payer,
+            gauge_config: pda::gauge_config().0,
+            emission_schedule: pda::emission_schedule().0,
+            epoch_record: pda::epoch_record(current_epoch + 1).0,
+            system_program: system_program::ID,
+        },
+        instruction::AdvanceEpoch,
+    )
+}
+
+/// Apply the votes of a pool's gauge queued for `pending_epoch`, once it left the epoch history
+pub fn apply_epoch_record(pool: Pubkey, pending_epoch: u64) -> Instruction {
+    build(
+        gauge::ID,
+        accounts::ApplyEpochRecord {
+            gauge_config: pda::gauge_config().0,
+            emission_schedule: pda::emission_schedule().0,
+            pool_gauge: pda::pool_gauge(&pool).0,
+            epoch_record: pda::epoch_record(pending_epoch).0,
+        },
+        instruction::ApplyEpochRecord,
+    )
+}
+
+pub fn init_bribe(payer: Pubkey, pool: Pubkey, mint: Pubkey, epoch: u64) -> Instruction {
+    let pool_gauge = pda::pool_gauge(&pool).0;
+    let bribe = pda::bribe(&pool_gauge, &mint, epoch).0;
+    build(
+        gauge::ID,
+        accounts::InitBribe {
+            payer,
+            gauge_config: pda::gauge_config().0,
+            pool_gauge,
+            mint,
+            bribe,
+            vault: pda::bribe_vault(&bribe).0,
+            token_program: token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::InitBribe { epoch },
+    )
+}
+
+/// Deposit `amount` of the bribe mint from `src`
+pub fn deposit_bribe(depositor: Pubkey, bribe: Pubkey, src: Pubkey, amount: u64) -> Instruction {
+    build(
+        gauge::ID,
+        accounts::DepositBribe {
+            depositor,
+            gauge_config: pda::gauge_config().0,
+            bribe,
+            vault: pda::bribe_vault(&bribe).0,
+            src,
+            token_program: token::ID,
+        },
+        instruction::DepositBribe { amount },
+    )
+}
+
+/// Claim `owner`'s share of a bribe to `dst`
+pub fn claim_bribe(owner: Pubkey, pool: Pubkey, bribe: Pubkey, dst: Pubkey) -> Instruction {
+    let pool_gauge = pda::pool_gauge(&pool).0;
+    let personal_gauge = pda::personal_gauge(&pool_gauge, &owner).0;
+    build(
+        gauge::ID,
+        accounts::ClaimBribe {
+            owner,
+            gauge_config: pda::gauge_config().0,
+            pool_gauge,
+            personal_gauge,
+            bribe,
+            vault: pda::bribe_vault(&bribe).0,
+            dst,
+            bribe_claim: pda::bribe_claim(&bribe, &personal_gauge).0,
+            token_program: token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::ClaimBribe,
+    )
+}
+
+/// Move the unclaimed amount of an expired bribe into `target_bribe`
+pub fn rollover_bribe(bribe: Pubkey, target_bribe: Pubkey) -> Instruction {
+    build(
+        gauge::ID,
+        accounts::RolloverBribe {
+            gauge_config: pda::gauge_config().0,
+            bribe,
+            vault: pda::bribe_vault(&bribe).0,
+            target_bribe,
+            target_vault: pda::bribe_vault(&target_bribe).0,
+            token_program: token::ID,
+        },
+        instruction::RolloverBribe,
+    )
+}
+
+/// Refund the unclaimed amount of an expired bribe to `dst`
+pub fn refund_bribe(refund_authority: Pubkey, bribe: Pubkey, dst: Pubkey) -> Instruction {
+    build(
+        gauge::ID,
+        accounts::RefundBribe {
+            refund_authority,
+            gauge_config: pda::gauge_config().0,
+            bribe,
+            vault: pda::bribe_vault(&bribe).0,
+            dst,
+            token_program: token::ID,
+        },
+        instruction::RefundBribe,
+    )
+}
+
+pub fn sync_pool_index(pool: Pubkey) -> Instruction {
+    build(
+        gauge::ID,
+        accounts::SyncPoolIndex {
+            gauge_config: pda::gauge_config().0,
+            emission_schedule: pda::emission_schedule().0,
+            pool_gauge: pda::pool_gauge(&pool).0,
+        },
+        instruction::SyncPoolIndex,
+    )
+}
+
+/// Sync the gauges of `pools` in one instruction
+pub fn sync_gauges(pools: &[Pubkey]) -> Instruction {
+    let ix = build(
+        gauge::ID,
+        accounts::SyncGauges {
+            gauge_config: pda::gauge_config().0,
+            emission_schedule: pda::emission_schedule().0,
+        },
+        instruction::SyncGauges,
+    );
+
+    with_remaining(
+        ix,
+        pools
+            .iter()
+            .map(|pool| AccountMeta::new(pda::pool_gauge(pool).0, false)),
+    )
+}
+
+/// Fund the gauge RAY hopper with `amount` from `ray_src`
+pub fn fund_gauge_hopper(funder: Pubkey, ray_src: Pubkey, amount: u64) -> Instruction {
+    build(
+        gauge::ID,
+        accounts::FundGaugeHopper {
+            funder,
+            ray_src,
+            gauge_config: pda::gauge_config().0,
+            emission_schedule: pda::emission_schedule().0,
+            ray_hopper: pda::gauge_ray_hopper().0,
+            token_program: token::ID,
+        },
+        instruction::FundGaugeHopper { amount },
+    )
+}
+
+pub fn get_budget_run_out_ts() -> Instruction {
+    build(
+        gauge::ID,
+        accounts::GetBudgetRunOutTs {
+            gauge_config: pda::gauge_config().0,
+            emission_schedule: pda::emission_schedule().0,
+        },
+        instruction::GetBudgetRunOutTs,
+    )
+}
+
+pub fn cp_init_personal_rewarder(owner: Pubkey, pool: Pubkey) -> Instruction {
+    let cp = CpKeys::new(owner, pool);
+    build(
+        gauge::ID,
+        accounts::InitPersonalRewarder {
+            owner,
+            gauge_config: pda::gauge_config().0,
+            emission_schedule: pda::emission_schedule().0,
+            pool_gauge: cp.pool_gauge,
+            personal_rewarder: cp.personal_rewarder,
+            personal_liq_position: cp.liq_position,
+            time_tracker: cp.time_tracker,
+            system_program: system_program::ID,
+            cp_lp_escrow_program: cp_lp_escrow::ID,
+        },
+        instruction::CpInitPersonalRewarder,
+    )
+}
+
+/// Accrue `owner`'s RAY on a CP pool, permissionless
+pub fn cp_accrue_ray(payer: Pubkey, owner: Pubkey, pool: Pubkey) -> Instruction {
+    let cp = CpKeys::new(owner, pool);
+    build(
+        gauge::ID,
+        accounts::AccrueRay {
+            payer,
+            gauge_config: pda::gauge_config().0,
+            emission_schedule: pda::emission_schedule().0,
+            personal_rewarder: cp.personal_rewarder,
+            pool_gauge: cp.pool_gauge,
+            liq_position: cp.liq_position,
+            time_tracker: cp.time_tracker,
+            cp_lp_escrow_program: cp_lp_escrow::ID,
+        },
+        instruction::CpAccrueRay,
+    )
+}
+
+/// Withdraw the accrued RAY of a CP pool to `ray_dst`
+pub fn cp_withdraw_ray(owner: Pubkey, pool: Pubkey, ray_dst: Pubkey) -> Instruction {
+    let cp = CpKeys::new(owner, pool);
+    build(
+        gauge::ID,
+        accounts::WithdrawRay {
+            owner,
+            gauge_config: pda::gauge_config().0,
+            personal_rewarder: cp.personal_rewarder,
+            ray_hopper: pda::gauge_ray_hopper().0,
+            ray_dst,
+            token_program: token::ID,
+        },
+        instruction::CpWithdrawRay,
+    )
+}
+
+/// Withdraw `amount` LP to `lp_dst`, along with the earned RAY to `ray_dst`
+pub fn cp_withdraw_lp(
+    owner: Pubkey,
+    pool: Pubkey,
+    lp_dst: Pubkey,
+    ray_dst: Pubkey,
+    amount: u64,
+) -> Instruction {
+    let cp = CpKeys::new(owner, pool);
+    build(
+        gauge::ID,
+        accounts::WithdrawLp {
+            owner,
+            gauge_config: pda::gauge_config().0,
+            emission_schedule: pda::emission_schedule().0,
+            personal_rewarder: cp.personal_rewarder,
+            pool_gauge: cp.pool_gauge,
+            liq_position: cp.liq_position,
+            time_tracker: cp.time_tracker,
+            escrow: pda::lp_escrow(&pool).0,
+            lp_dst,
+            ray_hopper: pda::gauge_ray_hopper().0,
+            ray_dst,
+            token_program: token::ID,
+            cp_lp_escrow_program: cp_lp_escrow::ID,
+        },
+        instruction::CpWithdrawLp { amount },
+    )
+}
+
+/// Close an empty CP personal rewarder, refunding the rent to `destination`
+pub fn cp_close_personal_rewarder(owner: Pubkey, pool: Pubkey, destination: Pubkey) -> Instruction {
+    let cp = CpKeys::new(owner, pool);
+    build(
+        gauge::ID,
+        accounts::ClosePersonalRewarder {
+            owner,
+            gauge_config: pda::gauge_config().0,
+            emission_schedule: pda::emission_schedule().0,
+            personal_rewarder: cp.personal_rewarder,
+            pool_gauge: cp.pool_gauge,
+            liq_position: cp.liq_position,
+            time_tracker: cp.time_tracker,
+            destination,
+            cp_lp_escrow_program: cp_lp_escrow::ID,
+        },
+        instruction::CpClosePersonalRewarder,
+    )
+}
+
+/// Withdraw the accrued RAY of a CLMM position to `ray_dst`
+/// `nft_account` holds the position NFT of `nft_owner`
+pub fn cl_withdraw_ray(
+    nft_owner: Pubkey,
+    nft_account: Pubkey,
+    personal_position: Pubkey,
+    ray_dst: Pubkey,
+) -> Instruction {
+    build(
+        gauge::ID,
+        accounts::WithdrawRayCl {
+            nft_owner,
+            nft_account,
+            personal_position,
+            gauge_config: pda::gauge_config().0,
+            personal_rewarder: pda::personal_rewarder_cl(&personal_position).0,
+            ray_hopper: pda::gauge_ray_hopper().0,
+            ray_dst,
+            token_program: token::ID,
+        },
+        instruction::ClWithdrawRay,
+    )
+}
+
+/// Accrue the RAY of a CLMM position, permissionless
+pub fn cl_accrue_ray(payer: Pubkey, position: &ClPosition) -> Instruction {
+    build(
+        gauge::ID,
+        accounts::AccrueRayCl {
+            payer,
+            gauge_config: pda::gauge_config().0,
+            emission_schedule: pda::emission_schedule().0,
+            personal_rewarder: pda::personal_rewarder_cl(&position.personal_position).0,
+            pool_gauge: pda::pool_gauge(&position.pool_state).0,
+            pool_position: position.personal_position,
+            pool_state: position.pool_state,
+            protocol_position: position.protocol_position,
+            tick_array_lower_loader: position.tick_array_lower,
+            tick_array_upper_loader: position.tick_array_upper,
+            clmm_program: raydium_amm_v3::ID,
+        },
+        instruction::ClAccrueRay,
+    )
+}
+
+pub fn cl_init_personal_rewarder(payer: Pubkey, position: &ClPosition) -> Instruction {
+    build(
+        gauge::ID,
+        accounts::InitPersonalRewarderCl {
+            payer,
+            gauge_config: pda::gauge_config().0,
+            emission_schedule: pda::emission_schedule().0,
+            pool_gauge: pda::pool_gauge(&position.pool_state).0,
+            personal_liq_position: position.personal_position,
+            pool_state: position.pool_state,
+            personal_rewarder: pda::personal_rewarder_cl(&position.personal_position).0,
+            protocol_position: position.protocol_position,
+            tick_array_lower_loader: position.tick_array_lower,
+            tick_array_upper_loader: position.tick_array_upper,
+            clmm_program: raydium_amm_v3::ID,
+            system_program: system_program::ID,
+        },
+        instruction::ClInitPersonalRewarder,
+    )
+}
+
+/// Close an empty CLMM personal rewarder, refunding the rent to `destination`
+pub fn cl_close_personal_rewarder(
+    nft_owner: Pubkey,
+    nft_account: Pubkey,
+    position: &ClPosition,
+    destination: Pubkey,
+) -> Instruction {
+    build(
+        gauge::ID,
+        accounts::ClosePersonalRewarderCl {
+            nft_owner,
+            nft_account,
+            gauge_config: pda::gauge_config().0,
+            emission_schedule: pda::emission_schedule().0,
+            personal_rewarder: pda::personal_rewarder_cl(&position.personal_position).0,
+            pool_gauge: pda::pool_gauge(&position.pool_state).0,
+            pool_position: position.personal_position,
+            pool_state: position.pool_state,
+            protocol_position: position.protocol_position,
+            tick_array_lower_loader: position.tick_array_lower,
+            tick_array_upper_loader: position.tick_array_upper,
+            destination,
+            clmm_program: raydium_amm_v3::ID,
+        },
+        instruction::ClClosePersonalRewarder,
+    )
+}
+
+/// Vote delegation is only passed when the authority is not the owner
+fn vote_delegation(authority: Pubkey, owner: Pubkey, reactor: &Pubkey) -> Option<Pubkey> {
+    (authority != owner).then(|| pda::vote_delegation(reactor).0)
+}
+
+/// Accounts of an owner's LP position in a CP pool
+struct CpKeys {
+    pool_gauge: Pubkey,
+    time_tracker: Pubkey,
+    liq_position: Pubkey,
+    personal_rewarder: Pubkey,
+}
+
+impl CpKeys {
+    fn new(owner: Pubkey, pool: Pubkey) -> Self {
+        let pool_gauge = pda::pool_gauge(&pool).0;
+        let time_tracker = pda::time_tracker(&pool).0;
+        Self {
+            pool_gauge,
+            time_tracker,
+            liq_position: pda::personal_position(&time_tracker, &owner).0,
+            personal_rewarder: pda::personal_rewarder_cp(&pool_gauge, &owner).0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::Discriminator;
+
+    #[test]
+    fn test_change_votes_delegation() {
+        let owner = Pubkey::new_unique();
+        let delegate = Pubkey::new_unique();
+        let pool = Pubkey::new_unique();
+        let reactor = pda::reactor(&owner).0;
+
+        let ix = change_votes(owner, owner, pool, 10);
+        assert_eq!(ix.program_id, gauge::ID);
+        assert_eq!(&ix.data[..8], &instruction::ChangeVotes::DISCRIMINATOR);
+        // the absent optional account is replaced by the program id
+        assert_eq!(ix.accounts[6].pubkey, gauge::ID);
+
+        let ix = change_votes(delegate, owner, pool, 10);
+        assert_eq!(ix.accounts[0].pubkey, delegate);
+        assert!(ix.accounts[0].is_signer);
+        assert_eq!(ix.accounts[6].pubkey, pda::vote_delegation(&reactor).0);
+    }
+
+    #[test]
+    fn test_reallocate_votes_remaining_accounts() {
+        let owner = Pubkey::new_unique();
+        let pools = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+        let ix = reallocate_votes(owner, owner, &[(pools[0], -5), (pools[1], 5)]);
+        let remaining = &ix.accounts[ix.accounts.len() - 4..];
+        for (pair, pool) in remaining.chunks(2).zip(pools) {
+            let pool_gauge = pda::pool_gauge(&pool).0;
+            assert_eq!(pair[0].pubkey, pool_gauge);
+            assert_eq!(pair[1].pubkey, pda::personal_gauge(&pool_gauge, &owner).0);
+            assert!(pair.iter().all(|meta| meta.is_writable));
+        }
+
+        let data = instruction::ReallocateVotes {
+            deltas: vec![-5, 5],
+        };
+        assert_eq!(ix.data, anchor_lang::InstructionData::data(&data));
+    }
+}
@@ -0,0 +1,35 @@
+//! Instruction builders, one per program handler
+//!
+//! Program addresses are derived, the other accounts are passed in.
+//! The reactor's `lock_votes`, `unlock_votes` and `release_decayed_votes` are left out,
+//! they may only be called by the gauge program through CPI
+
+pub mod escrow;
+pub mod gauge;
+pub mod reactor;
+
+use anchor_lang::{
+    prelude::{AccountMeta, Pubkey},
+    solana_program::instruction::Instruction,
+    InstructionData, ToAccountMetas,
+};
+
+fn build(
+    program_id: Pubkey,
+    accounts: impl ToAccountMetas,
+    data: impl InstructionData,
+) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+fn with_remaining(
+    mut ix: Instruction,
+    remaining: impl IntoIterator<Item = AccountMeta>,
+) -> Instruction {
+    ix.accounts.extend(remaining);
+    ix
+}
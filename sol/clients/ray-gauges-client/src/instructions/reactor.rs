@@ -0,0 +1,360 @@
+//! Reactor instructions, keyed by the reactor owner
+
+use super::build;
+use crate::pda;
+use anchor_lang::{prelude::Pubkey, solana_program::instruction::Instruction, system_program};
+use anchor_spl::token;
+use reactor::{accounts, instruction};
+
+pub fn init_config(
+    payer: Pubkey,
+    ray_reward_daily_emission: u64,
+    iso_ray_apr_bps: u16,
+) -> Instruction {
+    build(
+        reactor::ID,
+        accounts::InitConfig {
+            payer,
+            config: pda::reactor_config().0,
+            ray_vault: pda::reactor_ray_vault().0,
+            ray_hopper: pda::reactor_reward_hopper().0,
+            ray_mint: reactor::ray_mint::ID,
+            token_program: token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::InitConfig {
+            ray_reward_daily_emission,
+            iso_ray_apr_bps,
+        },
+    )
+}
+
+pub fn set_ray_reward_daily_emission(admin: Pubkey, ray_reward_daily_emission: u64) -> Instruction {
+    build(
+        reactor::ID,
+        accounts::SetRayRewardDailyEmission {
+            admin,
+            reactor_config: pda::reactor_config().0,
+        },
+        instruction::SetRayRewardDailyEmission {
+            ray_reward_daily_emission,
+        },
+    )
+}
+
+pub fn set_iso_ray_apr_bps(admin: Pubkey, iso_ray_apr_bps: u16) -> Instruction {
+    build(
+        reactor::ID,
+        accounts::SetIsoRayAprBps {
+            admin,
+            reactor_config: pda::reactor_config().0,
+        },
+        instruction::SetIsoRayAprBps { iso_ray_apr_bps },
+    )
+}
+
+pub fn propose_admin(admin: Pubkey, new_admin: Pubkey) -> Instruction {
+    build(
+        reactor::ID,
+        accounts::ProposeAdmin {
+            admin,
+            reactor_config: pda::reactor_config().0,
+        },
+        instruction::ProposeAdmin { new_admin },
+    )
+}
+
+pub fn accept_admin(pending_admin: Pubkey) -> Instruction {
+    build(
+        reactor::ID,
+        accounts::AcceptAdmin {
+            pending_admin,
+            reactor_config: pda::reactor_config().0,
+        },
+        instruction::AcceptAdmin,
+    )
+}
+
+pub fn set_pause_flags(admin: Pubkey, pause_flags: u8) -> Instruction {
+    build(
+        reactor::ID,
+        accounts::SetPauseFlags {
+            admin,
+            reactor_config: pda::reactor_config().0,
+        },
+        instruction::SetPauseFlags { pause_flags },
+    )
+}
+
+pub fn set_unstake_cooldown(admin: Pubkey, unstake_cooldown: u64) -> Instruction {
+    build(
+        reactor::ID,
+        accounts::SetUnstakeCooldown {
+            admin,
+            reactor_config: pda::reactor_config().0,
+        },
+        instruction::SetUnstakeCooldown { unstake_cooldown },
+    )
+}
+
+pub fn init_reactor(payer: Pubkey, owner: Pubkey) -> Instruction {
+    build(
+        reactor::ID,
+        accounts::InitReactor {
+            payer,
+            owner,
+            reactor: pda::reactor(&owner).0,
+            system_program: system_program::ID,
+        },
+        instruction::InitReactor,
+    )
+}
+
+/// Deposit `amount` RAY from `ray_src`
+pub fn deposit_ray(owner: Pubkey, ray_src: Pubkey, amount: u64) -> Instruction {
+    build(
+        reactor::ID,
+        accounts::DepositRay {
+            owner,
+            ray_src,
+            ray_vault: pda::reactor_ray_vault().0,
+            reactor: pda::reactor(&owner).0,
+            reactor_config: pda::reactor_config().0,
+            token_program: token::ID,
+        },
+        instruction::DepositRay { amount },
+    )
+}
+
+/// Withdraw `amount` RAY to `ray_dst`
+pub fn withdraw_ray(owner: Pubkey, ray_dst: Pubkey, amount: u64) -> Instruction {
+    build(
+        reactor::ID,
+        accounts::WithdrawRay {
+            owner,
+            reactor: pda::reactor(&owner).0,
+            ray_vault: pda::reactor_ray_vault().0,
+            ray_dst,
+            reactor_config: pda::reactor_config().0,
+            token_program: token::ID,
+        },
+        instruction::WithdrawRay { amount },
+    )
+}
+
+pub fn request_unstake(owner: Pubkey, amount: u64) -> Instruction {
+    build(
+        reactor::ID,
+        accounts::RequestUnstake {
+            owner,
+            reactor: pda::reactor(&owner).0,
+            reactor_config: pda::reactor_config().0,
+        },
+        instruction::RequestUnstake { amount },
+    )
+}
+
+/// Complete the pending unstake, sending the RAY to `ray_dst`
+pub fn complete_unstake(owner: Pubkey, ray_dst: Pubkey) -> Instruction {
+    build(
+        reactor::ID,
+        accounts::CompleteUnstake {
+            owner,
+            reactor: pda::reactor(&owner).0,
+            ray_vault: pda::reactor_ray_vault().0,
+            ray_dst,
+            reactor_config: pda::reactor_config().0,
+            token_program: token::ID,
+        },
+        instruction::CompleteUnstake,
+    )
+}
+
+pub fn get_pending_unstake(owner: Pubkey) -> Instruction {
+    build(
+        reactor::ID,
+        accounts::GetPendingUnstake {
+            reactor: pda::reactor(&owner).0,
+        },
+        instruction::GetPendingUnstake,
+    )
+}
+
+pub fn lock_ray(owner: Pubkey, amount: u64, duration: u64) -> Instruction {
+    build(
+        reactor::ID,
+        accounts::LockRay {
+            owner,
+            reactor: pda::reactor(&owner).0,
+            reactor_config: pda::reactor_config().0,
+        },
+        instruction::LockRay { amount, duration },
+    )
+}
+
+pub fn create_vote_delegation(
+    owner: Pubkey,
+    delegate: Pubkey,
+    expires_at: u64,
+    allowed_gauges: Vec<Pubkey>,
+) -> Instruction {
+    let reactor = pda::reactor(&owner).0;
+    build(
+        reactor::ID,
+        accounts::CreateVoteDelegation {
+            owner,
+            reactor,
+            vote_delegation: pda::vote_delegation(&reactor).0,
+            system_program: system_program::ID,
+        },
+        instruction::CreateVoteDelegation {
+            delegate,
+            expires_at,
+            allowed_gauges,
+        },
+    )
+}
+
+pub fn revoke_vote_delegation(owner: Pubkey) -> Instruction {
+    let reactor = pda::reactor(&owner).0;
+    build(
+        reactor::ID,
+        accounts::RevokeVoteDelegation {
+            owner,
+            reactor,
+            vote_delegation: pda::vote_delegation(&reactor).0,
+        },
+        instruction::RevokeVoteDelegation,
+    )
+}
+
+pub fn set_ve_mode(owner: Pubkey, ve_mode: bool) -> Instruction {
+    build(
+        reactor::ID,
+        accounts::SetVeMode {
+            owner,
+            reactor: pda::reactor(&owner).0,
+        },
+        instruction::SetVeMode { ve_mode },
+    )
+}
+
+/// Close an empty reactor, refunding the rent to `destination`
+pub fn close_reactor(owner: Pubkey, destination: Pubkey) -> Instruction {
+    let reactor = pda::reactor(&owner).0;
+    build(
+        reactor::ID,
+        accounts::CloseReactor {
+            owner,
+            reactor,
+            reactor_config: pda::reactor_config().0,
+            vote_delegation: pda::vote_delegation(&reactor).0,
+            destination,
+        },
+        instruction::CloseReactor,
+    )
+}
+
+pub fn sync_reactor(owner: Pubkey) -> Instruction {
+    build(
+        reactor::ID,
+        accounts::SyncReactor {
+            reactor: pda::reactor(&owner).0,
+            reactor_config: pda::reactor_config().0,
+        },
+        instruction::SyncReactor,
+    )
+}
+
+/// Sync the reactor and collect its RAY rewards to `ray_dst`
+pub fn sync_and_collect_ray_rewards(owner: Pubkey, ray_dst: Pubkey) -> Instruction {
+    build(
+        reactor::ID,
+        accounts::SyncAndCollectRayRewards {
+            owner,
+            reactor: pda::reactor(&owner).0,
+            reactor_config: pda::reactor_config().0,
+            ray_reward_hopper: pda::reactor_reward_hopper().0,
+            ray_dst,
+            token_program: token::ID,
+        },
+        instruction::SyncAndCollectRayRewards,
+    )
+}
+
+/// Fund the RAY reward hopper with `amount` from `ray_src`
+pub fn fund_reward_hopper(funder: Pubkey, ray_src: Pubkey, amount: u64) -> Instruction {
+    build(
+        reactor::ID,
+        accounts::FundRewardHopper {
+            funder,
+            ray_src,
+            reactor_config: pda::reactor_config().0,
+            ray_reward_hopper: pda::reactor_reward_hopper().0,
+            token_program: token::ID,
+        },
+        instruction::FundRewardHopper { amount },
+    )
+}
+
+/// Collect the synced RAY rewards to `ray_dst`
+pub fn collect_ray_rewards(owner: Pubkey, ray_dst: Pubkey) -> Instruction {
+    build(
+        reactor::ID,
+        accounts::CollectRayRewards {
+            owner,
+            reactor: pda::reactor(&owner).0,
+            reactor_config: pda::reactor_config().0,
+            ray_reward_hopper: pda::reactor_reward_hopper().0,
+            ray_dst,
+            token_program: token::ID,
+        },
+        instruction::CollectRayRewards,
+    )
+}
+
+pub fn compound_ray_rewards(owner: Pubkey) -> Instruction {
+    build(
+        reactor::ID,
+        accounts::CompoundRayRewards {
+            owner,
+            reactor: pda::reactor(&owner).0,
+            reactor_config: pda::reactor_config().0,
+            ray_reward_hopper: pda::reactor_reward_hopper().0,
+            ray_vault: pda::reactor_ray_vault().0,
+            token_program: token::ID,
+        },
+        instruction::CompoundRayRewards,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::{Discriminator, InstructionData};
+
+    #[test]
+    fn test_unstake() {
+        let owner = Pubkey::new_unique();
+        let ray_dst = Pubkey::new_unique();
+
+        let ix = request_unstake(owner, 5);
+        assert_eq!(ix.program_id, reactor::ID);
+        assert_eq!(ix.data, instruction::RequestUnstake { amount: 5 }.data());
+        assert_eq!(ix.accounts[0].pubkey, owner);
+        assert!(ix.accounts[0].is_signer);
+        assert_eq!(ix.accounts[1].pubkey, pda::reactor(&owner).0);
+        assert!(ix.accounts[1].is_writable);
+
+        let ix = complete_unstake(owner, ray_dst);
+        assert_eq!(&ix.data[..8], &instruction::CompleteUnstake::DISCRIMINATOR);
+        assert_eq!(ix.accounts[0].pubkey, owner);
+        assert!(ix.accounts[0].is_signer);
+        let ray_dst_meta = ix.accounts.iter().find(|m| m.pubkey == ray_dst).unwrap();
+        assert!(ray_dst_meta.is_writable);
+        assert!(ix
+            .accounts
+            .iter()
+            .any(|m| m.pubkey == pda::reactor_ray_vault().0 && m.is_writable));
+    }
+}
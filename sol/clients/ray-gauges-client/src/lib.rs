@@ -0,0 +1,19 @@
+//! Off-chain client for the gauge, reactor and cp-lp-escrow programs
+//!
+//! - [`pda`] derives the program addresses from the on-chain seeds
+//! - [`instructions`] builds an [`Instruction`] for every handler
+//! - [`accounts`] deserializes the on-chain state
+//! - [`projection`] simulates pending rewards to a timestamp
+//!
+//! The account lists and instruction data are the programs' own anchor types,
+//! so they cannot drift from the on-chain definitions.
+
+pub mod accounts;
+pub mod instructions;
+pub mod pda;
+pub mod projection;
+
+pub use anchor_lang::solana_program::instruction::Instruction;
+pub use cp_lp_escrow;
+pub use gauge;
+pub use reactor;
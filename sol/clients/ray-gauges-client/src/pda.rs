@@ -0,0 +1,159 @@
+//! Program derived addresses, each returned with its bump
+
+use anchor_lang::prelude::Pubkey;
+use gauge::pda::*;
+
+fn find(seeds: &[&[u8]], program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(seeds, program_id)
+}
+
+/// Global gauge config
+pub fn gauge_config() -> (Pubkey, u8) {
+    find(&[GLOBAL_CONFIG_SEED.as_bytes()], &gauge::ID)
+}
+
+/// RAY hopper emitted by the gauges
+pub fn gauge_ray_hopper() -> (Pubkey, u8) {
+    find(&[GLOBAL_RAY_HOPPER_SEED.as_bytes()], &gauge::ID)
+}
+
+pub fn emission_schedule() -> (Pubkey, u8) {
+    find(&[EMISSION_SCHEDULE_SEED.as_bytes()], &gauge::ID)
+}
+
+/// Gauge of a CP or CLMM pool
+pub fn pool_gauge(pool_id: &Pubkey) -> (Pubkey, u8) {
+    find(&[POOL_GAUGE_SEED.as_bytes(), pool_id.as_ref()], &gauge::ID)
+}
+
+pub fn personal_gauge(pool_gauge: &Pubkey, owner: &Pubkey) -> (Pubkey, u8) {
+    find(
+        &[
+            PERSONAL_GAUGE_SEED.as_bytes(),
+            pool_gauge.as_ref(),
+            owner.as_ref(),
+        ],
+        &gauge::ID,
+    )
+}
+
+pub fn personal_rewarder_cp(pool_gauge: &Pubkey, owner: &Pubkey) -> (Pubkey, u8) {
+    find(
+        &[
+            PERSONAL_REWARDER_CP_SEED.as_bytes(),
+            pool_gauge.as_ref(),
+            owner.as_ref(),
+        ],
+        &gauge::ID,
+    )
+}
+
+/// Personal rewarder of a CLMM personal position
+pub fn personal_rewarder_cl(personal_position: &Pubkey) -> (Pubkey, u8) {
+    find(
+        &[
+            PERSONAL_REWARDER_CL_SEED.as_bytes(),
+            personal_position.as_ref(),
+        ],
+        &gauge::ID,
+    )
+}
+
+/// Record of the boundary at the start of `epoch`
+pub fn epoch_record(epoch: u64) -> (Pubkey, u8) {
+    find(
+        &[EPOCH_RECORD_SEED.as_bytes(), &epoch.to_le_bytes()],
+        &gauge::ID,
+    )
+}
+
+pub fn bribe(pool_gauge: &Pubkey, mint: &Pubkey, epoch: u64) -> (Pubkey, u8) {
+    find(
+        &[
+            BRIBE_SEED.as_bytes(),
+            pool_gauge.as_ref(),
+            mint.as_ref(),
+            &epoch.to_le_bytes(),
+        ],
+        &gauge::ID,
+    )
+}
+
+pub fn bribe_vault(bribe: &Pubkey) -> (Pubkey, u8) {
+    find(&[BRIBE_VAULT_SEED.as_bytes(), bribe.as_ref()], &gauge::ID)
+}
+
+pub fn bribe_claim(bribe: &Pubkey, personal_gauge: &Pubkey) -> (Pubkey, u8) {
+    find(
+        &[
+            BRIBE_CLAIM_SEED.as_bytes(),
+            bribe.as_ref(),
+            personal_gauge.as_ref(),
+        ],
+        &gauge::ID,
+    )
+}
+
+pub fn vote_ledger(reactor: &Pubkey) -> (Pubkey, u8) {
+    find(&[VOTE_LEDGER_SEED.as_bytes(), reactor.as_ref()], &gauge::ID)
+}
+
+/// Global reactor config
+pub fn reactor_config() -> (Pubkey, u8) {
+    find(&[reactor::REACTOR_CONFIG_SEED.as_bytes()], &reactor::ID)
+}
+
+/// Vault of the RAY deposited in reactors
+pub fn reactor_ray_vault() -> (Pubkey, u8) {
+    find(&[reactor::REACTOR_VAULT_SEED.as_bytes()], &reactor::ID)
+}
+
+/// Hopper of the RAY staking rewards
+pub fn reactor_reward_hopper() -> (Pubkey, u8) {
+    find(
+        &[reactor::REACTOR_REWARD_HOPPER_SEED.as_bytes()],
+        &reactor::ID,
+    )
+}
+
+/// Reactor of an owner
+pub fn reactor(owner: &Pubkey) -> (Pubkey, u8) {
+    find(
+        &[reactor::REACTOR_SEED.as_bytes(), owner.as_ref()],
+        &reactor::ID,
+    )
+}
+
+pub fn vote_delegation(reactor: &Pubkey) -> (Pubkey, u8) {
+    find(
+        &[reactor::VOTE_DELEGATION_SEED.as_bytes(), reactor.as_ref()],
+        &reactor::ID,
+    )
+}
+
+/// Time tracker of a CP pool
+pub fn time_tracker(pool_state: &Pubkey) -> (Pubkey, u8) {
+    find(
+        &[cp_lp_escrow::TIME_TRACKER_SEED, pool_state.as_ref()],
+        &cp_lp_escrow::ID,
+    )
+}
+
+/// LP escrow of a CP pool
+pub fn lp_escrow(pool_state: &Pubkey) -> (Pubkey, u8) {
+    find(
+        &[cp_lp_escrow::LP_ESCROW_SEED, pool_state.as_ref()],
+        &cp_lp_escrow::ID,
+    )
+}
+
+pub fn personal_position(time_tracker: &Pubkey, owner: &Pubkey) -> (Pubkey, u8) {
+    find(
+        &[
+            cp_lp_escrow::PERSONAL_POSITION_SEED,
+            time_tracker.as_ref(),
+            owner.as_ref(),
+        ],
+        &cp_lp_escrow::ID,
+    )
+}
@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum EscrowErrors {
+    #[msg("Personal position still holds LP tokens")]
+    PositionNotEmpty,
+
+    #[msg("Gauge personal rewarder of the position must be closed first")]
+    PersonalRewarderOpen,
+}
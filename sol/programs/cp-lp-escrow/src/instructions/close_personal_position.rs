@@ -0,0 +1,62 @@
+use crate::{clock::now, errors::EscrowErrors, gauge_program, state::*};
+use anchor_lang::prelude::*;
+
+/// Close an empty personal position and refund its rent
+/// The gauge rewarder tracks the position's earned time units, which a re-opened position resets,
+/// so it must be closed first
+#[derive(Accounts)]
+pub struct ClosePersonalPosition<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
+    pub time_tracker: Account<'info, TimeTracker>,
+
+    #[account(
+        mut,
+        has_one = owner,
+        has_one = time_tracker,
+        close = destination
+    )]
+    pub personal_position: Account<'info, PersonalPosition>,
+
+    /// CHECK: gauge of the pool, only used to derive the personal rewarder
+    #[account(
+        seeds = [gauge_program::POOL_GAUGE_SEED, time_tracker.pool_id.as_ref()],
+        bump,
+        seeds::program = gauge_program::ID
+    )]
+    pub pool_gauge: UncheckedAccount<'info>,
+
+    /// CHECK: gauge rewarder of the position, only checked to be empty
+    #[account(
+        seeds = [
+            gauge_program::PERSONAL_REWARDER_CP_SEED,
+            pool_gauge.key().as_ref(),
+            owner.key().as_ref(),
+        ],
+        bump,
+        seeds::program = gauge_program::ID,
+        constraint = personal_rewarder.data_is_empty() @ EscrowErrors::PersonalRewarderOpen
+    )]
+    pub personal_rewarder: UncheckedAccount<'info>,
+
+    /// CHECK: receives the rent of the personal position
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<ClosePersonalPosition>) -> Result<()> {
+    // sync the position before checking it
+    ctx.accounts.time_tracker.update(now());
+
+    ctx.accounts
+        .personal_position
+        .update(ctx.accounts.time_tracker.get_index().into());
+
+    require!(
+        ctx.accounts.personal_position.is_empty(),
+        EscrowErrors::PositionNotEmpty
+    );
+
+    Ok(())
+}
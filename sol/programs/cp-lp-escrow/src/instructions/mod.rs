@@ -1,9 +1,11 @@
+pub mod close_personal_position;
 pub mod deposit;
 pub mod init_escrow;
 pub mod init_personal_position;
 pub mod update_personal_position;
 pub mod withdraw;
 
+pub use close_personal_position::*;
 pub use deposit::*;
 pub use init_escrow::*;
 pub use init_personal_position::*;
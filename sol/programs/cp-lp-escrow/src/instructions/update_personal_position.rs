@@ -17,11 +17,22 @@ pub struct UpdatePersonalPosition<'info> {
 pub fn handler(ctx: Context<UpdatePersonalPosition>) -> Result<()> {
     let now = now();
 
-    ctx.accounts.time_tracker.update(now);
-
-    ctx.accounts
-        .personal_position
-        .update(ctx.accounts.time_tracker.get_index().into());
+    handle_update_personal_position(
+        &mut ctx.accounts.time_tracker,
+        &mut ctx.accounts.personal_position,
+        now,
+    );
 
     Ok(())
 }
+
+/// Updates the pool's time index and the time units earned by the position
+pub fn handle_update_personal_position(
+    time_tracker: &mut TimeTracker,
+    personal_position: &mut PersonalPosition,
+    now: u64,
+) {
+    time_tracker.update(now);
+
+    personal_position.update(time_tracker.get_index().into());
+}
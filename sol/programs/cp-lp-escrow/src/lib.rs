@@ -1,11 +1,15 @@
 use anchor_lang::prelude::*;
 
 mod clock;
+mod errors;
 mod instructions;
 pub mod state;
 
 use instructions::*;
 
+/// State transition of `update_personal_position`, for off-chain projections
+pub use instructions::update_personal_position::handle_update_personal_position;
+
 /// Seed for pool-specific time tracker PDAs
 pub const TIME_TRACKER_SEED: &[u8] = b"time-tracker";
 
@@ -17,6 +21,18 @@ pub const PERSONAL_POSITION_SEED: &[u8] = b"personal-position";
 
 declare_id!("9GXRUvyuS444wsgY7uXJqtJjb5XHXCBd4hCZD83nAD42");
 
+/// Gauge program, which keeps a personal rewarder per personal position
+/// The gauge depends on this program, so its seeds are mirrored here
+pub mod gauge_program {
+    anchor_lang::prelude::declare_id!("b1tVsd3q8i4JpSJctQCQtkScXou4mVaKVhSJThiqf3s");
+
+    /// Seed of the gauge program's pool gauges
+    pub const POOL_GAUGE_SEED: &[u8] = b"pool-gauge";
+
+    /// Seed of the gauge program's CP personal rewarders
+    pub const PERSONAL_REWARDER_CP_SEED: &[u8] = b"personal-rewarder-cp";
+}
+
 #[program]
 pub mod cp_lp_escrow {
     use super::*;
@@ -41,6 +57,11 @@ pub mod cp_lp_escrow {
         withdraw::handler(ctx, amount)
     }
 
+    /// Close an empty personal position and refund its rent
+    pub fn close_personal_position(ctx: Context<ClosePersonalPosition>) -> Result<()> {
+        close_personal_position::handler(ctx)
+    }
+
     /// Sync the personal position with the time tracker
     pub fn update_personal_position(ctx: Context<UpdatePersonalPosition>) -> Result<()> {
         update_personal_position::handler(ctx)
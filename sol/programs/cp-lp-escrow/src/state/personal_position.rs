@@ -56,6 +56,11 @@ impl PersonalPosition {
             .expect("personal position underflow");
     }
 
+    /// No LP tokens are left in the position
+    pub fn is_empty(&self) -> bool {
+        self.amount == 0
+    }
+
     /// Update the earned time units
     pub fn update(&mut self, cur_index: Number) {
         assert!(
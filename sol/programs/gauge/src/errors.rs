@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum GaugeErrors {
+    #[msg("Not admin")]
+    NotAdmin,
+
+    #[msg("Not pending admin")]
+    NotPendingAdmin,
+
+    #[msg("Unknown pause flags")]
+    InvalidPauseFlags,
+
+    #[msg("Pledging votes is paused")]
+    VotesPaused,
+
+    #[msg("Reward claims are paused")]
+    ClaimsPaused,
+
+    #[msg("Emission schedule has too many segments or is not sorted by start time")]
+    InvalidEmissionSchedule,
+
+    #[msg("Gauge is not in the vote delegation allow-list")]
+    GaugeNotDelegated,
+
+    #[msg("Vote share cap cannot exceed 100%")]
+    InvalidVoteShareCap,
+
+    #[msg("Gauge is not whitelisted")]
+    GaugeNotWhitelisted,
+
+    #[msg("Epoch length must be positive")]
+    InvalidEpochLength,
+
+    #[msg("Epoch mode is off")]
+    EpochModeOff,
+
+    #[msg("Current epoch has not ended")]
+    EpochNotEnded,
+
+    #[msg("Bribed epoch has ended")]
+    BribeClosed,
+
+    #[msg("Bribe is not claimable in this epoch")]
+    BribeNotClaimable,
+
+    #[msg("Bribe claim window has not ended")]
+    BribeNotExpired,
+
+    #[msg("Votes of the bribed epoch are no longer remembered")]
+    BribeVotesUnknown,
+
+    #[msg("Rollover target must be a bribe of the same gauge, mint and creator")]
+    InvalidRolloverBribe,
+
+    #[msg("Remaining accounts must be a (pool gauge, personal gauge) pair per vote delta")]
+    InvalidReallocation,
+
+    #[msg("Gauge appears more than once")]
+    DuplicateGauge,
+
+    #[msg("Net vote change exceeds the free votes")]
+    InsufficientFreeVotes,
+
+    #[msg("Personal gauge still holds votes")]
+    PersonalGaugeNotEmpty,
+
+    #[msg("Personal rewarder still holds staged RAY")]
+    RewarderNotEmpty,
+
+    #[msg("Vote ledger cannot list more gauges")]
+    VoteLedgerFull,
+
+    #[msg("Personal gauges do not add up to the reactor's locked votes")]
+    VoteLedgerIncomplete,
+
+    #[msg("CLMM pool has no time-tracker reward")]
+    ClTimeTrackerRewardMissing,
+
+    #[msg("CLMM time-tracker mint is already set")]
+    ClTimeTrackerMintAlreadySet,
+
+    #[msg("CLMM time-tracker reward must emit one unit per second")]
+    ClTimeTrackerRewardMisconfigured,
+
+    #[msg(
+        "Pool gauges are only created for CP swap pools, CLMM pool gauges use init_cl_pool_gauge"
+    )]
+    NotCpSwapPool,
+
+    #[msg("Signer is neither the reactor owner nor its vote delegate")]
+    NotVoteAuthority,
+
+    #[msg("Without the vote authority, decay is split over every gauge the reactor votes on")]
+    DecayNotProRata,
+
+    #[msg("Gauge has no queued votes due")]
+    NoDueVotes,
+
+    #[msg("Queued votes are due at an epoch that left the history, apply its epoch record first")]
+    EpochRecordRequired,
+}
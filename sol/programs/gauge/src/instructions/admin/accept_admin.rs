@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::GaugeErrors, state::GaugeConfig};
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    pub pending_admin: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = pending_admin @ GaugeErrors::NotPendingAdmin,
+    )]
+    pub gauge_config: Account<'info, GaugeConfig>,
+}
+
+pub fn handler(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let old_admin = ctx.accounts.gauge_config.accept_admin();
+
+    emit!(AdminAcceptedEvent {
+        old_admin,
+        new_admin: ctx.accounts.pending_admin.key(),
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct AdminAcceptedEvent {
+    pub old_admin: Pubkey,
+    pub new_admin: Pubkey,
+}
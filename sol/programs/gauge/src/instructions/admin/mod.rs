@@ -0,0 +1,19 @@
+pub mod accept_admin;
+pub mod propose_admin;
+pub mod set_cl_time_tracker_mint;
+pub mod set_emission_schedule;
+pub mod set_epoch_length;
+pub mod set_gauge_max_vote_share_bps;
+pub mod set_gauge_status;
+pub mod set_max_vote_share_bps;
+pub mod set_pause_flags;
+
+pub use accept_admin::*;
+pub use propose_admin::*;
+pub use set_cl_time_tracker_mint::*;
+pub use set_emission_schedule::*;
+pub use set_epoch_length::*;
+pub use set_gauge_max_vote_share_bps::*;
+pub use set_gauge_status::*;
+pub use set_max_vote_share_bps::*;
+pub use set_pause_flags::*;
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::GaugeErrors, state::GaugeConfig};
+
+/// Designate the mint of the CLMM reward that tracks the time units of CL positions
+/// Can only be set once, since CL rewarders keep counting time units from the same reward
+#[derive(Accounts)]
+pub struct SetClTimeTrackerMint<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = admin @ GaugeErrors::NotAdmin,
+        constraint = gauge_config.cl_time_tracker_mint == Pubkey::default() @ GaugeErrors::ClTimeTrackerMintAlreadySet,
+    )]
+    pub gauge_config: Account<'info, GaugeConfig>,
+}
+
+pub fn handler(ctx: Context<SetClTimeTrackerMint>, mint: Pubkey) -> Result<()> {
+    require_keys_neq!(mint, Pubkey::default());
+
+    ctx.accounts.gauge_config.cl_time_tracker_mint = mint;
+
+    Ok(())
+}
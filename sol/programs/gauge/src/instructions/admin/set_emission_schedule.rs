@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::GaugeErrors,
+    state::{EmissionSchedule, EmissionSegment, GaugeConfig},
+    syncer::get_now,
+};
+
+#[derive(Accounts)]
+pub struct SetEmissionSchedule<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = admin @ GaugeErrors::NotAdmin,
+        has_one = emission_schedule,
+    )]
+    pub gauge_config: Account<'info, GaugeConfig>,
+
+    #[account(mut)]
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+}
+
+pub fn handler(ctx: Context<SetEmissionSchedule>, segments: Vec<EmissionSegment>) -> Result<()> {
+    let new_schedule =
+        EmissionSchedule::from_segments(&segments).ok_or(GaugeErrors::InvalidEmissionSchedule)?;
+
+    // settle emission up to now under the old schedule
+    ctx.accounts
+        .gauge_config
+        .update_index(get_now(), &ctx.accounts.emission_schedule);
+
+    ctx.accounts.emission_schedule.set_inner(new_schedule);
+
+    emit!(EmissionScheduleChangedEvent { segments });
+
+    Ok(())
+}
+
+#[event]
+pub struct EmissionScheduleChangedEvent {
+    pub segments: Vec<EmissionSegment>,
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::GaugeErrors,
+    state::{EmissionSchedule, GaugeConfig},
+    syncer::get_now,
+};
+
+/// Turn on epoch mode, or change the length of the following epochs
+/// Epoch mode cannot be turned off, queued votes and bribes rely on epochs advancing
+#[derive(Accounts)]
+pub struct SetEpochLength<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = admin @ GaugeErrors::NotAdmin,
+        has_one = emission_schedule,
+    )]
+    pub gauge_config: Account<'info, GaugeConfig>,
+
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+}
+
+pub fn handler(ctx: Context<SetEpochLength>, epoch_length: u64) -> Result<()> {
+    let old_epoch_length = ctx.accounts.gauge_config.set_epoch_length(
+        epoch_length,
+        get_now(),
+        &ctx.accounts.emission_schedule,
+    )?;
+
+    emit!(EpochLengthChangedEvent {
+        old_epoch_length,
+        new_epoch_length: epoch_length,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct EpochLengthChangedEvent {
+    /// 0 if epoch mode was off
+    pub old_epoch_length: u64,
+    pub new_epoch_length: u64,
+}
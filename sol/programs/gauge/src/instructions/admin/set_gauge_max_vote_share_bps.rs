@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::GaugeErrors,
+    instructions::admin::MaxVoteShareChangedEvent,
+    state::{EmissionSchedule, Gauge, GaugeConfig, MAX_BPS},
+    syncer::{get_now, sync_gauge},
+};
+
+/// Set the cap on the share of emission a single gauge may receive
+/// The gauge is synced under its old cap first
+#[derive(Accounts)]
+pub struct SetGaugeMaxVoteShareBps<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = admin @ GaugeErrors::NotAdmin,
+        has_one = emission_schedule,
+    )]
+    pub gauge_config: Account<'info, GaugeConfig>,
+
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+
+    #[account(mut)]
+    pub pool_gauge: Account<'info, Gauge>,
+}
+
+pub fn handler(ctx: Context<SetGaugeMaxVoteShareBps>, max_vote_share_bps: u16) -> Result<()> {
+    require!(
+        max_vote_share_bps <= MAX_BPS,
+        GaugeErrors::InvalidVoteShareCap
+    );
+
+    sync_gauge(
+        get_now(),
+        &mut ctx.accounts.gauge_config,
+        &ctx.accounts.emission_schedule,
+        &mut ctx.accounts.pool_gauge,
+    )?;
+
+    let g = &mut ctx.accounts.pool_gauge;
+    let old_max_vote_share_bps = g.max_vote_share_bps;
+    g.max_vote_share_bps = max_vote_share_bps;
+
+    emit!(MaxVoteShareChangedEvent {
+        gauge: Some(g.key()),
+        old_max_vote_share_bps,
+        new_max_vote_share_bps: max_vote_share_bps,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::GaugeErrors,
+    state::{EmissionSchedule, Gauge, GaugeConfig, GaugeStatus},
+    syncer::{get_now, sync_gauge},
+};
+
+/// Whitelist, kill or revive a pool gauge
+/// Killing takes the gauge's votes out of the global total, reviving puts them back
+#[derive(Accounts)]
+pub struct SetGaugeStatus<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = admin @ GaugeErrors::NotAdmin,
+        has_one = emission_schedule,
+    )]
+    pub gauge_config: Account<'info, GaugeConfig>,
+
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+
+    #[account(mut)]
+    pub pool_gauge: Account<'info, Gauge>,
+}
+
+pub fn handler(ctx: Context<SetGaugeStatus>, status: GaugeStatus) -> Result<()> {
+    // emission up to now follows the old status
+    sync_gauge(
+        get_now(),
+        &mut ctx.accounts.gauge_config,
+        &ctx.accounts.emission_schedule,
+        &mut ctx.accounts.pool_gauge,
+    )?;
+
+    let old_status = ctx.accounts.pool_gauge.status;
+    let (votes_delta, pending_votes_delta) = ctx.accounts.pool_gauge.set_status(status);
+    let c = &mut ctx.accounts.gauge_config;
+    c.change_votes(votes_delta);
+    c.pending_votes_delta = c
+        .pending_votes_delta
+        .checked_add(pending_votes_delta)
+        .unwrap();
+
+    emit!(GaugeStatusChangedEvent {
+        gauge: ctx.accounts.pool_gauge.key(),
+        old_status,
+        new_status: status,
+        global_total_votes: ctx.accounts.gauge_config.total_votes,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct GaugeStatusChangedEvent {
+    pub gauge: Pubkey,
+    pub old_status: GaugeStatus,
+    pub new_status: GaugeStatus,
+
+    /// Total votes across all gauges, after adding or removing the gauge's votes
+    pub global_total_votes: u64,
+}
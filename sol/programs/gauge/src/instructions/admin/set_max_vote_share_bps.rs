@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::GaugeErrors,
+    state::{EmissionSchedule, Gauge, GaugeConfig},
+    syncer::{apply_index, get_now},
+};
+
+/// Set the global cap on the share of emission a gauge may receive
+/// The global index is synced first and the change is remembered, so that gauges synced later
+/// apply the old cap up to it
+/// Only the last `VOTE_SHARE_CAP_HISTORY_LEN` changes are remembered: pool gauges passed as
+/// writable remaining accounts are synced now, for gauges that may not sync before then
+#[derive(Accounts)]
+pub struct SetMaxVoteShareBps<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = admin @ GaugeErrors::NotAdmin,
+        has_one = emission_schedule,
+    )]
+    pub gauge_config: Account<'info, GaugeConfig>,
+
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SetMaxVoteShareBps<'info>>,
+    max_vote_share_bps: u16,
+) -> Result<()> {
+    let c = &mut ctx.accounts.gauge_config;
+    let old_max_vote_share_bps = c.set_max_vote_share_bps(
+        max_vote_share_bps,
+        get_now(),
+        &ctx.accounts.emission_schedule,
+    )?;
+
+    for info in ctx.remaining_accounts {
+        let mut pool_gauge = Account::<Gauge>::try_from(info)?;
+        apply_index(c, &mut pool_gauge)?;
+        pool_gauge.exit(&crate::ID)?;
+    }
+
+    emit!(MaxVoteShareChangedEvent {
+        gauge: None,
+        old_max_vote_share_bps,
+        new_max_vote_share_bps: max_vote_share_bps,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct MaxVoteShareChangedEvent {
+    /// The pool gauge whose cap changed, or `None` for the global cap
+    pub gauge: Option<Pubkey>,
+    pub old_max_vote_share_bps: u16,
+    pub new_max_vote_share_bps: u16,
+}
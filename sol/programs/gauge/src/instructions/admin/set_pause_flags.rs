@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::GaugeErrors, state::GaugeConfig};
+
+#[derive(Accounts)]
+pub struct SetPauseFlags<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = admin @ GaugeErrors::NotAdmin,
+    )]
+    pub gauge_config: Account<'info, GaugeConfig>,
+}
+
+pub fn handler(ctx: Context<SetPauseFlags>, pause_flags: u8) -> Result<()> {
+    let old_pause_flags = ctx.accounts.gauge_config.set_pause_flags(pause_flags)?;
+
+    emit!(PauseFlagsChangedEvent {
+        old_pause_flags,
+        new_pause_flags: pause_flags,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct PauseFlagsChangedEvent {
+    pub old_pause_flags: u8,
+    pub new_pause_flags: u8,
+}
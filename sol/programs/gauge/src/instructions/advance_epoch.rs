@@ -0,0 +1,65 @@
+use crate::{
+    pda::EPOCH_RECORD_SEED,
+    state::{EmissionSchedule, EpochRecord, GaugeConfig},
+    syncer::get_now,
+};
+use anchor_lang::prelude::*;
+
+/// Permissionless crank that ends the current voting epoch and applies the queued votes
+/// Advances a single epoch per call, recording the boundary for gauges synced after it left the history
+#[derive(Accounts)]
+pub struct AdvanceEpoch<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = emission_schedule
+    )]
+    pub gauge_config: Account<'info, GaugeConfig>,
+
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+
+    /// Record of the boundary at the start of the next epoch
+    #[account(
+        init,
+        payer = payer,
+        space = EpochRecord::SIZE,
+        seeds = [
+            EPOCH_RECORD_SEED.as_bytes(),
+            (gauge_config.current_epoch + 1).to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub epoch_record: Account<'info, EpochRecord>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AdvanceEpoch>) -> Result<()> {
+    let gc = &mut ctx.accounts.gauge_config;
+
+    gc.advance_epoch(get_now(), &ctx.accounts.emission_schedule)?;
+
+    ctx.accounts.epoch_record.snapshot = gc.epoch_snapshot(gc.current_epoch).unwrap();
+
+    emit!(EpochAdvancedEvent {
+        epoch: gc.current_epoch,
+        epoch_end_ts: gc.epoch_end_ts,
+        global_total_votes: gc.total_votes,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct EpochAdvancedEvent {
+    /// The epoch that started
+    pub epoch: u64,
+
+    /// Timestamp at which the new epoch ends
+    pub epoch_end_ts: u64,
+
+    /// Total votes across all gauges for the new epoch
+    pub global_total_votes: u64,
+}
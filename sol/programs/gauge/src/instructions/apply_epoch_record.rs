@@ -0,0 +1,50 @@
+use crate::{
+    errors::GaugeErrors,
+    pda::EPOCH_RECORD_SEED,
+    state::{EmissionSchedule, EpochRecord, Gauge, GaugeConfig},
+    syncer::{apply_due_votes, apply_index, get_now},
+};
+use anchor_lang::prelude::*;
+
+/// Permissionless crank that applies a gauge's queued votes at a boundary no longer in the epoch history
+/// The gauge cannot be synced otherwise, so it must run before any other instruction syncing the gauge
+#[derive(Accounts)]
+pub struct ApplyEpochRecord<'info> {
+    #[account(
+        mut,
+        has_one = emission_schedule
+    )]
+    pub gauge_config: Account<'info, GaugeConfig>,
+
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+
+    #[account(
+        mut,
+        constraint = pool_gauge.has_due_votes(gauge_config.current_epoch) @ GaugeErrors::NoDueVotes,
+    )]
+    pub pool_gauge: Account<'info, Gauge>,
+
+    /// Record of the boundary at which the queued votes take effect
+    #[account(
+        seeds = [
+            EPOCH_RECORD_SEED.as_bytes(),
+            pool_gauge.pending_epoch.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub epoch_record: Account<'info, EpochRecord>,
+}
+
+pub fn handler(ctx: Context<ApplyEpochRecord>) -> Result<()> {
+    let gc = &mut ctx.accounts.gauge_config;
+    let pool_gauge = &mut ctx.accounts.pool_gauge;
+
+    // emit up to the boundary under the old votes, then up to now under the new ones
+    let ray_diverted = apply_due_votes(gc, pool_gauge, ctx.accounts.epoch_record.snapshot);
+    gc.return_diverted_ray(ray_diverted);
+
+    gc.update_index(get_now(), &ctx.accounts.emission_schedule);
+    apply_index(gc, pool_gauge)?;
+
+    Ok(())
+}
@@ -0,0 +1,130 @@
+use crate::{
+    errors::GaugeErrors,
+    pda::BRIBE_CLAIM_SEED,
+    state::{Bribe, BribeClaim, Gauge, GaugeConfig, PersonalGauge, PAUSE_CLAIM},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+/// Claim a share of a bribe, pro-rata to the personal votes on the gauge during the bribed epoch
+#[derive(Accounts)]
+pub struct ClaimBribe<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        constraint = !gauge_config.is_paused(PAUSE_CLAIM) @ GaugeErrors::ClaimsPaused,
+    )]
+    pub gauge_config: Account<'info, GaugeConfig>,
+
+    pub pool_gauge: Account<'info, Gauge>,
+
+    #[account(
+        has_one = owner,
+        has_one = pool_gauge,
+    )]
+    pub personal_gauge: Account<'info, PersonalGauge>,
+
+    #[account(
+        mut,
+        has_one = pool_gauge,
+        has_one = vault,
+        constraint = bribe.is_claimable(gauge_config.current_epoch) @ GaugeErrors::BribeNotClaimable,
+    )]
+    pub bribe: Account<'info, Bribe>,
+
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub dst: Account<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = BribeClaim::SIZE,
+        seeds = [
+            BRIBE_CLAIM_SEED.as_bytes(),
+            bribe.key().as_ref(),
+            personal_gauge.key().as_ref(),
+        ],
+        bump
+    )]
+    pub bribe_claim: Account<'info, BribeClaim>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimBribe<'info> {
+    fn claim_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.vault.to_account_info(),
+                to: self.dst.to_account_info(),
+                authority: self.bribe.to_account_info(),
+            },
+        )
+    }
+}
+
+pub fn handler(ctx: Context<ClaimBribe>) -> Result<()> {
+    let epoch = ctx.accounts.bribe.epoch;
+
+    let personal_votes = ctx
+        .accounts
+        .personal_gauge
+        .votes_at(epoch)
+        .ok_or(GaugeErrors::BribeVotesUnknown)?;
+    let gauge_votes = ctx
+        .accounts
+        .pool_gauge
+        .votes_at(epoch)
+        .ok_or(GaugeErrors::BribeVotesUnknown)?;
+
+    let amount = ctx.accounts.bribe.claim(personal_votes, gauge_votes);
+
+    if amount > 0 {
+        let epoch_bytes = epoch.to_le_bytes();
+        token::transfer(
+            ctx.accounts
+                .claim_ctx()
+                .with_signer(&[&ctx.accounts.bribe.seeds(&epoch_bytes)]),
+            amount,
+        )?;
+    }
+
+    let bribe_claim = &mut ctx.accounts.bribe_claim;
+    bribe_claim.bribe = ctx.accounts.bribe.key();
+    bribe_claim.personal_gauge = ctx.accounts.personal_gauge.key();
+    bribe_claim.amount = amount;
+
+    emit!(BribeClaimedEvent {
+        owner: ctx.accounts.owner.key(),
+        bribe: ctx.accounts.bribe.key(),
+        personal_gauge: ctx.accounts.personal_gauge.key(),
+        personal_votes,
+        gauge_votes,
+        amount,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct BribeClaimedEvent {
+    pub owner: Pubkey,
+    pub bribe: Pubkey,
+    pub personal_gauge: Pubkey,
+
+    /// Personal votes on the gauge during the bribed epoch
+    pub personal_votes: u64,
+
+    /// Total votes on the gauge during the bribed epoch
+    pub gauge_votes: u64,
+
+    /// Amount claimed
+    pub amount: u64,
+}
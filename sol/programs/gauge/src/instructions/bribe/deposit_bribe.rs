@@ -0,0 +1,78 @@
+use crate::{
+    errors::GaugeErrors,
+    state::{Bribe, GaugeConfig},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+/// Add tokens to a bribe, until the bribed epoch ends
+#[derive(Accounts)]
+pub struct DepositBribe<'info> {
+    /// Permissionless depositor
+    pub depositor: Signer<'info>,
+
+    pub gauge_config: Account<'info, GaugeConfig>,
+
+    #[account(
+        mut,
+        has_one = vault,
+        constraint = bribe.is_open(gauge_config.current_epoch) @ GaugeErrors::BribeClosed,
+    )]
+    pub bribe: Account<'info, Bribe>,
+
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub src: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> DepositBribe<'info> {
+    fn deposit_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.src.to_account_info(),
+                to: self.vault.to_account_info(),
+                authority: self.depositor.to_account_info(),
+            },
+        )
+    }
+}
+
+pub fn handler(ctx: Context<DepositBribe>, amount: u64) -> Result<()> {
+    ctx.accounts.bribe.deposit(amount);
+
+    token::transfer(ctx.accounts.deposit_ctx(), amount)?;
+
+    emit!(BribeDepositedEvent {
+        depositor: ctx.accounts.depositor.key(),
+        bribe: ctx.accounts.bribe.key(),
+        pool_gauge: ctx.accounts.bribe.pool_gauge,
+        mint: ctx.accounts.bribe.mint,
+        epoch: ctx.accounts.bribe.epoch,
+        amount,
+        total_amount: ctx.accounts.bribe.total_amount,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct BribeDepositedEvent {
+    pub depositor: Pubkey,
+    pub bribe: Pubkey,
+    pub pool_gauge: Pubkey,
+    pub mint: Pubkey,
+
+    /// Epoch whose voters are paid
+    pub epoch: u64,
+
+    /// Amount deposited
+    pub amount: u64,
+
+    /// Total amount deposited in the bribe
+    pub total_amount: u64,
+}
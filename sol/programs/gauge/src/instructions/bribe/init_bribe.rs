@@ -0,0 +1,69 @@
+use crate::{
+    errors::GaugeErrors,
+    pda::{BRIBE_SEED, BRIBE_VAULT_SEED},
+    state::{Bribe, Gauge, GaugeConfig},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+/// Create the bribe of a pool gauge for an epoch, in any SPL mint
+/// The creator receives refunds of unclaimed tokens
+#[derive(Accounts)]
+#[instruction(epoch: u64)]
+pub struct InitBribe<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        constraint = gauge_config.is_epoch_mode() @ GaugeErrors::EpochModeOff,
+        constraint = epoch >= gauge_config.current_epoch @ GaugeErrors::BribeClosed,
+    )]
+    pub gauge_config: Account<'info, GaugeConfig>,
+
+    pub pool_gauge: Account<'info, Gauge>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = Bribe::SIZE,
+        seeds = [
+            BRIBE_SEED.as_bytes(),
+            pool_gauge.key().as_ref(),
+            mint.key().as_ref(),
+            epoch.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub bribe: Account<'info, Bribe>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [
+            BRIBE_VAULT_SEED.as_bytes(),
+            bribe.key().as_ref(),
+        ],
+        bump,
+        token::mint = mint,
+        token::authority = bribe,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitBribe>, epoch: u64) -> Result<()> {
+    let bribe = &mut ctx.accounts.bribe;
+    bribe.pool_gauge = ctx.accounts.pool_gauge.key();
+    bribe.mint = ctx.accounts.mint.key();
+    bribe.epoch = epoch;
+    bribe.vault = ctx.accounts.vault.key();
+    bribe.refund_authority = ctx.accounts.payer.key();
+    bribe.bump = [ctx.bumps.bribe];
+
+    Ok(())
+}
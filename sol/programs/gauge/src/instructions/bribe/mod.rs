@@ -0,0 +1,11 @@
+pub mod claim_bribe;
+pub mod deposit_bribe;
+pub mod init_bribe;
+pub mod refund_bribe;
+pub mod rollover_bribe;
+
+pub use claim_bribe::*;
+pub use deposit_bribe::*;
+pub use init_bribe::*;
+pub use refund_bribe::*;
+pub use rollover_bribe::*;
@@ -0,0 +1,72 @@
+use crate::{
+    errors::GaugeErrors,
+    state::{Bribe, GaugeConfig},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+/// Refund the unclaimed tokens of an expired bribe to its creator
+#[derive(Accounts)]
+pub struct RefundBribe<'info> {
+    pub refund_authority: Signer<'info>,
+
+    pub gauge_config: Account<'info, GaugeConfig>,
+
+    #[account(
+        mut,
+        has_one = refund_authority,
+        has_one = vault,
+        constraint = bribe.is_expired(gauge_config.current_epoch) @ GaugeErrors::BribeNotExpired,
+    )]
+    pub bribe: Account<'info, Bribe>,
+
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub dst: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> RefundBribe<'info> {
+    fn refund_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.vault.to_account_info(),
+                to: self.dst.to_account_info(),
+                authority: self.bribe.to_account_info(),
+            },
+        )
+    }
+}
+
+pub fn handler(ctx: Context<RefundBribe>) -> Result<()> {
+    let amount = ctx.accounts.bribe.settle();
+
+    let epoch_bytes = ctx.accounts.bribe.epoch.to_le_bytes();
+    token::transfer(
+        ctx.accounts
+            .refund_ctx()
+            .with_signer(&[&ctx.accounts.bribe.seeds(&epoch_bytes)]),
+        amount,
+    )?;
+
+    emit!(BribeRefundedEvent {
+        bribe: ctx.accounts.bribe.key(),
+        refund_authority: ctx.accounts.refund_authority.key(),
+        amount,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct BribeRefundedEvent {
+    pub bribe: Pubkey,
+    pub refund_authority: Pubkey,
+
+    /// Amount refunded
+    pub amount: u64,
+}
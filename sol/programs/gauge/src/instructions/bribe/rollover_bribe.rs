@@ -0,0 +1,85 @@
+use crate::{
+    errors::GaugeErrors,
+    state::{Bribe, GaugeConfig},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+/// Permissionless crank that moves the unclaimed tokens of an expired bribe
+/// into an open bribe of the same gauge, mint and creator
+#[derive(Accounts)]
+pub struct RolloverBribe<'info> {
+    pub gauge_config: Account<'info, GaugeConfig>,
+
+    #[account(
+        mut,
+        has_one = vault,
+        constraint = bribe.is_expired(gauge_config.current_epoch) @ GaugeErrors::BribeNotExpired,
+    )]
+    pub bribe: Account<'info, Bribe>,
+
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = target_bribe.pool_gauge == bribe.pool_gauge @ GaugeErrors::InvalidRolloverBribe,
+        constraint = target_bribe.mint == bribe.mint @ GaugeErrors::InvalidRolloverBribe,
+        constraint = target_bribe.refund_authority == bribe.refund_authority @ GaugeErrors::InvalidRolloverBribe,
+        constraint = target_bribe.vault == target_vault.key() @ GaugeErrors::InvalidRolloverBribe,
+        constraint = target_bribe.is_open(gauge_config.current_epoch) @ GaugeErrors::BribeClosed,
+    )]
+    pub target_bribe: Account<'info, Bribe>,
+
+    #[account(mut)]
+    pub target_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> RolloverBribe<'info> {
+    fn rollover_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.vault.to_account_info(),
+                to: self.target_vault.to_account_info(),
+                authority: self.bribe.to_account_info(),
+            },
+        )
+    }
+}
+
+pub fn handler(ctx: Context<RolloverBribe>) -> Result<()> {
+    let amount = ctx.accounts.bribe.settle();
+    ctx.accounts.target_bribe.deposit(amount);
+
+    let epoch_bytes = ctx.accounts.bribe.epoch.to_le_bytes();
+    token::transfer(
+        ctx.accounts
+            .rollover_ctx()
+            .with_signer(&[&ctx.accounts.bribe.seeds(&epoch_bytes)]),
+        amount,
+    )?;
+
+    emit!(BribeRolledOverEvent {
+        bribe: ctx.accounts.bribe.key(),
+        target_bribe: ctx.accounts.target_bribe.key(),
+        target_epoch: ctx.accounts.target_bribe.epoch,
+        amount,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct BribeRolledOverEvent {
+    pub bribe: Pubkey,
+    pub target_bribe: Pubkey,
+
+    /// Epoch whose voters are paid the rolled over tokens
+    pub target_epoch: u64,
+
+    /// Amount rolled over
+    pub amount: u64,
+}
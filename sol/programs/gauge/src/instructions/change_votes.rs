@@ -1,4 +1,6 @@
 use crate::{
+    errors::GaugeErrors,
+    pda::VOTE_LEDGER_SEED,
     state::*,
     syncer::{get_now, sync_gauge},
 };
@@ -6,34 +8,52 @@ use anchor_lang::prelude::*;
 use anchor_lang::solana_program::sysvar::instructions as tx_instructions;
 use reactor::cpi::accounts::{LockVotes, UnlockVotes};
 use reactor::program::Reactor as ReactorProgram;
+use reactor::state::VoteDelegation;
 
 #[derive(Accounts)]
 pub struct ChangeVotes<'info> {
+    /// Reactor owner, or its delegate when a vote delegation is provided
     #[account(mut)]
-    pub owner: Signer<'info>,
+    pub authority: Signer<'info>,
 
     /// Global config for Gauge instance
-    #[account(mut)]
+    #[account(
+        mut,
+        has_one = emission_schedule
+    )]
     pub gauge_config: Account<'info, GaugeConfig>,
 
+    /// Emission schedule for Gauge instance
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+
     /// Gauge for the pool
     #[account(mut)]
     pub pool_gauge: Account<'info, Gauge>,
 
-    /// Personal vote account for owner
+    /// Personal vote account for the reactor owner
     #[account(
         mut,
-        has_one = owner,
-        has_one = pool_gauge
+        has_one = pool_gauge,
+        constraint = personal_gauge.owner == reactor.owner
     )]
     pub personal_gauge: Account<'info, PersonalGauge>,
 
-    /// Personal reactor account for owner
+    /// Personal reactor account
+    #[account(mut)]
+    pub reactor: Account<'info, reactor::state::Reactor>,
+
+    /// Delegation of the reactor's votes, required when the authority is not the owner
+    #[account(has_one = reactor)]
+    pub vote_delegation: Option<Account<'info, reactor::state::VoteDelegation>>,
+
+    /// Vote ledger of the reactor, kept in sync when it exists
+    /// CHECK: PDA of the reactor, only deserialized when initialized
     #[account(
         mut,
-        has_one = owner,
+        seeds = [VOTE_LEDGER_SEED.as_bytes(), reactor.key().as_ref()],
+        bump
     )]
-    pub reactor: Account<'info, reactor::state::Reactor>,
+    pub vote_ledger: UncheckedAccount<'info>,
 
     pub reactor_prog: Program<'info, ReactorProgram>,
 
@@ -47,8 +67,9 @@ impl<'info> ChangeVotes<'info> {
         CpiContext::new(
             self.reactor_prog.to_account_info(),
             UnlockVotes {
-                owner: self.owner.to_account_info(),
+                authority: self.authority.to_account_info(),
                 reactor: self.reactor.to_account_info(),
+                vote_delegation: self.vote_delegation.as_ref().map(|d| d.to_account_info()),
                 sysvar_instruction: self.sysvar_instruction.to_account_info(),
             },
         )
@@ -58,8 +79,9 @@ impl<'info> ChangeVotes<'info> {
         CpiContext::new(
             self.reactor_prog.to_account_info(),
             LockVotes {
-                owner: self.owner.to_account_info(),
+                authority: self.authority.to_account_info(),
                 reactor: self.reactor.to_account_info(),
+                vote_delegation: self.vote_delegation.as_ref().map(|d| d.to_account_info()),
                 sysvar_instruction: self.sysvar_instruction.to_account_info(),
             },
         )
@@ -77,21 +99,39 @@ impl<'info> ChangeVotes<'info> {
 }
 
 pub fn handler(ctx: Context<ChangeVotes>, amount: i64) -> Result<()> {
+    let now = get_now();
+
+    ctx.accounts
+        .gauge_config
+        .check_vote_change(amount, ctx.accounts.pool_gauge.status)?;
+
+    check_vote_authority(
+        ctx.accounts.reactor.owner,
+        ctx.accounts.authority.key(),
+        ctx.accounts.vote_delegation.as_deref(),
+        ctx.accounts.pool_gauge.key(),
+        now,
+    )?;
+
     // attempt to un/lock the amount of votes
     ctx.accounts.cpi_change_votes(amount)?;
 
-    let now = get_now();
-
     handle_change_votes(
         now,
         &mut ctx.accounts.gauge_config,
+        &ctx.accounts.emission_schedule,
         &mut ctx.accounts.pool_gauge,
         &mut ctx.accounts.personal_gauge,
         amount,
-    );
+    )?;
+
+    sync_vote_ledger(
+        &ctx.accounts.vote_ledger,
+        &[(ctx.accounts.pool_gauge.key(), amount)],
+    )?;
 
     emit!(VotesChangedEvent {
-        user: ctx.accounts.owner.key(),
+        user: ctx.accounts.reactor.owner,
         gauge: ctx.accounts.pool_gauge.key(),
         amount_changed: amount,
         total_personal_votes_on_gauge: ctx.accounts.personal_gauge.votes,
@@ -102,23 +142,71 @@ pub fn handler(ctx: Context<ChangeVotes>, amount: i64) -> Result<()> {
     Ok(())
 }
 
-fn handle_change_votes(
+pub(crate) fn handle_change_votes(
     now: u64,
     gauge_config: &mut GaugeConfig,
+    emission_schedule: &EmissionSchedule,
     pool_gauge: &mut Gauge,
     personal_gauge: &mut PersonalGauge,
     amount: i64,
-) {
-    sync_gauge(now, gauge_config, pool_gauge);
+) -> Result<()> {
+    sync_gauge(now, gauge_config, emission_schedule, pool_gauge)?;
 
-    // update the global votes
-    gauge_config.change_votes(amount);
+    // update the global votes, queued until the next epoch in epoch mode
+    // killed gauges' votes are already out of the global total
+    if !pool_gauge.is_killed() {
+        gauge_config.queue_votes(amount);
+    }
+
+    // update the pool gauge votes, queued until the next epoch in epoch mode
+    pool_gauge.queue_votes(amount, gauge_config.next_vote_epoch());
+
+    // update the personal gauge votes, recording when they take effect in epoch mode
+    personal_gauge.queue_votes(amount, gauge_config.next_vote_epoch());
+
+    Ok(())
+}
+
+/// Check that `authority` may change the reactor's votes on a pool gauge
+/// Either the reactor owner, or its unexpired delegate when the gauge is in the delegation allow-list
+pub(crate) fn check_vote_authority(
+    reactor_owner: Pubkey,
+    authority: Pubkey,
+    vote_delegation: Option<&VoteDelegation>,
+    pool_gauge: Pubkey,
+    now: u64,
+) -> Result<()> {
+    if authority == reactor_owner {
+        return Ok(());
+    }
 
-    // update the pool gauge votes
-    pool_gauge.change_votes(amount);
+    match vote_delegation {
+        Some(d) if d.authorizes(authority, now) => {
+            require!(d.allows_gauge(pool_gauge), GaugeErrors::GaugeNotDelegated);
+            Ok(())
+        }
+        _ => err!(GaugeErrors::NotVoteAuthority),
+    }
+}
+
+/// Apply vote changes to the reactor's vote ledger, if it was created
+pub(crate) fn sync_vote_ledger(vote_ledger: &AccountInfo, changes: &[(Pubkey, i64)]) -> Result<()> {
+    if vote_ledger.data_is_empty() {
+        return Ok(());
+    }
+
+    require_keys_eq!(
+        *vote_ledger.owner,
+        crate::ID,
+        ErrorCode::AccountOwnedByWrongProgram
+    );
+
+    let mut ledger = VoteLedger::try_deserialize(&mut &vote_ledger.try_borrow_data()?[..])?;
+    for (pool_gauge, amount) in changes {
+        ledger.change_votes(*pool_gauge, *amount)?;
+    }
 
-    // update the personal gauge votes
-    personal_gauge.change_votes(amount);
+    ledger.try_serialize(&mut &mut vote_ledger.try_borrow_mut_data()?[..])
 }
 
 #[event]
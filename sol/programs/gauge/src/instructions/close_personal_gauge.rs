@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::GaugeErrors, state::PersonalGauge};
+
+/// Close a personal gauge without votes and refund its rent
+/// Its vote history goes with it, so bribes must be claimed first
+#[derive(Accounts)]
+pub struct ClosePersonalGauge<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner,
+        constraint = personal_gauge.votes == 0 @ GaugeErrors::PersonalGaugeNotEmpty,
+        close = destination
+    )]
+    pub personal_gauge: Account<'info, PersonalGauge>,
+
+    /// CHECK: receives the rent of the personal gauge
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+}
+
+pub fn handler(_ctx: Context<ClosePersonalGauge>) -> Result<()> {
+    Ok(())
+}
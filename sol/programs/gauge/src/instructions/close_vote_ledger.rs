@@ -0,0 +1,23 @@
+use crate::state::VoteLedger;
+use anchor_lang::prelude::*;
+
+/// Close the vote ledger of a reactor and refund its rent
+#[derive(Accounts)]
+pub struct CloseVoteLedger<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(has_one = owner)]
+    pub reactor: Account<'info, reactor::state::Reactor>,
+
+    #[account(
+        mut,
+        has_one = reactor,
+        close = owner
+    )]
+    pub vote_ledger: Account<'info, VoteLedger>,
+}
+
+pub fn handler(_ctx: Context<CloseVoteLedger>) -> Result<()> {
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use super::common::get_current_earned_time_units;
 use crate::{
     state::*,
-    syncer::{get_now, sync_gauge},
+    syncer::{get_now, sync_and_stage_rewarder},
 };
 use anchor_lang::prelude::*;
 use personal_rewarder_cl::PersonalRewarderCl;
@@ -16,9 +16,14 @@ pub struct AccrueRayCl<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        has_one = emission_schedule
+    )]
     pub gauge_config: Box<Account<'info, GaugeConfig>>,
 
+    pub emission_schedule: Box<Account<'info, EmissionSchedule>>,
+
     #[account(
         mut,
         has_one = pool_position,
@@ -85,21 +90,22 @@ pub fn handler(ctx: Context<AccrueRayCl>) -> Result<()> {
     // reload the pool_position
     ctx.accounts.pool_position.reload()?;
 
+    let cur_earned_time_units = get_current_earned_time_units(
+        &*ctx.accounts.pool_state.load()?,
+        &ctx.accounts.pool_position,
+        &ctx.accounts.pool_gauge,
+        ctx.accounts.gauge_config.cl_time_tracker_mint,
+    )?;
+
     let now = get_now();
-    sync_gauge(
+    sync_and_stage_rewarder(
         now,
         &mut ctx.accounts.gauge_config,
+        &ctx.accounts.emission_schedule,
         &mut ctx.accounts.pool_gauge,
-    );
-
-    let cur_earned_time_units =
-        get_current_earned_time_units(ctx.accounts.pool_state.load()?, &ctx.accounts.pool_position);
-
-    ctx.accounts.personal_rewarder.sync_and_stage(
-        now,
-        ctx.accounts.pool_gauge.total_ray_emitted,
+        &mut ctx.accounts.personal_rewarder.rewarder,
         cur_earned_time_units,
-    );
+    )?;
 
     Ok(())
 }
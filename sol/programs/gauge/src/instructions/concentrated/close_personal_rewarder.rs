@@ -0,0 +1,129 @@
+use super::common::get_current_earned_time_units;
+use crate::{
+    errors::GaugeErrors,
+    state::*,
+    syncer::{get_now, sync_gauge},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use personal_rewarder_cl::PersonalRewarderCl;
+use raydium_amm_v3::{
+    cpi::{accounts::UpdatePersonalRewards, update_personal_rewards},
+    program::AmmV3,
+    states::{PersonalPositionState, PoolState},
+};
+
+/// Close a CL personal rewarder and refund its rent
+/// The rewarder is accrued first, and must have no staged RAY left
+#[derive(Accounts)]
+pub struct ClosePersonalRewarderCl<'info> {
+    /// Prove ownership of the position by owning the NFT
+    pub nft_owner: Signer<'info>,
+
+    /// The token account for the NFT
+    #[account(
+        constraint = nft_account.mint == pool_position.nft_mint,
+        constraint = nft_account.amount == 1,
+        token::authority = nft_owner
+    )]
+    pub nft_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        has_one = emission_schedule
+    )]
+    pub gauge_config: Box<Account<'info, GaugeConfig>>,
+
+    pub emission_schedule: Box<Account<'info, EmissionSchedule>>,
+
+    #[account(
+        mut,
+        has_one = pool_position,
+        has_one = pool_gauge,
+        close = destination
+    )]
+    pub personal_rewarder: Box<Account<'info, PersonalRewarderCl>>,
+
+    #[account(
+        mut,
+        constraint = pool_gauge.pool_id == pool_state.key()
+    )]
+    pub pool_gauge: Box<Account<'info, Gauge>>,
+
+    #[account(mut)]
+    pub pool_position: Box<Account<'info, PersonalPositionState>>,
+
+    /// CHECK: constrained by CPI call to the CLMM program
+    #[account(mut)]
+    pub pool_state: AccountLoader<'info, PoolState>,
+
+    /// The protocol position for the CLMM program
+    /// CHECK: constrained by the CPI call to the CLMM program
+    #[account(mut)]
+    pub protocol_position: UncheckedAccount<'info>,
+
+    /// CHECK: validated by the CPI call to the CLMM program
+    #[account(mut)]
+    pub tick_array_lower_loader: UncheckedAccount<'info>,
+
+    /// CHECK: validated by the CPI call to the CLMM program
+    #[account(mut)]
+    pub tick_array_upper_loader: UncheckedAccount<'info>,
+
+    /// CHECK: receives the rent of the personal rewarder
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+
+    pub clmm_program: Program<'info, AmmV3>,
+}
+
+impl<'info> ClosePersonalRewarderCl<'info> {
+    fn update_position_rewards_cpi_ctx(
+        &self,
+    ) -> CpiContext<'_, '_, '_, 'info, UpdatePersonalRewards<'info>> {
+        CpiContext::new(
+            self.clmm_program.to_account_info(),
+            UpdatePersonalRewards {
+                pool_state: self.pool_state.to_account_info(),
+                protocol_position: self.protocol_position.to_account_info(),
+                personal_position: self.pool_position.to_account_info(),
+                tick_array_lower_loader: self.tick_array_lower_loader.to_account_info(),
+                tick_array_upper_loader: self.tick_array_upper_loader.to_account_info(),
+            },
+        )
+    }
+}
+
+pub fn handler(ctx: Context<ClosePersonalRewarderCl>) -> Result<()> {
+    // accrue before closing, so that no earned RAY is left behind unstaged
+    update_personal_rewards(ctx.accounts.update_position_rewards_cpi_ctx())?;
+    ctx.accounts.pool_position.reload()?;
+
+    let now = get_now();
+    sync_gauge(
+        now,
+        &mut ctx.accounts.gauge_config,
+        &ctx.accounts.emission_schedule,
+        &mut ctx.accounts.pool_gauge,
+    )?;
+
+    let cur_earned_time_units = get_current_earned_time_units(
+        &*ctx.accounts.pool_state.load()?,
+        &ctx.accounts.pool_position,
+        &ctx.accounts.pool_gauge,
+        ctx.accounts.gauge_config.cl_time_tracker_mint,
+    )?;
+
+    ctx.accounts.personal_rewarder.sync_and_stage(
+        now,
+        ctx.accounts.pool_gauge.total_ray_emitted,
+        cur_earned_time_units,
+    );
+
+    require!(
+        ctx.accounts.personal_rewarder.rewarder.staged_ray == 0,
+        GaugeErrors::RewarderNotEmpty
+    );
+
+    Ok(())
+}
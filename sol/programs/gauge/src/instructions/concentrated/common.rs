@@ -1,22 +1,126 @@
+use crate::{errors::GaugeErrors, state::Gauge};
+use anchor_lang::prelude::*;
 use precise_number::Number;
 use raydium_amm_v3::states::{PersonalPositionState, PoolState};
-use std::cell::Ref;
+
+/// Emission of the time-tracker reward, one unit per second in Q64.64
+pub const TIME_TRACKER_EMISSIONS_PER_SECOND_X64: u128 = 1 << 64;
 
 /// Get the index of the reward info that has the special time tracker mint
-pub fn get_time_tracker_reward_info_index(_pool_state: Ref<'_, PoolState>) -> usize {
-    // TODO - get the time tracker mint from the pool state
-    // pool_state.reward_infos.iter().position(|r| r.token_mint == TIME_TRACKER_MINT).unwrap()
-    return 0;
+pub fn get_time_tracker_reward_info_index(
+    pool_state: &PoolState,
+    time_tracker_mint: Pubkey,
+) -> Result<usize> {
+    // unused reward slots have the default mint
+    require_keys_neq!(
+        time_tracker_mint,
+        Pubkey::default(),
+        GaugeErrors::ClTimeTrackerRewardMissing
+    );
+
+    // copied out of the packed pool state
+    let reward_infos = pool_state.reward_infos;
+
+    reward_infos
+        .iter()
+        .position(|r| {
+            let token_mint = r.token_mint;
+            token_mint == time_tracker_mint
+        })
+        .ok_or(GaugeErrors::ClTimeTrackerRewardMissing.into())
+}
+
+/// Check that the reward slot recorded on the pool gauge still holds the time-tracker mint
+pub fn check_time_tracker_reward_info_index(
+    pool_state: &PoolState,
+    reward_index: u8,
+    time_tracker_mint: Pubkey,
+) -> Result<usize> {
+    let index = usize::from(reward_index);
+
+    // copied out of the packed pool state
+    let reward_infos = pool_state.reward_infos;
+    let token_mint = reward_infos
+        .get(index)
+        .ok_or(GaugeErrors::ClTimeTrackerRewardMissing)?
+        .token_mint;
+    require_keys_eq!(
+        token_mint,
+        time_tracker_mint,
+        GaugeErrors::ClTimeTrackerRewardMissing
+    );
+
+    Ok(index)
 }
 
 pub fn get_current_earned_time_units(
-    pool_state: Ref<'_, PoolState>,
+    pool_state: &PoolState,
     pool_position: &PersonalPositionState,
-) -> Number {
-    let time_tracker_reward_info_index = get_time_tracker_reward_info_index(pool_state);
+    pool_gauge: &Gauge,
+    time_tracker_mint: Pubkey,
+) -> Result<Number> {
+    let time_tracker_reward_info_index = check_time_tracker_reward_info_index(
+        pool_state,
+        pool_gauge.time_tracker_reward_index,
+        time_tracker_mint,
+    )?;
 
     let earned_time_units =
         pool_position.reward_infos[time_tracker_reward_info_index].reward_amount_owed;
 
-    return Number::from(earned_time_units);
+    Ok(Number::from(earned_time_units))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_time_tracker_reward_lookup() {
+        let time_tracker_mint = Pubkey::new_unique();
+        let mut pool_state = PoolState::default();
+        pool_state.reward_infos[0].token_mint = Pubkey::new_unique();
+
+        assert_eq!(
+            get_time_tracker_reward_info_index(&pool_state, time_tracker_mint).unwrap_err(),
+            GaugeErrors::ClTimeTrackerRewardMissing.into()
+        );
+
+        pool_state.reward_infos[2].token_mint = time_tracker_mint;
+        assert_eq!(
+            get_time_tracker_reward_info_index(&pool_state, time_tracker_mint).unwrap(),
+            2
+        );
+
+        // unset mint never matches the unused slots
+        assert_eq!(
+            get_time_tracker_reward_info_index(&pool_state, Pubkey::default()).unwrap_err(),
+            GaugeErrors::ClTimeTrackerRewardMissing.into()
+        );
+    }
+
+    #[test]
+    fn test_time_tracker_reward_index_check() {
+        let time_tracker_mint = Pubkey::new_unique();
+        let mut pool_state = PoolState::default();
+        pool_state.reward_infos[1].token_mint = time_tracker_mint;
+
+        assert_eq!(
+            check_time_tracker_reward_info_index(&pool_state, 1, time_tracker_mint).unwrap(),
+            1
+        );
+
+        // slot recorded on the gauge no longer holds the time-tracker mint
+        assert_eq!(
+            check_time_tracker_reward_info_index(&pool_state, 0, time_tracker_mint).unwrap_err(),
+            GaugeErrors::ClTimeTrackerRewardMissing.into()
+        );
+
+        // out of range slot
+        assert_eq!(
+            check_time_tracker_reward_info_index(&pool_state, u8::MAX, time_tracker_mint)
+                .unwrap_err(),
+            GaugeErrors::ClTimeTrackerRewardMissing.into()
+        );
+    }
 }
@@ -0,0 +1,175 @@
+use super::common::{get_time_tracker_reward_info_index, TIME_TRACKER_EMISSIONS_PER_SECOND_X64};
+use crate::{errors::GaugeErrors, pda::*, state::*, syncer::get_now};
+use anchor_lang::prelude::*;
+use raydium_amm_v3::{
+    cpi::{accounts::InitializeReward, initialize_reward},
+    instructions::InitializeRewardParam,
+    program::AmmV3,
+    states::PoolState,
+};
+
+/// Create the gauge of a CLMM pool, making sure the pool emits the time-tracker reward
+/// The reward is initialized by CPI when the pool does not have it yet
+/// The gauge is whitelisted right away, since it is created by the admin
+#[derive(Accounts)]
+pub struct InitClPoolGauge<'info> {
+    /// Also funds the time-tracker reward when it is initialized
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = admin @ GaugeErrors::NotAdmin,
+        has_one = emission_schedule,
+        constraint = gauge_config.cl_time_tracker_mint != Pubkey::default() @ GaugeErrors::ClTimeTrackerRewardMissing,
+    )]
+    pub gauge_config: Box<Account<'info, GaugeConfig>>,
+
+    pub emission_schedule: Box<Account<'info, EmissionSchedule>>,
+
+    #[account(
+        init,
+        payer = admin,
+        seeds = [
+            POOL_GAUGE_SEED.as_bytes(),
+            pool_state.key().as_ref(),
+        ],
+        space = Gauge::SIZE,
+        bump
+    )]
+    pub pool_gauge: Box<Account<'info, Gauge>>,
+
+    #[account(mut)]
+    pub pool_state: AccountLoader<'info, PoolState>,
+
+    /// CHECK: constrained to the designated time-tracker mint
+    #[account(address = gauge_config.cl_time_tracker_mint)]
+    pub time_tracker_mint: UncheckedAccount<'info>,
+
+    /// CHECK: validated by the CPI call to the CLMM program
+    #[account(mut)]
+    pub funder_token_account: UncheckedAccount<'info>,
+
+    /// CHECK: validated by the CPI call to the CLMM program
+    pub amm_config: UncheckedAccount<'info>,
+
+    /// CHECK: validated by the CPI call to the CLMM program
+    pub operation_state: UncheckedAccount<'info>,
+
+    /// CHECK: validated by the CPI call to the CLMM program
+    #[account(mut)]
+    pub reward_token_vault: UncheckedAccount<'info>,
+
+    /// CHECK: validated by the CPI call to the CLMM program
+    pub reward_token_program: UncheckedAccount<'info>,
+
+    pub clmm_program: Program<'info, AmmV3>,
+
+    pub system_program: Program<'info, System>,
+
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> InitClPoolGauge<'info> {
+    fn initialize_reward_cpi_ctx(&self) -> CpiContext<'_, '_, '_, 'info, InitializeReward<'info>> {
+        CpiContext::new(
+            self.clmm_program.to_account_info(),
+            InitializeReward {
+                reward_funder: self.admin.to_account_info(),
+                funder_token_account: self.funder_token_account.to_account_info(),
+                amm_config: self.amm_config.to_account_info(),
+                pool_state: self.pool_state.to_account_info(),
+                operation_state: self.operation_state.to_account_info(),
+                reward_token_mint: self.time_tracker_mint.to_account_info(),
+                reward_token_vault: self.reward_token_vault.to_account_info(),
+                reward_token_program: self.reward_token_program.to_account_info(),
+                system_program: self.system_program.to_account_info(),
+                rent: self.rent.to_account_info(),
+            },
+        )
+    }
+
+    /// Slot of the time-tracker reward, checked to emit one unit per second
+    fn time_tracker_reward_index(&self) -> Result<usize> {
+        let pool_state = self.pool_state.load()?;
+        let index = get_time_tracker_reward_info_index(
+            &pool_state,
+            self.gauge_config.cl_time_tracker_mint,
+        )?;
+
+        // copied out of the packed pool state
+        let reward_infos = pool_state.reward_infos;
+        let emissions_per_second_x64 = reward_infos[index].emissions_per_second_x64;
+        require!(
+            emissions_per_second_x64 == TIME_TRACKER_EMISSIONS_PER_SECOND_X64,
+            GaugeErrors::ClTimeTrackerRewardMisconfigured
+        );
+
+        Ok(index)
+    }
+}
+
+/// `reward_open_time` and `reward_end_time` are only used when the time-tracker reward is initialized
+pub fn handler(
+    ctx: Context<InitClPoolGauge>,
+    reward_open_time: u64,
+    reward_end_time: u64,
+) -> Result<()> {
+    let has_time_tracker_reward = get_time_tracker_reward_info_index(
+        &*ctx.accounts.pool_state.load()?,
+        ctx.accounts.gauge_config.cl_time_tracker_mint,
+    )
+    .is_ok();
+
+    if !has_time_tracker_reward {
+        initialize_reward(
+            ctx.accounts.initialize_reward_cpi_ctx(),
+            InitializeRewardParam {
+                open_time: reward_open_time,
+                end_time: reward_end_time,
+                emissions_per_second_x64: TIME_TRACKER_EMISSIONS_PER_SECOND_X64,
+            },
+        )?;
+    }
+
+    let time_tracker_reward_index = ctx.accounts.time_tracker_reward_index()?;
+
+    let now = get_now();
+    ctx.accounts
+        .gauge_config
+        .update_index(now, &ctx.accounts.emission_schedule);
+
+    let g = &mut ctx.accounts.pool_gauge;
+
+    // initialize the pool gauge with the current global index
+    g.last_seen_global_index = ctx.accounts.gauge_config.index;
+    g.last_seen_total_emission = ctx.accounts.gauge_config.total_emission;
+    g.pool_id = ctx.accounts.pool_state.key();
+    g.total_ray_emitted = 0;
+    g.total_votes = 0;
+    g.status = GaugeStatus::Whitelisted;
+    g.pending_votes_delta = 0;
+    g.pending_epoch = 0;
+    g.time_tracker_reward_index = time_tracker_reward_index as u8;
+
+    emit!(ClPoolGaugeInitializedEvent {
+        pool_gauge: g.key(),
+        pool_state: g.pool_id,
+        time_tracker_reward_index: g.time_tracker_reward_index,
+        time_tracker_reward_initialized: !has_time_tracker_reward,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ClPoolGaugeInitializedEvent {
+    pub pool_gauge: Pubkey,
+    pub pool_state: Pubkey,
+
+    /// Slot of the time-tracker reward in the pool's reward infos
+    pub time_tracker_reward_index: u8,
+
+    /// Whether the time-tracker reward was initialized by this instruction
+    pub time_tracker_reward_initialized: bool,
+}
@@ -1,6 +1,7 @@
 use super::common::get_current_earned_time_units;
 use crate::{
-    state::{personal_rewarder_cl::PersonalRewarderCl, Gauge, GaugeConfig},
+    pda::PERSONAL_REWARDER_CL_SEED,
+    state::{personal_rewarder_cl::PersonalRewarderCl, EmissionSchedule, Gauge, GaugeConfig},
     syncer::{get_now, sync_gauge},
 };
 use anchor_lang::prelude::*;
@@ -18,9 +19,14 @@ pub struct InitPersonalRewarderCl<'info> {
 
     /// Global tenant for the gauge program
     /// Must be mutable in order to update itself before initializing the personal rewarder
-    #[account(mut)]
+    #[account(
+        mut,
+        has_one = emission_schedule
+    )]
     pub gauge_config: Account<'info, GaugeConfig>,
 
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+
     /// Global pool gauge for the pool
     /// Must be mutable in order to update itself before initializing the personal rewarder
     #[account(
@@ -45,7 +51,7 @@ pub struct InitPersonalRewarderCl<'info> {
         payer = payer,
         space = PersonalRewarderCl::SIZE,
         seeds = [
-            b"personal-rewarder-cl".as_ref(),
+            PERSONAL_REWARDER_CL_SEED.as_bytes(),
             personal_liq_position.key().as_ref(),
         ],
         bump
@@ -102,13 +108,16 @@ pub fn handler(ctx: Context<InitPersonalRewarderCl>) -> Result<()> {
     sync_gauge(
         now,
         &mut ctx.accounts.gauge_config,
+        &ctx.accounts.emission_schedule,
         &mut ctx.accounts.pool_gauge,
-    );
+    )?;
 
     let cur_earned_time_units = get_current_earned_time_units(
-        ctx.accounts.pool_state.load()?,
+        &*ctx.accounts.pool_state.load()?,
         &ctx.accounts.personal_liq_position,
-    );
+        &ctx.accounts.pool_gauge,
+        ctx.accounts.gauge_config.cl_time_tracker_mint,
+    )?;
 
     let personal_rewarder = &mut ctx.accounts.personal_rewarder;
     personal_rewarder.pool = ctx.accounts.pool_state.key();
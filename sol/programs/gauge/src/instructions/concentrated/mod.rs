@@ -1,8 +1,12 @@
 pub mod accrue_ray;
-mod common;
+pub mod close_personal_rewarder;
+pub mod common;
+pub mod init_cl_pool_gauge;
 pub mod init_personal_rewarder;
 pub mod withdraw_ray;
 
 pub use accrue_ray::*;
+pub use close_personal_rewarder::*;
+pub use init_cl_pool_gauge::*;
 pub use init_personal_rewarder::*;
 pub use withdraw_ray::*;
@@ -3,7 +3,7 @@ use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 use personal_rewarder_cl::PersonalRewarderCl;
 use raydium_amm_v3::states::PersonalPositionState;
 
-use crate::state::*;
+use crate::{errors::GaugeErrors, state::*};
 
 /// Collect earned RAY rewards for the personal rewarder
 #[derive(Accounts)]
@@ -27,7 +27,8 @@ pub struct WithdrawRayCl<'info> {
     /// Global, read-only GaugeConfig
     /// Constrains the ray_hopper
     #[account(
-        has_one = ray_hopper
+        has_one = ray_hopper,
+        constraint = !gauge_config.is_paused(PAUSE_CLAIM) @ GaugeErrors::ClaimsPaused,
     )]
     pub gauge_config: Account<'info, GaugeConfig>,
 
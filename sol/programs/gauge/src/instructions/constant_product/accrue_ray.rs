@@ -1,6 +1,6 @@
 use crate::{
     state::*,
-    syncer::{get_now, sync_gauge},
+    syncer::{get_now, sync_and_stage_rewarder},
 };
 use anchor_lang::prelude::*;
 
@@ -9,9 +9,14 @@ pub struct AccrueRay<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        has_one = emission_schedule
+    )]
     pub gauge_config: Account<'info, GaugeConfig>,
 
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+
     #[account(
         mut,
         constraint = personal_rewarder.owner == liq_position.owner,
@@ -62,17 +67,14 @@ pub fn handler(ctx: Context<AccrueRay>) -> Result<()> {
 
     let now = get_now();
 
-    sync_gauge(
+    sync_and_stage_rewarder(
         now,
         &mut ctx.accounts.gauge_config,
+        &ctx.accounts.emission_schedule,
         &mut ctx.accounts.pool_gauge,
-    );
-
-    ctx.accounts.personal_rewarder.sync_and_stage(
-        now,
-        ctx.accounts.pool_gauge.total_ray_emitted,
+        &mut ctx.accounts.personal_rewarder.rewarder,
         ctx.accounts.liq_position.earned_time_units.into(),
-    );
+    )?;
 
     Ok(())
 }
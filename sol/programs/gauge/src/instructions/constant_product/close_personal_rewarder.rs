@@ -0,0 +1,91 @@
+use crate::{
+    errors::GaugeErrors,
+    state::*,
+    syncer::{get_now, sync_gauge},
+};
+use anchor_lang::prelude::*;
+
+/// Close a CP personal rewarder and refund its rent
+/// The rewarder is accrued first, and must have no staged RAY left
+#[derive(Accounts)]
+pub struct ClosePersonalRewarder<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = emission_schedule
+    )]
+    pub gauge_config: Account<'info, GaugeConfig>,
+
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+
+    #[account(
+        mut,
+        has_one = owner,
+        has_one = pool_gauge,
+        constraint = personal_rewarder.owner == liq_position.owner,
+        close = destination
+    )]
+    pub personal_rewarder: Account<'info, PersonalRewarderCp>,
+
+    #[account(mut)]
+    pub pool_gauge: Account<'info, Gauge>,
+
+    #[account(
+        mut,
+        has_one = time_tracker
+    )]
+    pub liq_position: Account<'info, cp_lp_escrow::state::PersonalPosition>,
+
+    #[account(
+        mut,
+        constraint = pool_gauge.pool_id == time_tracker.pool_id
+    )]
+    pub time_tracker: Account<'info, cp_lp_escrow::state::TimeTracker>,
+
+    /// CHECK: receives the rent of the personal rewarder
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+
+    pub cp_lp_escrow_program: Program<'info, cp_lp_escrow::program::CpLpEscrow>,
+}
+
+impl<'i> ClosePersonalRewarder<'i> {
+    fn do_cpi_update_personal_position(&self) -> Result<()> {
+        let cpi_accounts = cp_lp_escrow::cpi::accounts::UpdatePersonalPosition {
+            time_tracker: self.time_tracker.to_account_info(),
+            personal_position: self.liq_position.to_account_info(),
+        };
+        let cpi_program = self.cp_lp_escrow_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        cp_lp_escrow::cpi::update_personal_position(cpi_ctx)
+    }
+}
+
+pub fn handler(ctx: Context<ClosePersonalRewarder>) -> Result<()> {
+    // accrue before closing, so that no earned RAY is left behind unstaged
+    ctx.accounts.do_cpi_update_personal_position()?;
+    ctx.accounts.liq_position.reload()?;
+
+    let now = get_now();
+
+    sync_gauge(
+        now,
+        &mut ctx.accounts.gauge_config,
+        &ctx.accounts.emission_schedule,
+        &mut ctx.accounts.pool_gauge,
+    )?;
+
+    ctx.accounts.personal_rewarder.sync_and_stage(
+        now,
+        ctx.accounts.pool_gauge.total_ray_emitted,
+        ctx.accounts.liq_position.earned_time_units.into(),
+    );
+
+    require!(
+        ctx.accounts.personal_rewarder.rewarder.staged_ray == 0,
+        GaugeErrors::RewarderNotEmpty
+    );
+
+    Ok(())
+}
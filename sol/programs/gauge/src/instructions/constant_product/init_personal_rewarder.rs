@@ -12,9 +12,14 @@ pub struct InitPersonalRewarder<'info> {
     #[account(mut)]
     pub owner: Signer<'info>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        has_one = emission_schedule
+    )]
     pub gauge_config: Account<'info, GaugeConfig>,
 
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+
     /// CHECK: constrained by time tracker
     #[account(mut)]
     pub pool_gauge: Account<'info, Gauge>,
@@ -78,8 +83,9 @@ pub fn handler(ctx: Context<InitPersonalRewarder>) -> Result<()> {
     sync_gauge(
         now,
         &mut ctx.accounts.gauge_config,
+        &ctx.accounts.emission_schedule,
         &mut ctx.accounts.pool_gauge,
-    );
+    )?;
 
     let pr = &mut ctx.accounts.personal_rewarder;
 
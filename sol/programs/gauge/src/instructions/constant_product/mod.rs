@@ -1,7 +1,11 @@
 pub mod accrue_ray;
+pub mod close_personal_rewarder;
 pub mod init_personal_rewarder;
+pub mod withdraw_lp;
 pub mod withdraw_ray;
 
 pub use accrue_ray::*;
+pub use close_personal_rewarder::*;
 pub use init_personal_rewarder::*;
+pub use withdraw_lp::*;
 pub use withdraw_ray::*;
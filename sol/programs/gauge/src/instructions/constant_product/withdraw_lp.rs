@@ -0,0 +1,151 @@
+use crate::{
+    errors::GaugeErrors,
+    state::*,
+    syncer::{get_now, sync_gauge},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+/// Accrue RAY, withdraw LP tokens from the escrow and collect the earned RAY in one go
+#[derive(Accounts)]
+pub struct WithdrawLp<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = ray_hopper,
+        has_one = emission_schedule,
+        constraint = !gauge_config.is_paused(PAUSE_CLAIM) @ GaugeErrors::ClaimsPaused,
+    )]
+    pub gauge_config: Account<'info, GaugeConfig>,
+
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+
+    #[account(
+        mut,
+        has_one = owner,
+        has_one = pool_gauge
+    )]
+    pub personal_rewarder: Account<'info, PersonalRewarderCp>,
+
+    #[account(mut)]
+    pub pool_gauge: Account<'info, Gauge>,
+
+    #[account(
+        mut,
+        has_one = owner,
+        has_one = time_tracker
+    )]
+    pub liq_position: Account<'info, cp_lp_escrow::state::PersonalPosition>,
+
+    #[account(
+        mut,
+        has_one = escrow,
+        constraint = pool_gauge.pool_id == time_tracker.pool_id
+    )]
+    pub time_tracker: Account<'info, cp_lp_escrow::state::TimeTracker>,
+
+    /// LP escrow of the pool, constrained by the time tracker
+    #[account(mut)]
+    pub escrow: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub lp_dst: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub ray_hopper: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub ray_dst: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub cp_lp_escrow_program: Program<'info, cp_lp_escrow::program::CpLpEscrow>,
+}
+
+impl<'i> WithdrawLp<'i> {
+    fn do_cpi_update_personal_position(&self) -> Result<()> {
+        let cpi_accounts = cp_lp_escrow::cpi::accounts::UpdatePersonalPosition {
+            time_tracker: self.time_tracker.to_account_info(),
+            personal_position: self.liq_position.to_account_info(),
+        };
+        let cpi_program = self.cp_lp_escrow_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        cp_lp_escrow::cpi::update_personal_position(cpi_ctx)
+    }
+
+    fn do_cpi_withdraw_lp(&self, amount: u64) -> Result<()> {
+        let cpi_accounts = cp_lp_escrow::cpi::accounts::Withdraw {
+            owner: self.owner.to_account_info(),
+            time_tracker: self.time_tracker.to_account_info(),
+            personal_position: self.liq_position.to_account_info(),
+            escrow: self.escrow.to_account_info(),
+            lp_dst: self.lp_dst.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+        };
+        let cpi_program = self.cp_lp_escrow_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        cp_lp_escrow::cpi::withdraw(cpi_ctx, amount)
+    }
+
+    fn withdraw_ray_ctx(&self) -> CpiContext<'_, '_, '_, 'i, Transfer<'i>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.ray_hopper.to_account_info(),
+                to: self.ray_dst.to_account_info(),
+                authority: self.gauge_config.to_account_info(),
+            },
+        )
+    }
+}
+
+pub fn handler(ctx: Context<WithdrawLp>, amount: u64) -> Result<()> {
+    // accrue on the LP held until now, before the withdrawal lowers it
+    ctx.accounts.do_cpi_update_personal_position()?;
+    ctx.accounts.liq_position.reload()?;
+
+    let now = get_now();
+
+    sync_gauge(
+        now,
+        &mut ctx.accounts.gauge_config,
+        &ctx.accounts.emission_schedule,
+        &mut ctx.accounts.pool_gauge,
+    )?;
+
+    ctx.accounts.personal_rewarder.sync_and_stage(
+        now,
+        ctx.accounts.pool_gauge.total_ray_emitted,
+        ctx.accounts.liq_position.earned_time_units.into(),
+    );
+
+    ctx.accounts.do_cpi_withdraw_lp(amount)?;
+
+    let ray = ctx.accounts.personal_rewarder.collect();
+
+    token::transfer(
+        ctx.accounts
+            .withdraw_ray_ctx()
+            .with_signer(&[&ctx.accounts.gauge_config.seeds()]),
+        ray,
+    )?;
+
+    emit!(WithdrawLpEvent {
+        owner: ctx.accounts.owner.key(),
+        personal_rewarder: ctx.accounts.personal_rewarder.key(),
+        lp_withdrawn: amount,
+        ray_withdrawn: ray,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct WithdrawLpEvent {
+    pub owner: Pubkey,
+    pub personal_rewarder: Pubkey,
+    pub lp_withdrawn: u64,
+    pub ray_withdrawn: u64,
+}
@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 
-use crate::state::*;
+use crate::{errors::GaugeErrors, state::*};
 
 /// Collect earned RAY rewards for the personal rewarder
 #[derive(Accounts)]
@@ -10,7 +10,8 @@ pub struct WithdrawRay<'info> {
     pub owner: Signer<'info>,
 
     #[account(
-        has_one = ray_hopper
+        has_one = ray_hopper,
+        constraint = !gauge_config.is_paused(PAUSE_CLAIM) @ GaugeErrors::ClaimsPaused,
     )]
     pub gauge_config: Account<'info, GaugeConfig>,
 
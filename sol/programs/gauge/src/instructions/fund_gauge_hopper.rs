@@ -0,0 +1,66 @@
+use crate::{state::*, syncer::get_now};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+/// Fund the RAY hopper, raising the remaining emission budget
+#[derive(Accounts)]
+pub struct FundGaugeHopper<'info> {
+    /// Permissionless funder
+    pub funder: Signer<'info>,
+
+    #[account(mut)]
+    pub ray_src: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        has_one = ray_hopper,
+        has_one = emission_schedule
+    )]
+    pub gauge_config: Account<'info, GaugeConfig>,
+
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+
+    #[account(mut)]
+    pub ray_hopper: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> FundGaugeHopper<'info> {
+    fn fund_ray_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.ray_src.to_account_info(),
+                to: self.ray_hopper.to_account_info(),
+                authority: self.funder.to_account_info(),
+            },
+        )
+    }
+}
+
+pub fn handler(ctx: Context<FundGaugeHopper>, amount: u64) -> Result<()> {
+    let remaining_budget =
+        ctx.accounts
+            .gauge_config
+            .fund_budget(amount, get_now(), &ctx.accounts.emission_schedule);
+
+    token::transfer(ctx.accounts.fund_ray_ctx(), amount)?;
+
+    emit!(GaugeHopperFundedEvent {
+        funder: ctx.accounts.funder.key(),
+        amount,
+        remaining_budget,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct GaugeHopperFundedEvent {
+    pub funder: Pubkey,
+    pub amount: u64,
+
+    /// RAY left to emit after funding
+    pub remaining_budget: u64,
+}
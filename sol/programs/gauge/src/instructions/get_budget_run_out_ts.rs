@@ -0,0 +1,18 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Read-only view of when the emission budget runs out
+#[derive(Accounts)]
+pub struct GetBudgetRunOutTs<'info> {
+    #[account(has_one = emission_schedule)]
+    pub gauge_config: Account<'info, GaugeConfig>,
+
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+}
+
+pub fn handler(ctx: Context<GetBudgetRunOutTs>) -> Result<u64> {
+    Ok(ctx
+        .accounts
+        .gauge_config
+        .projected_run_out_ts(&ctx.accounts.emission_schedule))
+}
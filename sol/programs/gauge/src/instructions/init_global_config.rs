@@ -1,14 +1,14 @@
-use crate::{pda::*, state::*, syncer::get_now};
+use crate::{errors::GaugeErrors, pda::*, state::*, syncer::get_now};
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Mint, Token, TokenAccount};
 use reactor::ray_mint::ID as RAY_MINT_ID;
 
 #[derive(Accounts)]
 pub struct InitGaugeConfig<'info> {
-    /// must be admin
+    /// The deployer key bootstraps the config and becomes its first admin
     #[account(
         mut,
-        address = crate::admin::ID
+        address = crate::admin::ID @ GaugeErrors::NotAdmin
     )]
     pub payer: Signer<'info>,
 
@@ -35,6 +35,17 @@ pub struct InitGaugeConfig<'info> {
     )]
     pub ray_hopper: Account<'info, TokenAccount>,
 
+    #[account(
+        init,
+        payer = payer,
+        space = EmissionSchedule::SIZE,
+        seeds = [
+            EMISSION_SCHEDULE_SEED.as_bytes(),
+        ],
+        bump
+    )]
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+
     #[account(address = RAY_MINT_ID)]
     pub ray_mint: Account<'info, Mint>,
 
@@ -49,7 +60,9 @@ pub fn handler(ctx: Context<InitGaugeConfig>, ray_emission_per_day: u64) -> Resu
     gc.last_updated_ts = get_now();
     gc.ray_emission_per_day = ray_emission_per_day;
     gc.ray_hopper = ctx.accounts.ray_hopper.key();
+    gc.emission_schedule = ctx.accounts.emission_schedule.key();
     gc.bump = [ctx.bumps.gauge_config];
+    gc.admin = ctx.accounts.payer.key();
 
     Ok(())
 }
@@ -1,4 +1,4 @@
-use crate::{pda::*, state::*, syncer::get_now};
+use crate::{errors::GaugeErrors, pda::*, state::*, syncer::get_now};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
@@ -6,9 +6,14 @@ pub struct InitConstantGauge<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        has_one = emission_schedule
+    )]
     pub gauge_config: Account<'info, GaugeConfig>,
 
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+
     #[account(
         init,
         payer = payer,
@@ -21,8 +26,11 @@ pub struct InitConstantGauge<'info> {
     )]
     pub pool_gauge: Account<'info, Gauge>,
 
-    /// CHECK: does not matter if gauges are created for fake pools
-    /// There will not be a CP escrow nor CL position for them anyway
+    /// CHECK: only CP swap pools, gauges cannot receive votes until whitelisted by the admin
+    /// CLMM pools are onboarded by the admin with init_cl_pool_gauge, who provisions their time-tracker reward
+    #[account(
+        owner = raydium_cp_swap::ID @ GaugeErrors::NotCpSwapPool
+    )]
     pub pool_id: UncheckedAccount<'info>,
 
     pub system_program: Program<'info, System>,
@@ -30,15 +38,21 @@ pub struct InitConstantGauge<'info> {
 
 pub fn handler(ctx: Context<InitConstantGauge>) -> Result<()> {
     let now = get_now();
-    ctx.accounts.gauge_config.update_index(now);
+    ctx.accounts
+        .gauge_config
+        .update_index(now, &ctx.accounts.emission_schedule);
 
     let g = &mut ctx.accounts.pool_gauge;
 
     // initialize the pool gauge with the current global index
     g.last_seen_global_index = ctx.accounts.gauge_config.index;
+    g.last_seen_total_emission = ctx.accounts.gauge_config.total_emission;
     g.pool_id = ctx.accounts.pool_id.key();
     g.total_ray_emitted = 0;
     g.total_votes = 0;
+    g.status = GaugeStatus::Pending;
+    g.pending_votes_delta = 0;
+    g.pending_epoch = 0;
 
     Ok(())
 }
@@ -0,0 +1,60 @@
+use crate::{
+    errors::GaugeErrors,
+    pda::VOTE_LEDGER_SEED,
+    state::{PersonalGauge, VoteLedger},
+};
+use anchor_lang::prelude::*;
+
+/// Create the vote ledger of a reactor
+/// Remaining accounts are all the owner's personal gauges with votes,
+/// which must add up to the reactor's locked votes
+/// The ledger lists at most `MAX_VOTE_LEDGER_ENTRIES` gauges, while it exists the reactor
+/// cannot vote on more gauges at once
+#[derive(Accounts)]
+pub struct InitVoteLedger<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(has_one = owner)]
+    pub reactor: Account<'info, reactor::state::Reactor>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = VoteLedger::SIZE,
+        seeds = [
+            VOTE_LEDGER_SEED.as_bytes(),
+            reactor.key().as_ref(),
+        ],
+        bump
+    )]
+    pub vote_ledger: Account<'info, VoteLedger>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, InitVoteLedger<'info>>) -> Result<()> {
+    let ledger = &mut ctx.accounts.vote_ledger;
+    ledger.reactor = ctx.accounts.reactor.key();
+
+    for info in ctx.remaining_accounts {
+        let personal_gauge = Account::<PersonalGauge>::try_from(info)?;
+        require_keys_eq!(personal_gauge.owner, ctx.accounts.reactor.owner);
+        require!(
+            ledger.votes_on(personal_gauge.pool_gauge) == 0,
+            GaugeErrors::DuplicateGauge
+        );
+
+        ledger.change_votes(
+            personal_gauge.pool_gauge,
+            personal_gauge.votes.try_into().unwrap(),
+        )?;
+    }
+
+    require!(
+        ledger.total_votes() == ctx.accounts.reactor.locked_votes,
+        GaugeErrors::VoteLedgerIncomplete
+    );
+
+    Ok(())
+}
@@ -1,15 +1,39 @@
+pub mod admin;
+pub mod advance_epoch;
+pub mod apply_epoch_record;
+pub mod bribe;
 pub mod change_votes;
+pub mod close_personal_gauge;
+pub mod close_vote_ledger;
 pub mod concentrated;
 pub mod constant_product;
+pub mod fund_gauge_hopper;
+pub mod get_budget_run_out_ts;
 pub mod init_global_config;
 pub mod init_personal_gauge;
 pub mod init_pool_gauge;
+pub mod init_vote_ledger;
+pub mod reallocate_votes;
+pub mod sync_decayed_votes;
+pub mod sync_gauges;
 pub mod sync_pool_index;
 
+pub use admin::*;
+pub use advance_epoch::*;
+pub use apply_epoch_record::*;
+pub use bribe::*;
 pub use change_votes::*;
+pub use close_personal_gauge::*;
+pub use close_vote_ledger::*;
 pub use concentrated::*;
 pub use constant_product::*;
+pub use fund_gauge_hopper::*;
+pub use get_budget_run_out_ts::*;
 pub use init_global_config::*;
 pub use init_personal_gauge::*;
 pub use init_pool_gauge::*;
+pub use init_vote_ledger::*;
+pub use reallocate_votes::*;
+pub use sync_decayed_votes::*;
+pub use sync_gauges::*;
 pub use sync_pool_index::*;
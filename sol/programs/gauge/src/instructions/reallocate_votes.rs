@@ -0,0 +1,195 @@
+use crate::{
+    errors::GaugeErrors,
+    instructions::change_votes::{
+        check_vote_authority, handle_change_votes, sync_vote_ledger, VotesChangedEvent,
+    },
+    pda::VOTE_LEDGER_SEED,
+    state::*,
+    syncer::get_now,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as tx_instructions;
+use reactor::cpi::accounts::{LockVotes, UnlockVotes};
+use reactor::program::Reactor as ReactorProgram;
+use reactor::state::VoteDelegation;
+
+/// Change the votes on many gauges at once
+/// Remaining accounts are `(pool_gauge, personal_gauge)` pairs, both writable, one pair per delta
+/// The reactor is locked or unlocked by the net change only
+#[derive(Accounts)]
+pub struct ReallocateVotes<'info> {
+    /// Reactor owner, or its delegate when a vote delegation is provided
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Global config for Gauge instance
+    #[account(
+        mut,
+        has_one = emission_schedule
+    )]
+    pub gauge_config: Account<'info, GaugeConfig>,
+
+    /// Emission schedule for Gauge instance
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+
+    /// Personal reactor account
+    #[account(mut)]
+    pub reactor: Account<'info, reactor::state::Reactor>,
+
+    /// Delegation of the reactor's votes, required when the authority is not the owner
+    #[account(has_one = reactor)]
+    pub vote_delegation: Option<Account<'info, reactor::state::VoteDelegation>>,
+
+    /// Vote ledger of the reactor, kept in sync when it exists
+    /// CHECK: PDA of the reactor, only deserialized when initialized
+    #[account(
+        mut,
+        seeds = [VOTE_LEDGER_SEED.as_bytes(), reactor.key().as_ref()],
+        bump
+    )]
+    pub vote_ledger: UncheckedAccount<'info>,
+
+    pub reactor_prog: Program<'info, ReactorProgram>,
+
+    /// CHECK: Provide transaction instruction data.
+    #[account(address = tx_instructions::ID)]
+    pub sysvar_instruction: UncheckedAccount<'info>,
+}
+
+impl<'info> ReallocateVotes<'info> {
+    fn unlock_votes_ctx(&self) -> CpiContext<'_, '_, '_, 'info, UnlockVotes<'info>> {
+        CpiContext::new(
+            self.reactor_prog.to_account_info(),
+            UnlockVotes {
+                authority: self.authority.to_account_info(),
+                reactor: self.reactor.to_account_info(),
+                vote_delegation: self.vote_delegation.as_ref().map(|d| d.to_account_info()),
+                sysvar_instruction: self.sysvar_instruction.to_account_info(),
+            },
+        )
+    }
+
+    fn lock_votes_ctx(&self) -> CpiContext<'_, '_, '_, 'info, LockVotes<'info>> {
+        CpiContext::new(
+            self.reactor_prog.to_account_info(),
+            LockVotes {
+                authority: self.authority.to_account_info(),
+                reactor: self.reactor.to_account_info(),
+                vote_delegation: self.vote_delegation.as_ref().map(|d| d.to_account_info()),
+                sysvar_instruction: self.sysvar_instruction.to_account_info(),
+            },
+        )
+    }
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ReallocateVotes<'info>>,
+    deltas: Vec<i64>,
+) -> Result<()> {
+    require!(
+        ctx.remaining_accounts.len() == deltas.len() * 2,
+        GaugeErrors::InvalidReallocation
+    );
+
+    let now = get_now();
+
+    let mut gauges = Vec::with_capacity(deltas.len());
+    for pair in ctx.remaining_accounts.chunks(2) {
+        let pool_gauge = Account::<Gauge>::try_from(&pair[0])?;
+        let personal_gauge = Account::<PersonalGauge>::try_from(&pair[1])?;
+
+        require!(
+            gauges
+                .iter()
+                .all(|(g, _): &(Account<Gauge>, _)| g.key() != pool_gauge.key()),
+            GaugeErrors::DuplicateGauge
+        );
+        require_keys_eq!(personal_gauge.pool_gauge, pool_gauge.key());
+        require_keys_eq!(personal_gauge.owner, ctx.accounts.reactor.owner);
+
+        gauges.push((pool_gauge, personal_gauge));
+    }
+
+    // every delta is checked here, the reactor program only sees the net change, if any
+    let checks: Vec<(Pubkey, GaugeStatus, i64)> = gauges
+        .iter()
+        .zip(&deltas)
+        .map(|((pool_gauge, _), amount)| (pool_gauge.key(), pool_gauge.status, *amount))
+        .collect();
+    let net = check_reallocation(
+        &ctx.accounts.gauge_config,
+        ctx.accounts.reactor.owner,
+        ctx.accounts.authority.key(),
+        ctx.accounts.vote_delegation.as_deref(),
+        &checks,
+        now,
+    )?;
+
+    // un/lock the net change only, which must fit in the free votes
+    let net_amount = net.unsigned_abs();
+
+    if net > 0 {
+        require!(
+            ctx.accounts.reactor.free_votes(now) >= net_amount,
+            GaugeErrors::InsufficientFreeVotes
+        );
+        reactor::cpi::lock_votes(ctx.accounts.lock_votes_ctx(), net_amount)?;
+    } else if net < 0 {
+        reactor::cpi::unlock_votes(ctx.accounts.unlock_votes_ctx(), net_amount)?;
+    }
+
+    let mut changes = Vec::with_capacity(deltas.len());
+    for ((pool_gauge, personal_gauge), amount) in gauges.iter_mut().zip(deltas) {
+        changes.push((pool_gauge.key(), amount));
+
+        handle_change_votes(
+            now,
+            &mut ctx.accounts.gauge_config,
+            &ctx.accounts.emission_schedule,
+            pool_gauge,
+            personal_gauge,
+            amount,
+        )?;
+
+        pool_gauge.exit(&crate::ID)?;
+        personal_gauge.exit(&crate::ID)?;
+
+        emit!(VotesChangedEvent {
+            user: ctx.accounts.reactor.owner,
+            gauge: pool_gauge.key(),
+            amount_changed: amount,
+            total_personal_votes_on_gauge: personal_gauge.votes,
+            total_votes_on_gauge: pool_gauge.total_votes,
+            global_total_votes: ctx.accounts.gauge_config.total_votes,
+        });
+    }
+
+    // removals first, so that gauges leaving the ledger make room for new ones
+    changes.sort_by_key(|(_, amount)| *amount);
+    sync_vote_ledger(&ctx.accounts.vote_ledger, &changes)?;
+
+    Ok(())
+}
+
+/// Check that `authority` may apply every `(pool_gauge, status, delta)` change, whatever the net change
+/// Returns the net change
+pub(crate) fn check_reallocation(
+    gauge_config: &GaugeConfig,
+    reactor_owner: Pubkey,
+    authority: Pubkey,
+    vote_delegation: Option<&VoteDelegation>,
+    changes: &[(Pubkey, GaugeStatus, i64)],
+    now: u64,
+) -> Result<i64> {
+    let mut net = 0i64;
+    for (pool_gauge, status, amount) in changes {
+        check_vote_authority(reactor_owner, authority, vote_delegation, *pool_gauge, now)?;
+        gauge_config.check_vote_change(*amount, *status)?;
+
+        net = net
+            .checked_add(*amount)
+            .ok_or(GaugeErrors::InvalidReallocation)?;
+    }
+
+    Ok(net)
+}
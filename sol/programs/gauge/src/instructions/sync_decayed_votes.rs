@@ -0,0 +1,194 @@
+use crate::{
+    errors::GaugeErrors,
+    instructions::change_votes::{check_vote_authority, handle_change_votes, sync_vote_ledger},
+    pda::VOTE_LEDGER_SEED,
+    state::*,
+    syncer::get_now,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as tx_instructions;
+use reactor::cpi::accounts::ReleaseDecayedVotes;
+use reactor::program::Reactor as ReactorProgram;
+
+/// Crank that removes decayed ve-mode votes from a reactor's gauges
+/// The pool gauge and global vote totals are rebalanced with it
+/// Remaining accounts are `(pool_gauge, personal_gauge)` pairs, both writable
+///
+/// Signed by the reactor owner or its delegate, the decay is taken from the given gauges in order.
+/// Otherwise the pairs must be every gauge the reactor votes on, their votes adding up to the
+/// reactor's locked votes, and the decay is split over them pro-rata to their votes
+#[derive(Accounts)]
+pub struct SyncDecayedVotes<'info> {
+    /// Reactor owner or its delegate, omitted for the permissionless pro-rata crank
+    pub authority: Option<Signer<'info>>,
+
+    /// Global config for Gauge instance
+    #[account(
+        mut,
+        has_one = emission_schedule
+    )]
+    pub gauge_config: Account<'info, GaugeConfig>,
+
+    /// Emission schedule for Gauge instance
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+
+    /// Personal reactor account whose votes have decayed
+    #[account(mut)]
+    pub reactor: Account<'info, reactor::state::Reactor>,
+
+    /// Delegation of the reactor's votes, required when the authority is not the owner
+    #[account(has_one = reactor)]
+    pub vote_delegation: Option<Account<'info, reactor::state::VoteDelegation>>,
+
+    /// Vote ledger of the reactor, kept in sync when it exists
+    /// CHECK: PDA of the reactor, only deserialized when initialized
+    #[account(
+        mut,
+        seeds = [VOTE_LEDGER_SEED.as_bytes(), reactor.key().as_ref()],
+        bump
+    )]
+    pub vote_ledger: UncheckedAccount<'info>,
+
+    pub reactor_prog: Program<'info, ReactorProgram>,
+
+    /// CHECK: Provide transaction instruction data.
+    #[account(address = tx_instructions::ID)]
+    pub sysvar_instruction: UncheckedAccount<'info>,
+}
+
+impl<'info> SyncDecayedVotes<'info> {
+    fn release_decayed_votes_ctx(
+        &self,
+    ) -> CpiContext<'_, '_, '_, 'info, ReleaseDecayedVotes<'info>> {
+        CpiContext::new(
+            self.reactor_prog.to_account_info(),
+            ReleaseDecayedVotes {
+                reactor: self.reactor.to_account_info(),
+                sysvar_instruction: self.sysvar_instruction.to_account_info(),
+            },
+        )
+    }
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, SyncDecayedVotes<'info>>) -> Result<()> {
+    let pairs = ctx.remaining_accounts.chunks_exact(2);
+    require!(
+        pairs.remainder().is_empty(),
+        GaugeErrors::InvalidReallocation
+    );
+
+    let now = get_now();
+
+    let mut gauges = Vec::with_capacity(pairs.len());
+    for pair in pairs {
+        let pool_gauge = Account::<Gauge>::try_from(&pair[0])?;
+        let personal_gauge = Account::<PersonalGauge>::try_from(&pair[1])?;
+
+        require!(
+            gauges
+                .iter()
+                .all(|(g, _): &(Account<Gauge>, _)| g.key() != pool_gauge.key()),
+            GaugeErrors::DuplicateGauge
+        );
+        require_keys_eq!(personal_gauge.pool_gauge, pool_gauge.key());
+        require_keys_eq!(personal_gauge.owner, ctx.accounts.reactor.owner);
+
+        gauges.push((pool_gauge, personal_gauge));
+    }
+
+    let decayed = ctx.accounts.reactor.decayed_votes(now);
+
+    // the owner or delegate picks the gauges, anyone else spreads the decay over all of them
+    let amounts = match &ctx.accounts.authority {
+        Some(authority) => {
+            let mut remaining = decayed;
+            let mut amounts = Vec::with_capacity(gauges.len());
+            for (pool_gauge, personal_gauge) in &gauges {
+                check_vote_authority(
+                    ctx.accounts.reactor.owner,
+                    authority.key(),
+                    ctx.accounts.vote_delegation.as_deref(),
+                    pool_gauge.key(),
+                    now,
+                )?;
+
+                let amount = remaining.min(personal_gauge.votes);
+                remaining -= amount;
+                amounts.push(amount);
+            }
+            amounts
+        }
+        None => {
+            // holding all the locked votes, the gauges are every gauge the reactor votes on
+            let votes: Vec<u64> = gauges.iter().map(|(_, p)| p.votes).collect();
+            require_eq!(
+                votes.iter().sum::<u64>(),
+                ctx.accounts.reactor.locked_votes,
+                GaugeErrors::DecayNotProRata
+            );
+
+            split_decay(&votes, decayed)
+        }
+    };
+
+    let total: u64 = amounts.iter().sum();
+    if total == 0 {
+        msg!("No decayed votes on gauges, skipping");
+        return Ok(());
+    }
+
+    reactor::cpi::release_decayed_votes(ctx.accounts.release_decayed_votes_ctx(), total)?;
+
+    let mut changes = Vec::with_capacity(gauges.len());
+    for ((pool_gauge, personal_gauge), amount) in gauges.iter_mut().zip(amounts) {
+        if amount == 0 {
+            continue;
+        }
+
+        let amount_changed = -i64::try_from(amount).unwrap();
+        changes.push((pool_gauge.key(), amount_changed));
+
+        handle_change_votes(
+            now,
+            &mut ctx.accounts.gauge_config,
+            &ctx.accounts.emission_schedule,
+            pool_gauge,
+            personal_gauge,
+            amount_changed,
+        )?;
+
+        pool_gauge.exit(&crate::ID)?;
+        personal_gauge.exit(&crate::ID)?;
+
+        emit!(VotesDecayedEvent {
+            user: personal_gauge.owner,
+            gauge: pool_gauge.key(),
+            amount_decayed: amount,
+            total_personal_votes_on_gauge: personal_gauge.votes,
+            total_votes_on_gauge: pool_gauge.total_votes,
+            global_total_votes: ctx.accounts.gauge_config.total_votes,
+        });
+    }
+
+    sync_vote_ledger(&ctx.accounts.vote_ledger, &changes)?;
+
+    Ok(())
+}
+
+#[event]
+pub struct VotesDecayedEvent {
+    pub user: Pubkey,
+    pub gauge: Pubkey,
+
+    /// Amount of votes removed from the gauge
+    pub amount_decayed: u64,
+
+    /// New total personal amount of votes in the gauge
+    pub total_personal_votes_on_gauge: u64,
+
+    /// Total amount of votes in the gauge
+    pub total_votes_on_gauge: u64,
+
+    /// Total votes across all gauges
+    pub global_total_votes: u64,
+}
@@ -0,0 +1,65 @@
+use crate::{
+    state::*,
+    syncer::{apply_index, get_now},
+};
+use anchor_lang::prelude::*;
+
+/// Permissionless crank that syncs any number of pool gauges at once
+/// Gauges are passed as writable remaining accounts
+#[derive(Accounts)]
+pub struct SyncGauges<'info> {
+    #[account(
+        mut,
+        has_one = emission_schedule
+    )]
+    pub gauge_config: Account<'info, GaugeConfig>,
+
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, SyncGauges<'info>>) -> Result<()> {
+    let gc = &mut ctx.accounts.gauge_config;
+
+    gc.update_index(get_now(), &ctx.accounts.emission_schedule);
+
+    let mut gauges = Vec::with_capacity(ctx.remaining_accounts.len());
+    for info in ctx.remaining_accounts {
+        let mut pool_gauge = Account::<Gauge>::try_from(info)?;
+
+        apply_index(gc, &mut pool_gauge)?;
+        pool_gauge.exit(&crate::ID)?;
+
+        gauges.push(GaugeSynced {
+            gauge: pool_gauge.key(),
+            total_votes: pool_gauge.total_votes,
+            total_ray_emitted: pool_gauge.total_ray_emitted,
+        });
+    }
+
+    emit!(GaugesSyncedEvent {
+        global_total_votes: gc.total_votes,
+        gauges,
+    });
+
+    Ok(())
+}
+
+/// Per-gauge summary of a sync
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct GaugeSynced {
+    pub gauge: Pubkey,
+
+    /// Total votes in the gauge
+    pub total_votes: u64,
+
+    /// Lifetime total RAY emitted by the gauge
+    pub total_ray_emitted: u64,
+}
+
+#[event]
+pub struct GaugesSyncedEvent {
+    /// Total votes across all gauges
+    pub global_total_votes: u64,
+
+    pub gauges: Vec<GaugeSynced>,
+}
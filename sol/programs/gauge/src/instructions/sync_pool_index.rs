@@ -7,9 +7,14 @@ use anchor_lang::prelude::*;
 /// Sync the index for the pool, increasing the amount of RAY rewards earned by the pool
 #[derive(Accounts)]
 pub struct SyncPoolIndex<'info> {
-    #[account(mut)]
+    #[account(
+        mut,
+        has_one = emission_schedule
+    )]
     pub gauge_config: Account<'info, GaugeConfig>,
 
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+
     #[account(mut)]
     pub pool_gauge: Account<'info, Gauge>,
 }
@@ -20,8 +25,9 @@ pub fn handler(ctx: Context<SyncPoolIndex>) -> Result<()> {
     sync_gauge(
         now,
         &mut ctx.accounts.gauge_config,
+        &ctx.accounts.emission_schedule,
         &mut ctx.accounts.pool_gauge,
-    );
+    )?;
 
     Ok(())
 }
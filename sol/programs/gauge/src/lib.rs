@@ -1,14 +1,23 @@
 use anchor_lang::prelude::*;
 
+mod errors;
 mod instructions;
 pub mod pda;
 pub mod state;
-mod syncer;
+pub mod syncer;
 
 use instructions::*;
+use state::{EmissionSegment, GaugeStatus};
+
+/// Time units earned by a CLMM position, for off-chain projections
+pub use instructions::concentrated::common::{
+    get_current_earned_time_units, TIME_TRACKER_EMISSIONS_PER_SECOND_X64,
+};
 
 declare_id!("b1tVsd3q8i4JpSJctQCQtkScXou4mVaKVhSJThiqf3s");
 
+/// Deployer key that may initialize the config
+/// Once initialized, admin authority is the `admin` stored on the `GaugeConfig`
 pub mod admin {
     use anchor_lang::prelude::declare_id;
 
@@ -30,25 +39,178 @@ pub mod gauge {
         init_global_config::handler(ctx, ray_emission_per_day)
     }
 
+    /// Propose a new admin for the gauge config
+    pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+        propose_admin::handler(ctx, new_admin)
+    }
+
+    /// Accept the admin role as the pending admin
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        accept_admin::handler(ctx)
+    }
+
+    /// Set the bitmask of paused actions
+    pub fn set_pause_flags(ctx: Context<SetPauseFlags>, pause_flags: u8) -> Result<()> {
+        set_pause_flags::handler(ctx, pause_flags)
+    }
+
+    /// Replace the emission schedule, settling emission under the old schedule first
+    pub fn set_emission_schedule(
+        ctx: Context<SetEmissionSchedule>,
+        segments: Vec<EmissionSegment>,
+    ) -> Result<()> {
+        set_emission_schedule::handler(ctx, segments)
+    }
+
+    /// Set the global cap on the share of emission a gauge may receive
+    pub fn set_max_vote_share_bps<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SetMaxVoteShareBps<'info>>,
+        max_vote_share_bps: u16,
+    ) -> Result<()> {
+        set_max_vote_share_bps::handler(ctx, max_vote_share_bps)
+    }
+
+    /// Set the cap on the share of emission a single gauge may receive, overriding the global cap
+    pub fn set_gauge_max_vote_share_bps(
+        ctx: Context<SetGaugeMaxVoteShareBps>,
+        max_vote_share_bps: u16,
+    ) -> Result<()> {
+        set_gauge_max_vote_share_bps::handler(ctx, max_vote_share_bps)
+    }
+
+    /// Whitelist, kill or revive a pool gauge
+    pub fn set_gauge_status(ctx: Context<SetGaugeStatus>, status: GaugeStatus) -> Result<()> {
+        set_gauge_status::handler(ctx, status)
+    }
+
+    /// Designate the mint of the CLMM reward that tracks time units, once
+    pub fn set_cl_time_tracker_mint(
+        ctx: Context<SetClTimeTrackerMint>,
+        mint: Pubkey,
+    ) -> Result<()> {
+        set_cl_time_tracker_mint::handler(ctx, mint)
+    }
+
+    /// Turn on epoch mode, or change the length of the following epochs
+    pub fn set_epoch_length(ctx: Context<SetEpochLength>, epoch_length: u64) -> Result<()> {
+        set_epoch_length::handler(ctx, epoch_length)
+    }
+
     /// Init a vote-tracking gauge
     pub fn init_personal_gauge(ctx: Context<InitPersonalGauge>) -> Result<()> {
         init_personal_gauge::handler(ctx)
     }
 
+    /// Close a personal gauge without votes and refund its rent
+    pub fn close_personal_gauge(ctx: Context<ClosePersonalGauge>) -> Result<()> {
+        close_personal_gauge::handler(ctx)
+    }
+
+    /// Create the gauge of a CP swap pool, pending until the admin whitelists it with `set_gauge_status`
     pub fn init_pool_gauge(ctx: Context<InitConstantGauge>) -> Result<()> {
         init_pool_gauge::handler(ctx)
     }
 
+    /// Create the gauge of a CLMM pool, provisioning its time-tracker reward if needed
+    pub fn init_cl_pool_gauge(
+        ctx: Context<InitClPoolGauge>,
+        reward_open_time: u64,
+        reward_end_time: u64,
+    ) -> Result<()> {
+        instructions::concentrated::init_cl_pool_gauge::handler(
+            ctx,
+            reward_open_time,
+            reward_end_time,
+        )
+    }
+
     /// Pledge/Unpledge votes to a gauge
     pub fn change_votes(ctx: Context<ChangeVotes>, amount: i64) -> Result<()> {
         change_votes::handler(ctx, amount)
     }
 
+    /// Create the ledger listing the votes of a reactor per gauge, for up to 16 gauges
+    pub fn init_vote_ledger<'info>(
+        ctx: Context<'_, '_, 'info, 'info, InitVoteLedger<'info>>,
+    ) -> Result<()> {
+        init_vote_ledger::handler(ctx)
+    }
+
+    /// Close the vote ledger of a reactor and refund its rent
+    pub fn close_vote_ledger(ctx: Context<CloseVoteLedger>) -> Result<()> {
+        close_vote_ledger::handler(ctx)
+    }
+
+    /// Pledge/Unpledge votes on many gauges, un/locking only the net change in the reactor
+    pub fn reallocate_votes<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReallocateVotes<'info>>,
+        deltas: Vec<i64>,
+    ) -> Result<()> {
+        reallocate_votes::handler(ctx, deltas)
+    }
+
+    /// Remove decayed ve-mode votes from a reactor's gauges, rebalancing the vote totals
+    pub fn sync_decayed_votes<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SyncDecayedVotes<'info>>,
+    ) -> Result<()> {
+        sync_decayed_votes::handler(ctx)
+    }
+
+    /// End the current voting epoch and apply the queued votes
+    pub fn advance_epoch(ctx: Context<AdvanceEpoch>) -> Result<()> {
+        advance_epoch::handler(ctx)
+    }
+
+    /// Apply a gauge's queued votes at an epoch boundary that left the epoch history
+    pub fn apply_epoch_record(ctx: Context<ApplyEpochRecord>) -> Result<()> {
+        apply_epoch_record::handler(ctx)
+    }
+
+    /// Create the bribe of a pool gauge for an epoch, in any SPL mint
+    pub fn init_bribe(ctx: Context<InitBribe>, epoch: u64) -> Result<()> {
+        init_bribe::handler(ctx, epoch)
+    }
+
+    /// Add tokens to a bribe, until the bribed epoch ends
+    pub fn deposit_bribe(ctx: Context<DepositBribe>, amount: u64) -> Result<()> {
+        deposit_bribe::handler(ctx, amount)
+    }
+
+    /// Claim a share of a bribe, pro-rata to the votes during the bribed epoch
+    pub fn claim_bribe(ctx: Context<ClaimBribe>) -> Result<()> {
+        claim_bribe::handler(ctx)
+    }
+
+    /// Move the unclaimed tokens of an expired bribe into an open bribe of the same creator
+    pub fn rollover_bribe(ctx: Context<RolloverBribe>) -> Result<()> {
+        rollover_bribe::handler(ctx)
+    }
+
+    /// Refund the unclaimed tokens of an expired bribe to its creator
+    pub fn refund_bribe(ctx: Context<RefundBribe>) -> Result<()> {
+        refund_bribe::handler(ctx)
+    }
+
     /// Update the pool's index to the global index
     pub fn sync_pool_index(ctx: Context<SyncPoolIndex>) -> Result<()> {
         sync_pool_index::handler(ctx)
     }
 
+    /// Update many pools' indexes to the global index at once
+    pub fn sync_gauges<'info>(ctx: Context<'_, '_, 'info, 'info, SyncGauges<'info>>) -> Result<()> {
+        sync_gauges::handler(ctx)
+    }
+
+    /// Fund the RAY hopper, raising the budget that gauges may emit
+    pub fn fund_gauge_hopper(ctx: Context<FundGaugeHopper>, amount: u64) -> Result<()> {
+        fund_gauge_hopper::handler(ctx, amount)
+    }
+
+    /// View the projected timestamp at which the emission budget runs out
+    pub fn get_budget_run_out_ts(ctx: Context<GetBudgetRunOutTs>) -> Result<u64> {
+        get_budget_run_out_ts::handler(ctx)
+    }
+
     /// CP: Initialize the personal RAY rewarder
     pub fn cp_init_personal_rewarder(ctx: Context<InitPersonalRewarder>) -> Result<()> {
         instructions::constant_product::init_personal_rewarder::handler(ctx)
@@ -64,6 +226,16 @@ pub mod gauge {
         instructions::constant_product::withdraw_ray::handler(ctx)
     }
 
+    /// CP: Accrue RAY, withdraw LP from the escrow and withdraw the earned RAY in one instruction
+    pub fn cp_withdraw_lp(ctx: Context<WithdrawLp>, amount: u64) -> Result<()> {
+        instructions::constant_product::withdraw_lp::handler(ctx, amount)
+    }
+
+    /// CP: Close an empty personal rewarder and refund its rent
+    pub fn cp_close_personal_rewarder(ctx: Context<ClosePersonalRewarder>) -> Result<()> {
+        instructions::constant_product::close_personal_rewarder::handler(ctx)
+    }
+
     /// CL: Withdraw earned RAY from the hopper and zero-out staged ray in the rewarder
     pub fn cl_withdraw_ray(ctx: Context<WithdrawRayCl>) -> Result<WithdrawRayClEvent> {
         instructions::concentrated::withdraw_ray::handler(ctx)
@@ -78,4 +250,9 @@ pub mod gauge {
     pub fn cl_init_personal_rewarder(ctx: Context<InitPersonalRewarderCl>) -> Result<()> {
         instructions::concentrated::init_personal_rewarder::handler(ctx)
     }
+
+    /// CL: Close an empty personal rewarder and refund its rent
+    pub fn cl_close_personal_rewarder(ctx: Context<ClosePersonalRewarderCl>) -> Result<()> {
+        instructions::concentrated::close_personal_rewarder::handler(ctx)
+    }
 }
@@ -2,4 +2,11 @@ pub const GLOBAL_CONFIG_SEED: &str = "global-config";
 pub const POOL_GAUGE_SEED: &str = "pool-gauge";
 pub const PERSONAL_GAUGE_SEED: &str = "personal-gauge";
 pub const PERSONAL_REWARDER_CP_SEED: &str = "personal-rewarder-cp";
+pub const PERSONAL_REWARDER_CL_SEED: &str = "personal-rewarder-cl";
 pub const GLOBAL_RAY_HOPPER_SEED: &str = "ray-hopper";
+pub const EMISSION_SCHEDULE_SEED: &str = "emission-schedule";
+pub const BRIBE_SEED: &str = "bribe";
+pub const BRIBE_VAULT_SEED: &str = "bribe-vault";
+pub const BRIBE_CLAIM_SEED: &str = "bribe-claim";
+pub const VOTE_LEDGER_SEED: &str = "vote-ledger";
+pub const EPOCH_RECORD_SEED: &str = "epoch-record";
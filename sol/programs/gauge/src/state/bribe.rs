@@ -0,0 +1,140 @@
+use crate::pda::BRIBE_SEED;
+use anchor_lang::prelude::*;
+
+/// Number of epochs after a bribed epoch ends during which voters may claim
+/// Afterwards, the unclaimed amount can be rolled over or refunded
+pub const BRIBE_CLAIM_EPOCHS: u64 = 2;
+
+/// Tokens offered to the voters of a pool gauge for one epoch
+/// One per gauge, mint and epoch, deposits are permissionless
+#[account]
+pub struct Bribe {
+    /// Pool gauge whose voters are paid
+    pub pool_gauge: Pubkey,
+
+    /// Mint of the bribe tokens
+    pub mint: Pubkey,
+
+    /// Epoch whose voters are paid
+    pub epoch: u64,
+
+    /// Token account holding the bribe, owned by this account
+    pub vault: Pubkey,
+
+    /// Creator of the bribe, receives refunds of unclaimed tokens
+    pub refund_authority: Pubkey,
+
+    /// Total amount deposited
+    pub total_amount: u64,
+
+    /// Amount claimed by voters
+    pub claimed_amount: u64,
+
+    /// Unclaimed amount rolled over or refunded after the claim window
+    pub settled_amount: u64,
+
+    pub bump: [u8; 1],
+}
+
+impl Bribe {
+    pub const SIZE: usize =
+        // discriminator
+        8 +
+        // pool_gauge
+        32 +
+        // mint
+        32 +
+        // epoch
+        8 +
+        // vault
+        32 +
+        // refund_authority
+        32 +
+        // total_amount
+        8 +
+        // claimed_amount
+        8 +
+        // settled_amount
+        8 +
+        // bump
+        1;
+
+    /// Deposits are accepted until the bribed epoch ends
+    pub fn is_open(&self, current_epoch: u64) -> bool {
+        current_epoch <= self.epoch
+    }
+
+    /// Voters may claim once the bribed epoch ended, for `BRIBE_CLAIM_EPOCHS` epochs
+    pub fn is_claimable(&self, current_epoch: u64) -> bool {
+        self.epoch < current_epoch && current_epoch <= self.epoch + BRIBE_CLAIM_EPOCHS
+    }
+
+    /// The claim window is over
+    pub fn is_expired(&self, current_epoch: u64) -> bool {
+        current_epoch > self.epoch + BRIBE_CLAIM_EPOCHS
+    }
+
+    pub fn deposit(&mut self, amount: u64) {
+        self.total_amount = self.total_amount.checked_add(amount).unwrap();
+    }
+
+    /// Amount owed to a voter with `personal_votes` out of the gauge's `gauge_votes` during the epoch
+    /// Rounds down, the dust is left for the rollover or refund
+    pub fn claim(&mut self, personal_votes: u64, gauge_votes: u64) -> u64 {
+        if personal_votes == 0 {
+            return 0;
+        }
+
+        let amount =
+            (self.total_amount as u128 * personal_votes as u128 / gauge_votes as u128) as u64;
+        self.claimed_amount = self.claimed_amount.checked_add(amount).unwrap();
+
+        amount
+    }
+
+    pub fn unsettled_amount(&self) -> u64 {
+        self.total_amount - self.claimed_amount - self.settled_amount
+    }
+
+    /// Take out everything that was not claimed
+    pub fn settle(&mut self) -> u64 {
+        let amount = self.unsettled_amount();
+        self.settled_amount += amount;
+
+        amount
+    }
+
+    /// Signer seeds, `epoch_bytes` being the little-endian `epoch`
+    pub fn seeds<'a>(&'a self, epoch_bytes: &'a [u8; 8]) -> [&'a [u8]; 5] {
+        [
+            BRIBE_SEED.as_bytes(),
+            self.pool_gauge.as_ref(),
+            self.mint.as_ref(),
+            epoch_bytes.as_ref(),
+            self.bump.as_ref(),
+        ]
+    }
+}
+
+/// Receipt that a personal gauge claimed a bribe, preventing double claims
+#[account]
+pub struct BribeClaim {
+    pub bribe: Pubkey,
+
+    pub personal_gauge: Pubkey,
+
+    /// Amount claimed
+    pub amount: u64,
+}
+
+impl BribeClaim {
+    pub const SIZE: usize =
+        // discriminator
+        8 +
+        // bribe
+        32 +
+        // personal_gauge
+        32 +
+        // amount
+        8;
+}
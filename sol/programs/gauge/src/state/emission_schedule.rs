@@ -0,0 +1,137 @@
+use anchor_lang::prelude::*;
+
+/// Maximum number of segments in an emission schedule
+pub const MAX_EMISSION_SEGMENTS: usize = 16;
+
+/// A period of constant emission, lasting until the next segment starts
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, AnchorDeserialize, AnchorSerialize)]
+pub struct EmissionSegment {
+    /// Timestamp at which this rate takes effect
+    pub start_ts: u64,
+
+    /// Total RAY emission per day during this segment
+    pub ray_per_day: u64,
+}
+
+impl EmissionSegment {
+    pub const SIZE: usize =
+        // start_ts
+        8 +
+        // ray_per_day
+        8;
+}
+
+/// Stepped schedule of RAY emission for all gauges
+/// Before the first segment starts, the `GaugeConfig.ray_emission_per_day` rate applies
+/// After the last segment starts, its rate applies indefinitely
+#[account]
+pub struct EmissionSchedule {
+    /// Number of segments in use
+    pub num_segments: u8,
+
+    /// Segments, sorted by strictly increasing start time
+    pub segments: [EmissionSegment; MAX_EMISSION_SEGMENTS],
+}
+
+impl Default for EmissionSchedule {
+    fn default() -> Self {
+        Self {
+            num_segments: 0,
+            segments: [EmissionSegment::default(); MAX_EMISSION_SEGMENTS],
+        }
+    }
+}
+
+impl EmissionSchedule {
+    pub const SIZE: usize =
+        // discriminator
+        8 +
+        // num_segments
+        1 +
+        // segments
+        EmissionSegment::SIZE * MAX_EMISSION_SEGMENTS;
+
+    /// Build a schedule from a list of segments
+    /// Returns `None` if there are too many segments or they are not sorted by start time
+    pub fn from_segments(segments: &[EmissionSegment]) -> Option<Self> {
+        if segments.len() > MAX_EMISSION_SEGMENTS {
+            return None;
+        }
+
+        let is_sorted = segments.windows(2).all(|w| w[0].start_ts < w[1].start_ts);
+        if !is_sorted {
+            return None;
+        }
+
+        let mut schedule = Self {
+            num_segments: segments.len() as u8,
+            ..Default::default()
+        };
+        schedule.segments[..segments.len()].copy_from_slice(segments);
+
+        Some(schedule)
+    }
+
+    pub fn segments(&self) -> &[EmissionSegment] {
+        &self.segments[..self.num_segments as usize]
+    }
+
+    /// RAY-seconds emitted between `from` and `to`, integrated across segment boundaries
+    /// Dividing by the seconds in a day gives the RAY emitted
+    pub fn ray_seconds_between(&self, base_ray_per_day: u64, from: u64, to: u64) -> u128 {
+        let mut ray_seconds: u128 = 0;
+        let mut cursor = from;
+        let mut rate = base_ray_per_day;
+
+        for segment in self.segments() {
+            if segment.start_ts >= to {
+                break;
+            }
+
+            if segment.start_ts > cursor {
+                ray_seconds += rate as u128 * (segment.start_ts - cursor) as u128;
+                cursor = segment.start_ts;
+            }
+
+            rate = segment.ray_per_day;
+        }
+
+        ray_seconds + rate as u128 * (to - cursor) as u128
+    }
+
+    /// Timestamp at which `budget_ray_seconds` runs out when emitting from `from`
+    /// Returns `u64::MAX` if the schedule never uses up the budget
+    pub fn run_out_ts(&self, base_ray_per_day: u64, from: u64, budget_ray_seconds: u128) -> u64 {
+        if budget_ray_seconds == 0 {
+            return from;
+        }
+
+        let mut remaining = budget_ray_seconds;
+        let mut cursor = from;
+        let mut rate = base_ray_per_day;
+
+        for segment in self.segments() {
+            if segment.start_ts > cursor {
+                let piece = rate as u128 * (segment.start_ts - cursor) as u128;
+                if piece >= remaining {
+                    break;
+                }
+
+                remaining -= piece;
+                cursor = segment.start_ts;
+            }
+
+            rate = segment.ray_per_day;
+        }
+
+        if rate == 0 {
+            return u64::MAX;
+        }
+
+        remaining
+            .div_ceil(rate as u128)
+            .checked_add(cursor as u128)
+            .and_then(|ts| u64::try_from(ts).ok())
+            .unwrap_or(u64::MAX)
+    }
+}
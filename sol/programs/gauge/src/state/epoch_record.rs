@@ -0,0 +1,17 @@
+use crate::state::EpochSnapshot;
+use anchor_lang::prelude::*;
+
+/// Permanent copy of an epoch boundary, written when the epoch is advanced
+/// Lets gauges apply queued votes after the boundary left the config's epoch history
+#[account]
+pub struct EpochRecord {
+    pub snapshot: EpochSnapshot,
+}
+
+impl EpochRecord {
+    pub const SIZE: usize =
+        // discriminator
+        8 +
+        // snapshot
+        EpochSnapshot::SIZE;
+}
@@ -1,8 +1,27 @@
-use crate::state::NumberRaw;
+use crate::state::{EpochSnapshot, NumberRaw, VoteHistory};
 use anchor_lang::prelude::*;
 use precise_number::Number;
 
+/// A share of 100%, in bps
+pub const MAX_BPS: u16 = 10_000;
+
+/// Admin-set listing status of a pool gauge
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, AnchorDeserialize, AnchorSerialize)]
+pub enum GaugeStatus {
+    /// Created permissionlessly, cannot receive new votes until whitelisted
+    #[default]
+    Pending,
+
+    /// Can receive votes and emission
+    Whitelisted,
+
+    /// Votes are left out of the global total, so the gauge receives no emission
+    /// Votes can only be pulled out
+    Killed,
+}
+
 /// The specific gauge for a pool
+#[derive(Default)]
 #[account]
 pub struct Gauge {
     /// The pool id that this gauge is connected to
@@ -17,6 +36,29 @@ pub struct Gauge {
 
     /// Lifetime total RAY emitted by this gauge
     pub total_ray_emitted: u64,
+
+    /// Maximum share of the global emission this gauge may receive, in bps
+    /// 0 falls back to the global cap
+    pub max_vote_share_bps: u16,
+
+    /// The snapshot of the global lifetime emission when the pool was last updated
+    pub last_seen_total_emission: NumberRaw,
+
+    /// Listing status set by the admin
+    pub status: GaugeStatus,
+
+    /// Change to `total_votes` queued in epoch mode
+    pub pending_votes_delta: i64,
+
+    /// Epoch at whose start the queued votes take effect
+    pub pending_epoch: u64,
+
+    /// Total votes in effect during recent epochs, for bribe claims
+    pub vote_history: VoteHistory,
+
+    /// Slot of the time-tracker reward in the CLMM pool's reward infos
+    /// Only set for CL gauges
+    pub time_tracker_reward_index: u8,
 }
 
 impl Gauge {
@@ -30,14 +72,37 @@ impl Gauge {
         // last_seen_global_index
         NumberRaw::SIZE +
         // total_ray_emitted
-        8;
+        8 +
+        // max_vote_share_bps
+        2 +
+        // last_seen_total_emission
+        NumberRaw::SIZE +
+        // status
+        1 +
+        // pending_votes_delta
+        8 +
+        // pending_epoch
+        8 +
+        // vote_history
+        VoteHistory::SIZE +
+        // time_tracker_reward_index
+        1;
 
     /// Update the amount of RAY emitted from this gauge
+    /// RAY above the gauge's share cap is diverted instead of emitted
+    /// Returns the amount of RAY diverted, so that emitted + diverted is exactly the uncapped emission
     ///
     /// # Arguments
     ///
     /// * `global_index` - The global index for all gauges, distributing RAY
-    pub fn update_index(&mut self, global_index: Number) {
+    /// * `global_total_emission` - The lifetime RAY emitted through the global index
+    /// * `global_max_vote_share_bps` - The cap applying to gauges without their own cap
+    pub fn update_index(
+        &mut self,
+        global_index: Number,
+        global_total_emission: Number,
+        global_max_vote_share_bps: u16,
+    ) -> u64 {
         assert!(
             global_index >= self.last_seen_global_index.into(),
             "local index greater than global index"
@@ -46,10 +111,129 @@ impl Gauge {
         let delta = global_index - self.last_seen_global_index.into();
 
         // the amount of RAY received by the gauge is the total votes on the gauge multiplied by the index delta
-        let ray_distributed = delta * Number::from_natural_u64(self.total_votes);
+        // killed gauges' votes are not part of the global total, so they receive nothing
+        let ray_distributed = if self.is_killed() {
+            Number::ZERO
+        } else {
+            delta * Number::from_natural_u64(self.total_votes)
+        };
+
+        let mut ray_emitted = ray_distributed.floor_u64();
+        let mut ray_diverted = 0;
+
+        if let Some(cap_bps) = self.effective_max_vote_share_bps(global_max_vote_share_bps) {
+            // the cap is a share of everything emitted globally since the last update
+            let emission_delta = global_total_emission - self.last_seen_total_emission.into();
+            let ray_cap = (emission_delta * Number::from_bps(cap_bps)).floor_u64();
 
-        self.total_ray_emitted += ray_distributed.floor_u64();
+            if ray_emitted > ray_cap {
+                ray_diverted = ray_emitted - ray_cap;
+                ray_emitted = ray_cap;
+            }
+        }
+
+        self.total_ray_emitted += ray_emitted;
         self.last_seen_global_index = global_index.into();
+        self.last_seen_total_emission = global_total_emission.into();
+
+        ray_diverted
+    }
+
+    /// The gauge's own cap, or the global cap
+    /// `None` if neither restricts the share
+    pub fn effective_max_vote_share_bps(&self, global_max_vote_share_bps: u16) -> Option<u16> {
+        let cap_bps = if self.max_vote_share_bps > 0 {
+            self.max_vote_share_bps
+        } else {
+            global_max_vote_share_bps
+        };
+
+        if cap_bps == 0 || cap_bps >= MAX_BPS {
+            None
+        } else {
+            Some(cap_bps)
+        }
+    }
+
+    /// Whether queued votes take effect at or before `current_epoch`
+    pub fn has_due_votes(&self, current_epoch: u64) -> bool {
+        self.pending_votes_delta != 0 && self.pending_epoch <= current_epoch
+    }
+
+    /// Apply the queued votes, after emitting up to the boundary they take effect at
+    /// `snapshot` must be the boundary at the start of `pending_epoch`
+    /// Returns the amount of RAY diverted
+    pub fn apply_due_votes(
+        &mut self,
+        snapshot: EpochSnapshot,
+        global_max_vote_share_bps: u16,
+    ) -> u64 {
+        assert_eq!(snapshot.epoch, self.pending_epoch, "wrong epoch boundary");
+
+        let ray_diverted = self.update_index(
+            snapshot.index.into(),
+            snapshot.total_emission.into(),
+            global_max_vote_share_bps,
+        );
+
+        let pending_votes_delta = self.pending_votes_delta;
+        self.pending_votes_delta = 0;
+        self.change_votes(pending_votes_delta);
+
+        ray_diverted
+    }
+
+    /// Change the votes now, or queue the change for the start of `apply_at_epoch` in epoch mode
+    /// Due votes must have been applied first
+    pub fn queue_votes(&mut self, votes: i64, apply_at_epoch: Option<u64>) {
+        match apply_at_epoch {
+            Some(epoch) => {
+                let votes_before = self.committed_votes();
+                self.pending_votes_delta = self.pending_votes_delta.checked_add(votes).unwrap();
+                self.pending_epoch = epoch;
+                self.vote_history
+                    .record(epoch, votes_before, self.committed_votes());
+            }
+            None => self.change_votes(votes),
+        }
+    }
+
+    /// Total votes including the queued change
+    pub fn committed_votes(&self) -> u64 {
+        (self.total_votes as i64)
+            .checked_add(self.pending_votes_delta)
+            .unwrap()
+            .try_into()
+            .unwrap()
+    }
+
+    /// Total votes in effect during `epoch`, `None` if no longer remembered
+    pub fn votes_at(&self, epoch: u64) -> Option<u64> {
+        self.vote_history.votes_at(epoch, self.total_votes)
+    }
+
+    pub fn is_killed(&self) -> bool {
+        self.status == GaugeStatus::Killed
+    }
+
+    /// Change the listing status
+    /// The gauge must be synced first, so that emission up to now follows the old status
+    /// Returns the change in the global votes, and in the global queued votes
+    pub fn set_status(&mut self, status: GaugeStatus) -> (i64, i64) {
+        let votes: i64 = self.total_votes.try_into().unwrap();
+        let pending = self.pending_votes_delta;
+
+        let delta = match (self.is_killed(), status == GaugeStatus::Killed) {
+            // killing takes the votes out of the global total
+            (false, true) => (-votes, -pending),
+            // reviving puts them back
+            (true, false) => (votes, pending),
+            _ => (0, 0),
+        };
+
+        self.status = status;
+
+        delta
     }
 
     pub fn change_votes(&mut self, votes: i64) {
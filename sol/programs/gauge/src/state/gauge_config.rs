@@ -1,16 +1,82 @@
-use crate::{pda::GLOBAL_CONFIG_SEED, state::NumberRaw};
+use crate::{
+    errors::GaugeErrors,
+    pda::GLOBAL_CONFIG_SEED,
+    state::{EmissionSchedule, Gauge, GaugeStatus, NumberRaw, MAX_BPS},
+};
 use anchor_lang::prelude::*;
 use precise_number::Number;
 
 const SECONDS_IN_DAY: u64 = 86_400;
 
+/// Pause flag for pledging votes to gauges
+/// Unpledging is never paused, so that votes can always be freed to withdraw RAY from the reactor
+pub const PAUSE_VOTE: u8 = 1 << 2;
+/// Pause flag for withdrawing earned RAY from personal rewarders
+pub const PAUSE_CLAIM: u8 = 1 << 3;
+/// All pause flags recognized by the gauge program
+pub const PAUSE_ALL: u8 = PAUSE_VOTE | PAUSE_CLAIM;
+
+/// Number of epoch boundaries remembered for gauges applying queued votes
+pub const EPOCH_HISTORY_LEN: usize = 8;
+
+/// Global index values at the start of an epoch
+#[derive(Default, Clone, Copy, AnchorDeserialize, AnchorSerialize)]
+pub struct EpochSnapshot {
+    /// Epoch that starts at this boundary
+    pub epoch: u64,
+
+    /// Global index at the boundary
+    pub index: NumberRaw,
+
+    /// Global lifetime emission at the boundary
+    pub total_emission: NumberRaw,
+}
+
+impl EpochSnapshot {
+    pub const SIZE: usize =
+        // epoch
+        8 +
+        // index
+        NumberRaw::SIZE +
+        // total_emission
+        NumberRaw::SIZE;
+}
+
+/// Number of global vote share cap changes remembered for gauges syncing across them
+pub const VOTE_SHARE_CAP_HISTORY_LEN: usize = 4;
+
+/// Global index values when the global vote share cap changed
+#[derive(Default, Clone, Copy, AnchorDeserialize, AnchorSerialize)]
+pub struct VoteShareCapChange {
+    /// Global index at the change
+    pub index: NumberRaw,
+
+    /// Global lifetime emission at the change
+    pub total_emission: NumberRaw,
+
+    /// Global cap in force until the change
+    pub old_max_vote_share_bps: u16,
+}
+
+impl VoteShareCapChange {
+    pub const SIZE: usize =
+        // index
+        NumberRaw::SIZE +
+        // total_emission
+        NumberRaw::SIZE +
+        // old_max_vote_share_bps
+        2;
+}
+
 /// Global config for all pool connected pool gauges
+#[derive(Default)]
 #[account]
 pub struct GaugeConfig {
     /// Global token account hopper for RAY tokens to emit
     pub ray_hopper: Pubkey,
 
     /// Total RAY emission per day
+    /// Applies until the first segment of the emission schedule starts
     pub ray_emission_per_day: u64,
 
     /// Total votes across all gauges
@@ -24,6 +90,53 @@ pub struct GaugeConfig {
     pub last_updated_ts: u64,
 
     pub bump: [u8; 1],
+
+    /// Authority for admin-gated instructions
+    pub admin: Pubkey,
+
+    /// Admin proposed by the current admin, pending acceptance
+    pub pending_admin: Pubkey,
+
+    /// Bitmask of paused actions
+    pub pause_flags: u8,
+
+    /// RAY funded into the hopper that has not been emitted through the index yet
+    pub remaining_budget: u64,
+
+    /// Schedule of emission rates over time
+    pub emission_schedule: Pubkey,
+
+    /// Maximum share of the emission any gauge may receive, in bps
+    /// 0 means gauges are uncapped unless they have their own cap
+    pub max_vote_share_bps: u16,
+
+    /// Lifetime RAY emitted through the index, before share caps
+    pub total_emission: NumberRaw,
+
+    /// Length of a voting epoch in seconds
+    /// 0 means epoch mode is off, and vote changes apply immediately
+    pub epoch_length: u64,
+
+    /// Current voting epoch
+    pub current_epoch: u64,
+
+    /// Timestamp at which the current epoch ends
+    /// The index does not move past it until the epoch is advanced
+    pub epoch_end_ts: u64,
+
+    /// Change to `total_votes` queued for the start of the next epoch
+    pub pending_votes_delta: i64,
+
+    /// Index snapshots at recent epoch boundaries, keyed by `epoch % EPOCH_HISTORY_LEN`
+    pub epoch_history: [EpochSnapshot; EPOCH_HISTORY_LEN],
+
+    /// Mint of the CLMM reward that tracks the time units of CL positions
+    /// The default key means it is not set yet
+    pub cl_time_tracker_mint: Pubkey,
+
+    /// Recent changes of `max_vote_share_bps`, oldest first
+    /// Gauges syncing across a change apply the old cap up to it
+    pub vote_share_cap_history: [VoteShareCapChange; VOTE_SHARE_CAP_HISTORY_LEN],
 }
 
 impl GaugeConfig {
@@ -41,15 +154,268 @@ impl GaugeConfig {
         // last_updated_ts
         8 +
         // bump
-        1;
+        1 +
+        // admin
+        32 +
+        // pending_admin
+        32 +
+        // pause_flags
+        1 +
+        // remaining_budget
+        8 +
+        // emission_schedule
+        32 +
+        // max_vote_share_bps
+        2 +
+        // total_emission
+        NumberRaw::SIZE +
+        // epoch_length
+        8 +
+        // current_epoch
+        8 +
+        // epoch_end_ts
+        8 +
+        // pending_votes_delta
+        8 +
+        // epoch_history
+        EpochSnapshot::SIZE * EPOCH_HISTORY_LEN +
+        // cl_time_tracker_mint
+        32 +
+        // vote_share_cap_history
+        VoteShareCapChange::SIZE * VOTE_SHARE_CAP_HISTORY_LEN;
 
     pub fn seeds(&self) -> [&[u8]; 2] {
         [GLOBAL_CONFIG_SEED.as_bytes(), &self.bump]
     }
 
+    /// Propose a new admin, who must accept before taking over
+    pub fn propose_admin(&mut self, new_admin: Pubkey) {
+        self.pending_admin = new_admin;
+    }
+
+    /// Promote the pending admin to admin
+    /// Returns the previous admin
+    pub fn accept_admin(&mut self) -> Pubkey {
+        let old = self.admin;
+        self.admin = self.pending_admin;
+        self.pending_admin = Pubkey::default();
+        old
+    }
+
+    /// Whether any of the given pause flags are set
+    pub fn is_paused(&self, flags: u8) -> bool {
+        self.pause_flags & flags != 0
+    }
+
+    /// Replace the pause flags, rejecting unknown bits
+    /// Returns the previous pause flags
+    pub fn set_pause_flags(&mut self, pause_flags: u8) -> Result<u8> {
+        require!(
+            pause_flags & !PAUSE_ALL == 0,
+            GaugeErrors::InvalidPauseFlags
+        );
+
+        let old = self.pause_flags;
+        self.pause_flags = pause_flags;
+        Ok(old)
+    }
+
+    /// Check that a vote change may be applied to a gauge with the given status
+    /// Only pledging can be paused or restricted, unpledging must stay open for users to exit
+    pub fn check_vote_change(&self, amount: i64, status: GaugeStatus) -> Result<()> {
+        if amount > 0 {
+            require!(!self.is_paused(PAUSE_VOTE), GaugeErrors::VotesPaused);
+            require!(
+                status == GaugeStatus::Whitelisted,
+                GaugeErrors::GaugeNotWhitelisted
+            );
+        }
+
+        Ok(())
+    }
+
+    pub fn is_epoch_mode(&self) -> bool {
+        self.epoch_length > 0
+    }
+
+    /// Epoch at which vote changes made now take effect
+    /// `None` if they take effect immediately
+    pub fn next_vote_epoch(&self) -> Option<u64> {
+        self.is_epoch_mode().then_some(self.current_epoch + 1)
+    }
+
+    /// Sync the index, then set the epoch length
+    /// Turning epoch mode on starts the first epoch now, otherwise the new length applies from the next epoch
+    /// Epoch mode cannot be turned off: gauges only apply their queued votes, and bribes
+    /// only become claimable and refundable, as epochs advance, so both would be stranded
+    pub fn set_epoch_length(
+        &mut self,
+        epoch_length: u64,
+        now: u64,
+        schedule: &EmissionSchedule,
+    ) -> Result<u64> {
+        if epoch_length == 0 {
+            return err!(GaugeErrors::InvalidEpochLength);
+        }
+
+        self.update_index(now, schedule);
+
+        if !self.is_epoch_mode() {
+            self.epoch_end_ts = now.checked_add(epoch_length).unwrap();
+        }
+
+        let old = self.epoch_length;
+        self.epoch_length = epoch_length;
+        Ok(old)
+    }
+
+    /// Sync the index, then set the global vote share cap
+    /// The change is remembered so that gauges synced later apply the old cap up to now
+    /// Returns the previous cap
+    pub fn set_max_vote_share_bps(
+        &mut self,
+        max_vote_share_bps: u16,
+        now: u64,
+        schedule: &EmissionSchedule,
+    ) -> Result<u16> {
+        require!(
+            max_vote_share_bps <= MAX_BPS,
+            GaugeErrors::InvalidVoteShareCap
+        );
+
+        self.update_index(now, schedule);
+
+        let old = self.max_vote_share_bps;
+        if max_vote_share_bps != old {
+            self.vote_share_cap_history.rotate_left(1);
+            self.vote_share_cap_history[VOTE_SHARE_CAP_HISTORY_LEN - 1] = VoteShareCapChange {
+                index: self.index,
+                total_emission: self.total_emission,
+                old_max_vote_share_bps: old,
+            };
+        }
+
+        self.max_vote_share_bps = max_vote_share_bps;
+        Ok(old)
+    }
+
+    /// Global cap in force up to the point where the lifetime emission reached `total_emission`
+    /// Before the oldest remembered change, its old cap is assumed
+    pub fn max_vote_share_bps_at(&self, total_emission: Number) -> u16 {
+        self.vote_share_cap_history
+            .iter()
+            .find(|c| Number::from(c.total_emission) >= total_emission)
+            .map_or(self.max_vote_share_bps, |c| c.old_max_vote_share_bps)
+    }
+
+    /// Bring the gauge up to each global cap change it has not synced across,
+    /// before the lifetime emission reaches `total_emission`, under the cap in force until the change
+    /// Returns the amount of RAY diverted
+    pub fn sync_vote_share_cap_changes(&self, gauge: &mut Gauge, total_emission: Number) -> u64 {
+        let mut ray_diverted = 0;
+
+        for change in &self.vote_share_cap_history {
+            let at: Number = change.total_emission.into();
+            if at > gauge.last_seen_total_emission.into() && at < total_emission {
+                ray_diverted +=
+                    gauge.update_index(change.index.into(), at, change.old_max_vote_share_bps);
+            }
+        }
+
+        ray_diverted
+    }
+
+    /// Change the global votes, or queue the change for the next epoch in epoch mode
+    pub fn queue_votes(&mut self, amount: i64) {
+        if self.is_epoch_mode() {
+            self.pending_votes_delta = self.pending_votes_delta.checked_add(amount).unwrap();
+        } else {
+            self.change_votes(amount);
+        }
+    }
+
+    /// Emit up to the end of the current epoch, snapshot the index at the boundary,
+    /// then apply the queued votes for the next epoch
+    /// Only advances a single epoch
+    pub fn advance_epoch(&mut self, now: u64, schedule: &EmissionSchedule) -> Result<()> {
+        if !self.is_epoch_mode() {
+            return err!(GaugeErrors::EpochModeOff);
+        }
+
+        if now < self.epoch_end_ts {
+            return err!(GaugeErrors::EpochNotEnded);
+        }
+
+        self.update_index(self.epoch_end_ts, schedule);
+
+        self.current_epoch += 1;
+        self.epoch_history[self.current_epoch as usize % EPOCH_HISTORY_LEN] = EpochSnapshot {
+            epoch: self.current_epoch,
+            index: self.index,
+            total_emission: self.total_emission,
+        };
+
+        let pending_votes_delta = self.pending_votes_delta;
+        self.pending_votes_delta = 0;
+        self.change_votes(pending_votes_delta);
+
+        self.epoch_end_ts = self.epoch_end_ts.checked_add(self.epoch_length).unwrap();
+
+        Ok(())
+    }
+
+    /// The snapshot at the start of `epoch`, if it is still in the history
+    pub fn epoch_snapshot(&self, epoch: u64) -> Option<EpochSnapshot> {
+        let snapshot = self.epoch_history[epoch as usize % EPOCH_HISTORY_LEN];
+        (snapshot.epoch == epoch && epoch <= self.current_epoch).then_some(snapshot)
+    }
+
+    /// Sync the index, then add funded RAY to the emission budget
+    /// Time that passed while the budget was empty is not back-paid
+    /// Returns the new remaining budget
+    pub fn fund_budget(&mut self, amount: u64, now: u64, schedule: &EmissionSchedule) -> u64 {
+        self.update_index(now, schedule);
+
+        self.remaining_budget = self
+            .remaining_budget
+            .checked_add(amount)
+            .expect("remaining budget overflow");
+
+        self.remaining_budget
+    }
+
+    /// Return RAY diverted from a capped gauge to the emission budget
+    pub fn return_diverted_ray(&mut self, amount: u64) {
+        self.remaining_budget = self
+            .remaining_budget
+            .checked_add(amount)
+            .expect("remaining budget overflow");
+    }
+
+    /// Projected timestamp at which the remaining budget runs out, following the emission schedule
+    /// Assumes there are votes on gauges, since no RAY is emitted without votes
+    /// Returns `u64::MAX` if the schedule never uses up the budget
+    pub fn projected_run_out_ts(&self, schedule: &EmissionSchedule) -> u64 {
+        let budget_ray_seconds = self.remaining_budget as u128 * SECONDS_IN_DAY as u128;
+
+        schedule.run_out_ts(
+            self.ray_emission_per_day,
+            self.last_updated_ts,
+            budget_ray_seconds,
+        )
+    }
+
     /// Update the index to the latest value
     /// Each unit in the index is a "per-vote" share of RAY
-    pub fn update_index(&mut self, now: u64) {
+    /// Emission is integrated across the segments of the schedule
+    /// In epoch mode, the index stops at the end of the current epoch until it is advanced
+    pub fn update_index(&mut self, now: u64, schedule: &EmissionSchedule) {
+        let now = if self.is_epoch_mode() {
+            now.min(self.epoch_end_ts)
+        } else {
+            now
+        };
+
         assert!(
             now >= self.last_updated_ts,
             "cannot update with older timestamp"
@@ -68,11 +434,20 @@ impl GaugeConfig {
         }
 
         // calculate the amount of RAY to emit for the duration
-        let time_elapsed: u64 = time_elapsed.try_into().unwrap();
-        // duration as a ratio of a day
-        let duration_day = Number::from_ratio(time_elapsed.into(), SECONDS_IN_DAY.into());
+        // summed as RAY-seconds across segments, so that a sync spanning several segments is exact
+        let ray_seconds =
+            schedule.ray_seconds_between(self.ray_emission_per_day, self.last_updated_ts, now);
         // RAY emission
-        let ray_emission = Number::from_natural_u64(self.ray_emission_per_day) * duration_day;
+        let ray_emission = Number::from_ratio(ray_seconds, SECONDS_IN_DAY.into());
+
+        // cannot emit more than the remaining budget
+        let ray_emission = ray_emission.min(Number::from_natural_u64(self.remaining_budget));
+
+        // round up, so the budget never understates what the index promises
+        self.remaining_budget -= ray_emission.ceil().floor_u64();
+
+        let total_emission: Number = self.total_emission.into();
+        self.total_emission = (total_emission + ray_emission).into();
 
         let total_shares = Number::from_natural_u64(self.total_votes);
 
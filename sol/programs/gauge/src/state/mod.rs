@@ -1,4 +1,7 @@
+pub mod bribe;
 pub mod common;
+pub mod emission_schedule;
+pub mod epoch_record;
 pub mod gauge;
 pub mod gauge_config;
 pub mod number;
@@ -6,9 +9,16 @@ pub mod personal_gauge;
 pub mod personal_rewarder_cl;
 pub mod personal_rewarder_cp;
 pub mod tests;
+pub mod vote_history;
+pub mod vote_ledger;
 
+pub use bribe::*;
+pub use emission_schedule::*;
+pub use epoch_record::*;
 pub use gauge::*;
 pub use gauge_config::*;
 pub use number::*;
 pub use personal_gauge::*;
 pub use personal_rewarder_cp::*;
+pub use vote_history::*;
+pub use vote_ledger::*;
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::state::VoteHistory;
+
 /// Account that tracks the number of votes on a given gauge for a specific user
 #[account]
 pub struct PersonalGauge {
@@ -11,6 +13,9 @@ pub struct PersonalGauge {
 
     /// Amount of votes pledged to a gauge
     pub votes: u64,
+
+    /// Votes in effect during recent epochs, for bribe claims
+    pub vote_history: VoteHistory,
 }
 
 impl PersonalGauge {
@@ -22,7 +27,24 @@ impl PersonalGauge {
         // pool_gauge
         32 +
         // amount
-        8;
+        8 +
+        // vote_history
+        VoteHistory::SIZE;
+
+    /// Change the votes, recording when the change takes effect in epoch mode
+    pub fn queue_votes(&mut self, amount: i64, apply_at_epoch: Option<u64>) {
+        let votes_before = self.votes;
+        self.change_votes(amount);
+
+        if let Some(epoch) = apply_at_epoch {
+            self.vote_history.record(epoch, votes_before, self.votes);
+        }
+    }
+
+    /// Votes in effect during `epoch`, `None` if no longer remembered
+    pub fn votes_at(&self, epoch: u64) -> Option<u64> {
+        self.vote_history.votes_at(epoch, self.votes)
+    }
 
     pub fn change_votes(&mut self, amount: i64) {
         let is_inc = amount > 0;
@@ -49,3 +71,29 @@ impl PersonalGauge {
             .expect("personal gauge underflow");
     }
 }
+
+/// Split decayed votes over gauges holding `votes`, pro-rata to their votes
+/// Rounding leftovers are taken one vote at a time in order
+/// `decayed` cannot exceed the total votes
+pub fn split_decay(votes: &[u64], decayed: u64) -> Vec<u64> {
+    let total: u64 = votes.iter().sum();
+    assert!(decayed <= total);
+
+    let mut shares: Vec<u64> = votes
+        .iter()
+        .map(|v| (decayed as u128 * *v as u128 / total as u128) as u64)
+        .collect();
+
+    let mut leftover = decayed - shares.iter().sum::<u64>();
+    for (share, v) in shares.iter_mut().zip(votes) {
+        if leftover == 0 {
+            break;
+        }
+        if *share < *v {
+            *share += 1;
+            leftover -= 1;
+        }
+    }
+
+    shares
+}
@@ -3,7 +3,24 @@ mod test {
     use anchor_lang::prelude::*;
     use precise_number::Number;
 
-    use crate::state::{common::PersonalRewarderState, Gauge, GaugeConfig, PersonalRewarderCp};
+    use crate::errors::GaugeErrors;
+    use crate::instructions::reallocate_votes::check_reallocation;
+    use crate::state::{
+        common::PersonalRewarderState, split_decay, Bribe, EmissionSchedule, EmissionSegment,
+        Gauge, GaugeConfig, GaugeStatus, PersonalGauge, PersonalRewarderCp, VoteHistory,
+        VoteLedger, VoteLedgerEntry, EPOCH_HISTORY_LEN, MAX_BPS, MAX_EMISSION_SEGMENTS,
+        MAX_VOTE_LEDGER_ENTRIES, PAUSE_ALL, PAUSE_CLAIM, PAUSE_VOTE, VOTE_HISTORY_LEN,
+    };
+    use reactor::state::{VoteDelegation, MAX_DELEGATION_GAUGES};
+
+    /// Whitelisted gauge without votes, synced to the start of the global index
+    fn new_gauge() -> Gauge {
+        Gauge {
+            pool_id: Pubkey::new_unique(),
+            status: GaugeStatus::Whitelisted,
+            ..Default::default()
+        }
+    }
 
     #[test]
     fn test_multiple_gauges() {
@@ -14,21 +31,13 @@ mod test {
             index: Number::ZERO.into(),
             last_updated_ts: 0,
             bump: [0; 1],
+            remaining_budget: 1_000,
+            ..Default::default()
         };
 
-        let mut pg_a = Gauge {
-            pool_id: Pubkey::new_unique(),
-            total_votes: 0,
-            last_seen_global_index: Number::ZERO.into(),
-            total_ray_emitted: 0,
-        };
+        let mut pg_a = new_gauge();
 
-        let mut pg_b = Gauge {
-            pool_id: Pubkey::new_unique(),
-            total_votes: 0,
-            last_seen_global_index: Number::ZERO.into(),
-            total_ray_emitted: 0,
-        };
+        let mut pg_b = new_gauge();
 
         // Add 100 votes to A
         gc.change_votes(100);
@@ -40,9 +49,9 @@ mod test {
 
         // Sync the gauge with 12 hours (1/2 day) of time
         let now = 12 * 60 * 60;
-        gc.update_index(now);
-        pg_a.update_index(gc.index.into());
-        pg_b.update_index(gc.index.into());
+        gc.update_index(now, &EmissionSchedule::default());
+        pg_a.update_index(gc.index.into(), gc.total_emission.into(), 0);
+        pg_b.update_index(gc.index.into(), gc.total_emission.into(), 0);
 
         assert_eq!(gc.total_votes, 300);
         // the index should share out 180 ray (0.5 * 360) to be shared among 300 votes
@@ -63,14 +72,11 @@ mod test {
             index: Number::ZERO.into(),
             last_updated_ts: 0,
             bump: [0; 1],
+            remaining_budget: 1_000,
+            ..Default::default()
         };
 
-        let mut pg = Gauge {
-            pool_id: Pubkey::new_unique(),
-            total_votes: 0,
-            last_seen_global_index: Number::ZERO.into(),
-            total_ray_emitted: 0,
-        };
+        let mut pg = new_gauge();
 
         let mut bilbo = PersonalRewarderCp {
             owner: Pubkey::new_unique(),
@@ -83,8 +89,8 @@ mod test {
 
         // Sync the gauge with 6 hours (1/4 day) of time
         let now = 6 * 60 * 60;
-        gc.update_index(now);
-        pg.update_index(gc.index.into());
+        gc.update_index(now, &EmissionSchedule::default());
+        pg.update_index(gc.index.into(), gc.total_emission.into(), 0);
 
         assert_eq!(gc.total_votes, 100);
         assert_eq!(pg.total_votes, 100);
@@ -115,4 +121,801 @@ mod test {
         );
         assert_eq!(bilbo.rewarder.last_updated_ts, now);
     }
+
+    #[test]
+    fn test_budget_caps_emission() {
+        let mut gc = GaugeConfig {
+            ray_emission_per_day: 360,
+            remaining_budget: 100,
+            ..Default::default()
+        };
+
+        let mut pg = new_gauge();
+
+        gc.change_votes(100);
+        pg.change_votes(100);
+
+        // 1/4 day emits 90 of the 100 budget
+        gc.update_index(6 * 60 * 60, &EmissionSchedule::default());
+        pg.update_index(gc.index.into(), gc.total_emission.into(), 0);
+        assert_eq!(pg.total_ray_emitted, 90);
+        assert_eq!(gc.remaining_budget, 10);
+
+        // another 1/4 day only has 10 left to emit
+        gc.update_index(12 * 60 * 60, &EmissionSchedule::default());
+        pg.update_index(gc.index.into(), gc.total_emission.into(), 0);
+        assert_eq!(pg.total_ray_emitted, 100);
+        assert_eq!(gc.remaining_budget, 0);
+
+        // the index stops growing once the budget is used up
+        let index_before: Number = gc.index.into();
+        gc.update_index(24 * 60 * 60, &EmissionSchedule::default());
+        assert_eq!(index_before, gc.index.into());
+        assert_eq!(gc.last_updated_ts, 24 * 60 * 60);
+
+        // funding does not back-pay the time without budget
+        gc.fund_budget(360, 36 * 60 * 60, &EmissionSchedule::default());
+        assert_eq!(index_before, gc.index.into());
+        assert_eq!(gc.remaining_budget, 360);
+    }
+
+    #[test]
+    fn test_projected_run_out_ts() {
+        let mut gc = GaugeConfig {
+            ray_emission_per_day: 360,
+            remaining_budget: 90,
+            last_updated_ts: 1_000,
+            ..Default::default()
+        };
+
+        // 90 RAY lasts 1/4 of a day
+        assert_eq!(
+            gc.projected_run_out_ts(&EmissionSchedule::default()),
+            1_000 + 6 * 60 * 60
+        );
+
+        // nothing emitted, the budget never runs out
+        gc.ray_emission_per_day = 0;
+        assert_eq!(
+            gc.projected_run_out_ts(&EmissionSchedule::default()),
+            u64::MAX
+        );
+    }
+
+    #[test]
+    fn test_emission_schedule_across_segments() {
+        const DAY: u64 = 24 * 60 * 60;
+
+        // 360/day for the first day, then 720/day, then stops after day 3
+        let schedule = EmissionSchedule::from_segments(&[
+            EmissionSegment {
+                start_ts: DAY,
+                ray_per_day: 720,
+            },
+            EmissionSegment {
+                start_ts: 3 * DAY,
+                ray_per_day: 0,
+            },
+        ])
+        .unwrap();
+
+        let new_gc = || GaugeConfig {
+            ray_emission_per_day: 360,
+            remaining_budget: 10_000,
+            ..Default::default()
+        };
+
+        // sync once across every boundary
+        let mut gc_once = new_gc();
+        let mut pg_once = new_gauge();
+        gc_once.change_votes(100);
+        pg_once.change_votes(100);
+
+        gc_once.update_index(4 * DAY, &schedule);
+        pg_once.update_index(gc_once.index.into(), gc_once.total_emission.into(), 0);
+        assert_eq!(pg_once.total_ray_emitted, 360 + 2 * 720);
+        assert_eq!(gc_once.remaining_budget, 10_000 - 360 - 2 * 720);
+
+        // sync at every boundary
+        let mut gc_steps = new_gc();
+        let mut pg_steps = new_gauge();
+        gc_steps.change_votes(100);
+        pg_steps.change_votes(100);
+
+        for now in [DAY / 2, DAY, 2 * DAY, 3 * DAY, 4 * DAY] {
+            gc_steps.update_index(now, &schedule);
+            pg_steps.update_index(gc_steps.index.into(), gc_steps.total_emission.into(), 0);
+        }
+        assert_eq!(pg_steps.total_ray_emitted, pg_once.total_ray_emitted);
+        assert_eq!(gc_steps.remaining_budget, gc_once.remaining_budget);
+    }
+
+    #[test]
+    fn test_emission_schedule_run_out_ts() {
+        const DAY: u64 = 24 * 60 * 60;
+
+        let schedule = EmissionSchedule::from_segments(&[
+            EmissionSegment {
+                start_ts: DAY,
+                ray_per_day: 720,
+            },
+            EmissionSegment {
+                start_ts: 2 * DAY,
+                ray_per_day: 0,
+            },
+        ])
+        .unwrap();
+
+        let mut gc = GaugeConfig {
+            ray_emission_per_day: 360,
+            remaining_budget: 360 + 360,
+            ..Default::default()
+        };
+
+        // the first day uses 360, then the 720/day rate uses the rest in half a day
+        assert_eq!(gc.projected_run_out_ts(&schedule), DAY + DAY / 2);
+
+        // the schedule stops emitting before the budget is used up
+        gc.remaining_budget = 360 + 720 + 1;
+        assert_eq!(gc.projected_run_out_ts(&schedule), u64::MAX);
+    }
+
+    #[test]
+    fn test_emission_schedule_validation() {
+        let segment = |start_ts| EmissionSegment {
+            start_ts,
+            ray_per_day: 1,
+        };
+
+        assert!(EmissionSchedule::from_segments(&[]).is_some());
+        assert!(EmissionSchedule::from_segments(&[segment(1), segment(2)]).is_some());
+
+        // unsorted or duplicated start times
+        assert!(EmissionSchedule::from_segments(&[segment(2), segment(1)]).is_none());
+        assert!(EmissionSchedule::from_segments(&[segment(1), segment(1)]).is_none());
+
+        // too many segments
+        let segments: Vec<_> = (0..=MAX_EMISSION_SEGMENTS as u64).map(segment).collect();
+        assert!(EmissionSchedule::from_segments(&segments).is_none());
+    }
+
+    #[test]
+    fn test_vote_share_cap_diverts_to_budget() {
+        let mut gc = GaugeConfig {
+            ray_emission_per_day: 360,
+            remaining_budget: 1_000,
+            max_vote_share_bps: 5_000,
+            ..Default::default()
+        };
+
+        let mut pg_a = new_gauge();
+        let mut pg_b = new_gauge();
+
+        gc.change_votes(100);
+        pg_a.change_votes(80);
+        pg_b.change_votes(20);
+
+        gc.update_index(24 * 60 * 60, &EmissionSchedule::default());
+        assert_eq!(gc.remaining_budget, 1_000 - 360);
+
+        // A would get 288, but is capped at half of the 360 emitted
+        let diverted_a = pg_a.update_index(
+            gc.index.into(),
+            gc.total_emission.into(),
+            gc.max_vote_share_bps,
+        );
+        gc.return_diverted_ray(diverted_a);
+        assert_eq!(pg_a.total_ray_emitted, 180);
+        assert_eq!(diverted_a, 288 - 180);
+
+        // B is under the cap
+        let diverted_b = pg_b.update_index(
+            gc.index.into(),
+            gc.total_emission.into(),
+            gc.max_vote_share_bps,
+        );
+        gc.return_diverted_ray(diverted_b);
+        assert_eq!(pg_b.total_ray_emitted, 72);
+        assert_eq!(diverted_b, 0);
+
+        // nothing is created nor lost
+        assert_eq!(
+            pg_a.total_ray_emitted + pg_b.total_ray_emitted + gc.remaining_budget,
+            1_000
+        );
+    }
+
+    #[test]
+    fn test_vote_share_cap_is_conservation_exact() {
+        let mut gc = GaugeConfig {
+            ray_emission_per_day: 1_000_003,
+            remaining_budget: u64::MAX / 2,
+            ..Default::default()
+        };
+
+        let new_pg = |max_vote_share_bps| Gauge {
+            max_vote_share_bps,
+            ..new_gauge()
+        };
+
+        // same votes, one capped and one uncapped
+        let mut capped = new_pg(3_333);
+        let mut uncapped = new_pg(0);
+        let mut other = new_pg(0);
+
+        gc.change_votes(7 + 7 + 3);
+        capped.change_votes(7);
+        uncapped.change_votes(7);
+        other.change_votes(3);
+
+        let mut total_diverted = 0;
+        for now in [1, 977, 12_345, 86_399, 86_400, 200_001] {
+            gc.update_index(now, &EmissionSchedule::default());
+
+            total_diverted += capped.update_index(gc.index.into(), gc.total_emission.into(), 0);
+            assert_eq!(
+                uncapped.update_index(gc.index.into(), gc.total_emission.into(), 0),
+                0
+            );
+
+            // emitted + diverted equals the uncapped emission exactly
+            assert_eq!(
+                capped.total_ray_emitted + total_diverted,
+                uncapped.total_ray_emitted
+            );
+        }
+
+        assert!(total_diverted > 0);
+    }
+
+    #[test]
+    fn test_vote_share_cap_change_is_not_retroactive() {
+        const DAY: u64 = 24 * 60 * 60;
+        let schedule = EmissionSchedule::default();
+
+        let mut gc = GaugeConfig {
+            ray_emission_per_day: 360,
+            remaining_budget: 10_000,
+            max_vote_share_bps: 5_000,
+            ..Default::default()
+        };
+
+        let mut pg_a = new_gauge();
+        let mut pg_b = new_gauge();
+
+        gc.change_votes(100);
+        pg_a.change_votes(80);
+        pg_b.change_votes(20);
+
+        // A is not synced when the cap drops after a day
+        assert_eq!(
+            gc.set_max_vote_share_bps(2_000, DAY, &schedule).unwrap(),
+            5_000
+        );
+        assert_eq!(gc.max_vote_share_bps_at(gc.total_emission.into()), 5_000);
+
+        gc.update_index(2 * DAY, &schedule);
+        assert_eq!(gc.max_vote_share_bps_at(gc.total_emission.into()), 2_000);
+
+        // A gets half of the first day and a fifth of the second, not a fifth of both
+        crate::syncer::apply_index(&mut gc, &mut pg_a).unwrap();
+        assert_eq!(pg_a.total_ray_emitted, 180 + 72);
+
+        // B stays under both caps
+        crate::syncer::apply_index(&mut gc, &mut pg_b).unwrap();
+        assert_eq!(pg_b.total_ray_emitted, 72 + 72);
+
+        // nothing is created nor lost
+        assert_eq!(
+            pg_a.total_ray_emitted + pg_b.total_ray_emitted + gc.remaining_budget,
+            10_000
+        );
+
+        // setting the same cap is not a change
+        gc.set_max_vote_share_bps(2_000, 2 * DAY, &schedule)
+            .unwrap();
+        assert_eq!(gc.max_vote_share_bps_at(gc.total_emission.into()), 2_000);
+
+        assert_eq!(
+            gc.set_max_vote_share_bps(MAX_BPS + 1, 2 * DAY, &schedule)
+                .unwrap_err(),
+            GaugeErrors::InvalidVoteShareCap.into()
+        );
+    }
+
+    #[test]
+    fn test_effective_vote_share_cap() {
+        let mut pg = new_gauge();
+
+        assert_eq!(pg.effective_max_vote_share_bps(0), None);
+        assert_eq!(pg.effective_max_vote_share_bps(2_000), Some(2_000));
+
+        // the gauge cap overrides the global cap
+        pg.max_vote_share_bps = 3_000;
+        assert_eq!(pg.effective_max_vote_share_bps(2_000), Some(3_000));
+
+        // a full share is no cap
+        pg.max_vote_share_bps = MAX_BPS;
+        assert_eq!(pg.effective_max_vote_share_bps(2_000), None);
+    }
+
+    #[test]
+    fn test_killed_gauge_leaves_denominator() {
+        let mut gc = GaugeConfig {
+            ray_emission_per_day: 360,
+            remaining_budget: 1_000,
+            ..Default::default()
+        };
+
+        let mut pg_a = new_gauge();
+        let mut pg_b = new_gauge();
+
+        gc.change_votes(100);
+        pg_a.change_votes(50);
+        pg_b.change_votes(50);
+
+        let sync = |gc: &mut GaugeConfig, pg: &mut Gauge, now| {
+            gc.update_index(now, &EmissionSchedule::default());
+            let diverted = pg.update_index(gc.index.into(), gc.total_emission.into(), 0);
+            gc.return_diverted_ray(diverted);
+        };
+
+        // kill A after a quarter day, syncing it first
+        sync(&mut gc, &mut pg_a, 6 * 60 * 60);
+        let (delta, _) = pg_a.set_status(GaugeStatus::Killed);
+        gc.change_votes(delta);
+        assert_eq!(delta, -50);
+        assert_eq!(gc.total_votes, 50);
+        assert_eq!(pg_a.total_ray_emitted, 45);
+
+        // B gets the whole emission while A is killed
+        sync(&mut gc, &mut pg_a, 12 * 60 * 60);
+        sync(&mut gc, &mut pg_b, 12 * 60 * 60);
+        assert_eq!(pg_a.total_ray_emitted, 45);
+        assert_eq!(pg_b.total_ray_emitted, 45 + 90);
+
+        // killed votes can be pulled out without touching the global total
+        pg_a.change_votes(-20);
+        assert_eq!(pg_a.total_votes, 30);
+
+        // reviving puts the remaining votes back
+        let (delta, _) = pg_a.set_status(GaugeStatus::Whitelisted);
+        gc.change_votes(delta);
+        assert_eq!(delta, 30);
+        assert_eq!(gc.total_votes, 80);
+
+        // revived gauge earns again, but not for the killed period
+        sync(&mut gc, &mut pg_a, 24 * 60 * 60);
+        sync(&mut gc, &mut pg_b, 24 * 60 * 60);
+        assert_eq!(pg_a.total_ray_emitted, 45 + 67);
+        assert_eq!(pg_b.total_ray_emitted, 45 + 90 + 112);
+    }
+
+    #[test]
+    fn test_epoch_mode_queues_votes() {
+        const DAY: u64 = 24 * 60 * 60;
+        let schedule = EmissionSchedule::default();
+
+        let mut gc = GaugeConfig {
+            ray_emission_per_day: 360,
+            remaining_budget: 10_000,
+            ..Default::default()
+        };
+        gc.set_epoch_length(DAY, 0, &schedule).unwrap();
+        assert_eq!(gc.epoch_end_ts, DAY);
+
+        let mut pg_a = new_gauge();
+        let mut pg_b = new_gauge();
+
+        let sync = |gc: &mut GaugeConfig, pg: &mut Gauge, now| {
+            crate::syncer::sync_gauge(now, gc, &EmissionSchedule::default(), pg).unwrap();
+        };
+
+        // votes in epoch 0 are queued for epoch 1
+        gc.queue_votes(50);
+        pg_a.queue_votes(50, gc.next_vote_epoch());
+        gc.queue_votes(50);
+        pg_b.queue_votes(50, gc.next_vote_epoch());
+        assert_eq!(gc.total_votes, 0);
+        assert_eq!(pg_a.total_votes, 0);
+
+        // the index does not move past the end of the epoch until it is advanced
+        gc.update_index(DAY + 100, &schedule);
+        assert_eq!(gc.last_updated_ts, DAY);
+        assert_eq!(
+            gc.advance_epoch(DAY - 1, &schedule).unwrap_err(),
+            GaugeErrors::EpochNotEnded.into()
+        );
+
+        gc.advance_epoch(DAY, &schedule).unwrap();
+        assert_eq!(gc.current_epoch, 1);
+        assert_eq!(gc.total_votes, 100);
+
+        // B flash-moves its votes to A halfway through epoch 1, which only counts from epoch 2
+        sync(&mut gc, &mut pg_a, DAY + DAY / 2);
+        sync(&mut gc, &mut pg_b, DAY + DAY / 2);
+        assert_eq!(pg_a.total_votes, 50);
+        gc.queue_votes(-50);
+        pg_b.queue_votes(-50, gc.next_vote_epoch());
+        gc.queue_votes(50);
+        pg_a.queue_votes(50, gc.next_vote_epoch());
+
+        gc.advance_epoch(2 * DAY, &schedule).unwrap();
+        gc.advance_epoch(3 * DAY, &schedule).unwrap();
+        gc.update_index(3 * DAY, &schedule);
+
+        // A is synced late, across both boundaries, and still splits epoch 1 evenly
+        sync(&mut gc, &mut pg_a, 3 * DAY);
+        sync(&mut gc, &mut pg_b, 3 * DAY);
+        assert_eq!(pg_a.total_votes, 100);
+        assert_eq!(pg_b.total_votes, 0);
+        assert_eq!(pg_a.total_ray_emitted, 180 + 360);
+        assert_eq!(pg_b.total_ray_emitted, 180);
+    }
+
+    #[test]
+    fn test_epoch_snapshot_history() {
+        let schedule = EmissionSchedule::default();
+
+        let mut gc = GaugeConfig {
+            ray_emission_per_day: 360,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            gc.advance_epoch(0, &schedule).unwrap_err(),
+            GaugeErrors::EpochModeOff.into()
+        );
+        assert_eq!(
+            gc.set_epoch_length(0, 0, &schedule).unwrap_err(),
+            GaugeErrors::InvalidEpochLength.into()
+        );
+
+        gc.set_epoch_length(10, 0, &schedule).unwrap();
+        for epoch in 1..=EPOCH_HISTORY_LEN as u64 + 1 {
+            gc.advance_epoch(epoch * 10, &schedule).unwrap();
+        }
+
+        // the oldest boundary has been overwritten
+        assert!(gc.epoch_snapshot(1).is_none());
+        assert!(gc.epoch_snapshot(2).is_some());
+        assert!(gc.epoch_snapshot(gc.current_epoch).is_some());
+        assert!(gc.epoch_snapshot(gc.current_epoch + 1).is_none());
+    }
+
+    #[test]
+    fn test_epoch_record_applies_evicted_boundary() {
+        const DAY: u64 = 24 * 60 * 60;
+        let schedule = EmissionSchedule::default();
+
+        let mut gc = GaugeConfig {
+            ray_emission_per_day: 360,
+            remaining_budget: 100_000,
+            ..Default::default()
+        };
+        gc.set_epoch_length(DAY, 0, &schedule).unwrap();
+
+        let mut pg_a = new_gauge();
+        let mut pg_b = new_gauge();
+
+        // records of each boundary, written by advance_epoch
+        let mut records = vec![];
+        let mut advance = |gc: &mut GaugeConfig, now| {
+            gc.advance_epoch(now, &schedule).unwrap();
+            records.push(gc.epoch_snapshot(gc.current_epoch).unwrap());
+        };
+
+        // both vote during epoch 0, then A doubles its votes during epoch 1
+        gc.queue_votes(100);
+        pg_a.queue_votes(50, gc.next_vote_epoch());
+        pg_b.queue_votes(50, gc.next_vote_epoch());
+        advance(&mut gc, DAY);
+        crate::syncer::sync_gauge(DAY, &mut gc, &schedule, &mut pg_a).unwrap();
+
+        gc.queue_votes(50);
+        pg_a.queue_votes(50, gc.next_vote_epoch());
+
+        // neither is synced again until their boundaries left the history
+        for epoch in 2..=EPOCH_HISTORY_LEN as u64 + 2 {
+            advance(&mut gc, epoch * DAY);
+        }
+        assert!(gc.epoch_snapshot(pg_a.pending_epoch).is_none());
+        assert!(gc.epoch_snapshot(pg_b.pending_epoch).is_none());
+
+        // syncing without the records fails, leaving the gauges untouched
+        for pg in [&mut pg_a, &mut pg_b] {
+            let total_ray_emitted = pg.total_ray_emitted;
+            assert_eq!(
+                crate::syncer::apply_index(&mut gc, pg).unwrap_err(),
+                GaugeErrors::EpochRecordRequired.into()
+            );
+            assert_eq!(pg.total_ray_emitted, total_ray_emitted);
+        }
+
+        // the records of the boundaries give the exact emission, with nothing stranded
+        for pg in [&mut pg_a, &mut pg_b] {
+            let record = records[pg.pending_epoch as usize - 1];
+            let diverted = pg.apply_due_votes(record, gc.max_vote_share_bps);
+            gc.return_diverted_ray(diverted);
+            crate::syncer::apply_index(&mut gc, pg).unwrap();
+        }
+
+        // epoch 1 split evenly, then 100 to 50 for the remaining epochs
+        let later_epochs = EPOCH_HISTORY_LEN as u64;
+        assert_eq!(pg_a.total_votes, 100);
+        assert_eq!(pg_a.total_ray_emitted, 180 + 240 * later_epochs);
+        assert_eq!(pg_b.total_ray_emitted, 180 + 120 * later_epochs);
+    }
+
+    #[test]
+    fn test_vote_history_lookup() {
+        let mut h = VoteHistory::default();
+        assert_eq!(h.votes_at(5, 42), Some(42));
+
+        // voted 100 during epoch 1, changed twice during epoch 3
+        h.record(2, 0, 100);
+        h.record(4, 100, 150);
+        h.record(4, 150, 120);
+
+        assert_eq!(h.votes_at(0, 120), Some(0));
+        assert_eq!(h.votes_at(1, 120), Some(0));
+        assert_eq!(h.votes_at(2, 120), Some(100));
+        assert_eq!(h.votes_at(3, 120), Some(100));
+        assert_eq!(h.votes_at(4, 120), Some(120));
+        assert_eq!(h.votes_at(9, 120), Some(120));
+
+        // filling the history forgets the oldest epochs
+        for epoch in 5..5 + VOTE_HISTORY_LEN as u64 {
+            h.record(epoch, 0, epoch);
+        }
+        assert_eq!(h.votes_at(4, 0), None);
+        assert_eq!(h.votes_at(5, 0), Some(5));
+    }
+
+    #[test]
+    fn test_bribe_claims_pro_rata() {
+        let mut gc = GaugeConfig {
+            ray_emission_per_day: 360,
+            remaining_budget: 1_000_000,
+            ..Default::default()
+        };
+        let schedule = EmissionSchedule::default();
+        gc.set_epoch_length(100, 0, &schedule).unwrap();
+
+        let mut pg = new_gauge();
+        let mut alice = PersonalGauge {
+            owner: Pubkey::new_unique(),
+            pool_gauge: Pubkey::new_unique(),
+            votes: 0,
+            vote_history: VoteHistory::default(),
+        };
+        let mut bob = PersonalGauge {
+            owner: Pubkey::new_unique(),
+            pool_gauge: Pubkey::new_unique(),
+            votes: 0,
+            vote_history: VoteHistory::default(),
+        };
+
+        // votes cast during epoch 0 count for epoch 1
+        pg.queue_votes(100, gc.next_vote_epoch());
+        alice.queue_votes(100, gc.next_vote_epoch());
+        pg.queue_votes(200, gc.next_vote_epoch());
+        bob.queue_votes(200, gc.next_vote_epoch());
+
+        let mut bribe = Bribe {
+            pool_gauge: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            epoch: 1,
+            vault: Pubkey::new_unique(),
+            refund_authority: Pubkey::new_unique(),
+            total_amount: 0,
+            claimed_amount: 0,
+            settled_amount: 0,
+            bump: [0; 1],
+        };
+        bribe.deposit(1_000);
+        assert!(bribe.is_open(gc.current_epoch));
+        assert!(!bribe.is_claimable(gc.current_epoch));
+
+        gc.advance_epoch(100, &schedule).unwrap();
+        gc.advance_epoch(200, &schedule).unwrap();
+
+        // changes during the next epoch do not affect the bribed epoch
+        pg.queue_votes(-100, gc.next_vote_epoch());
+        alice.queue_votes(-100, gc.next_vote_epoch());
+
+        assert!(!bribe.is_open(gc.current_epoch));
+        assert!(bribe.is_claimable(gc.current_epoch));
+
+        let gauge_votes = pg.votes_at(bribe.epoch).unwrap();
+        assert_eq!(gauge_votes, 300);
+        assert_eq!(bribe.claim(alice.votes_at(1).unwrap(), gauge_votes), 333);
+        assert_eq!(bribe.claim(bob.votes_at(1).unwrap(), gauge_votes), 666);
+
+        // the dust is left for the refund once the claim window ends
+        for ts in [300, 400] {
+            gc.advance_epoch(ts, &schedule).unwrap();
+        }
+        assert!(!bribe.is_claimable(gc.current_epoch));
+        assert!(bribe.is_expired(gc.current_epoch));
+        assert_eq!(bribe.settle(), 1);
+        assert_eq!(bribe.unsettled_amount(), 0);
+    }
+
+    #[test]
+    fn test_vote_ledger_tracks_gauges() {
+        let mut ledger = VoteLedger {
+            reactor: Pubkey::new_unique(),
+            num_entries: 0,
+            entries: [VoteLedgerEntry::default(); MAX_VOTE_LEDGER_ENTRIES],
+        };
+        let gauge_a = Pubkey::new_unique();
+        let gauge_b = Pubkey::new_unique();
+
+        ledger.change_votes(gauge_a, 100).unwrap();
+        ledger.change_votes(gauge_b, 50).unwrap();
+        ledger.change_votes(gauge_a, -30).unwrap();
+        assert_eq!(ledger.votes_on(gauge_a), 70);
+        assert_eq!(ledger.votes_on(gauge_b), 50);
+        assert_eq!(ledger.total_votes(), 120);
+
+        // gauges without votes leave the listing
+        ledger.change_votes(gauge_a, -70).unwrap();
+        assert_eq!(ledger.entries().len(), 1);
+        assert_eq!(ledger.entries()[0].pool_gauge, gauge_b);
+
+        for _ in 1..MAX_VOTE_LEDGER_ENTRIES {
+            ledger.change_votes(Pubkey::new_unique(), 1).unwrap();
+        }
+        assert_eq!(
+            ledger.change_votes(gauge_a, 1).unwrap_err(),
+            GaugeErrors::VoteLedgerFull.into()
+        );
+
+        // zero changes do not need a slot
+        ledger.change_votes(gauge_a, 0).unwrap();
+        assert_eq!(ledger.votes_on(gauge_a), 0);
+
+        // a listed gauge can still change
+        ledger.change_votes(gauge_b, 1).unwrap();
+        assert_eq!(ledger.votes_on(gauge_b), 51);
+    }
+
+    #[test]
+    fn test_split_decay_pro_rata() {
+        let votes = [50, 30, 20];
+
+        // exact shares
+        assert_eq!(split_decay(&votes, 10), vec![5, 3, 2]);
+
+        // rounding leftovers go to the first gauges with votes left
+        assert_eq!(split_decay(&votes, 7), vec![4, 2, 1]);
+        assert_eq!(split_decay(&votes, 1), vec![1, 0, 0]);
+        assert_eq!(split_decay(&[0, 1, 1], 1), vec![0, 1, 0]);
+
+        // no gauge can be emptied ahead of the others
+        assert_eq!(split_decay(&votes, 100), vec![50, 30, 20]);
+        assert_eq!(split_decay(&votes, 0), vec![0, 0, 0]);
+    }
+
+    fn new_delegation(delegate: Pubkey, allowed_gauges: &[Pubkey]) -> VoteDelegation {
+        let mut d = VoteDelegation {
+            reactor: Pubkey::new_unique(),
+            delegate,
+            expires_at: 0,
+            num_allowed_gauges: 0,
+            allowed_gauges: [Pubkey::default(); MAX_DELEGATION_GAUGES],
+        };
+        d.set(d.reactor, delegate, 100, allowed_gauges).unwrap();
+        d
+    }
+
+    #[test]
+    fn test_reallocation_checks_authority() {
+        let gc = GaugeConfig::default();
+        let owner = Pubkey::new_unique();
+        let delegate = Pubkey::new_unique();
+        let third_party = Pubkey::new_unique();
+        let gauge_a = Pubkey::new_unique();
+        let gauge_b = Pubkey::new_unique();
+        let whitelisted = GaugeStatus::Whitelisted;
+
+        // moving votes between gauges nets to zero, so the reactor program never sees it
+        let moves = [(gauge_a, whitelisted, -100), (gauge_b, whitelisted, 100)];
+        assert_eq!(
+            check_reallocation(&gc, owner, third_party, None, &moves, 0).unwrap_err(),
+            GaugeErrors::NotVoteAuthority.into()
+        );
+        assert_eq!(
+            check_reallocation(&gc, owner, owner, None, &moves, 0).unwrap(),
+            0
+        );
+
+        // a delegation only authorizes its delegate, until it expires
+        let d = new_delegation(delegate, &[]);
+        assert_eq!(
+            check_reallocation(&gc, owner, third_party, Some(&d), &moves, 0).unwrap_err(),
+            GaugeErrors::NotVoteAuthority.into()
+        );
+        assert_eq!(
+            check_reallocation(&gc, owner, delegate, Some(&d), &moves, 0).unwrap(),
+            0
+        );
+        assert_eq!(
+            check_reallocation(&gc, owner, delegate, Some(&d), &moves, 101).unwrap_err(),
+            GaugeErrors::NotVoteAuthority.into()
+        );
+
+        // a restricted delegate cannot move votes out of its gauges either
+        let d = new_delegation(delegate, &[gauge_b]);
+        assert_eq!(
+            check_reallocation(&gc, owner, delegate, Some(&d), &moves, 0).unwrap_err(),
+            GaugeErrors::GaugeNotDelegated.into()
+        );
+    }
+
+    #[test]
+    fn test_escrow_mirrors_gauge_seeds() {
+        use crate::pda::{PERSONAL_REWARDER_CP_SEED, POOL_GAUGE_SEED};
+        use cp_lp_escrow::gauge_program;
+
+        assert_eq!(gauge_program::ID, crate::ID);
+        assert_eq!(gauge_program::POOL_GAUGE_SEED, POOL_GAUGE_SEED.as_bytes());
+        assert_eq!(
+            gauge_program::PERSONAL_REWARDER_CP_SEED,
+            PERSONAL_REWARDER_CP_SEED.as_bytes()
+        );
+    }
+
+    #[test]
+    fn test_set_pause_flags() {
+        let mut gc = GaugeConfig::default();
+
+        assert_eq!(gc.set_pause_flags(PAUSE_ALL).unwrap(), 0);
+        assert_eq!(gc.set_pause_flags(0).unwrap(), PAUSE_ALL);
+
+        // reactor deposits and withdrawals are paused from the reactor program
+        assert_eq!(
+            gc.set_pause_flags(1 << 0).unwrap_err(),
+            GaugeErrors::InvalidPauseFlags.into()
+        );
+        assert_eq!(gc.pause_flags, 0);
+    }
+
+    #[test]
+    fn test_pause_vote() {
+        let mut gc = GaugeConfig::default();
+        gc.set_pause_flags(PAUSE_VOTE).unwrap();
+
+        // change_votes and reallocate_votes reject pledges
+        assert_eq!(
+            gc.check_vote_change(1, GaugeStatus::Whitelisted)
+                .unwrap_err(),
+            GaugeErrors::VotesPaused.into()
+        );
+
+        // unpledging stays open, so that votes can be freed to withdraw RAY from the reactor
+        gc.check_vote_change(-1, GaugeStatus::Whitelisted).unwrap();
+        gc.check_vote_change(-1, GaugeStatus::Killed).unwrap();
+
+        // claims stay open
+        assert!(!gc.is_paused(PAUSE_CLAIM));
+
+        gc.set_pause_flags(0).unwrap();
+        gc.check_vote_change(1, GaugeStatus::Whitelisted).unwrap();
+    }
+
+    #[test]
+    fn test_pause_claim() {
+        let mut gc = GaugeConfig::default();
+        gc.set_pause_flags(PAUSE_CLAIM).unwrap();
+
+        // cp/cl withdraw_ray, cp_withdraw_lp and claim_bribe are rejected
+        assert!(gc.is_paused(PAUSE_CLAIM));
+
+        // votes can still be pledged and unpledged
+        gc.check_vote_change(1, GaugeStatus::Whitelisted).unwrap();
+        gc.check_vote_change(-1, GaugeStatus::Whitelisted).unwrap();
+        assert!(!gc.is_paused(PAUSE_VOTE));
+    }
 }
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+
+/// Number of vote changes remembered per gauge in epoch mode
+/// Must exceed `BRIBE_CLAIM_EPOCHS + 1`, so that the votes of any claimable epoch are still known
+pub const VOTE_HISTORY_LEN: usize = 4;
+
+/// Votes in effect from the start of an epoch
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, AnchorDeserialize, AnchorSerialize)]
+pub struct VoteCheckpoint {
+    /// Epoch from which the votes are in effect
+    pub from_epoch: u64,
+
+    /// Votes in effect
+    pub votes: u64,
+}
+
+impl VoteCheckpoint {
+    pub const SIZE: usize =
+        // from_epoch
+        8 +
+        // votes
+        8;
+}
+
+/// Recent epoch-mode vote changes of a gauge, oldest first
+/// Used to look up the votes in effect during a past epoch
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, AnchorDeserialize, AnchorSerialize)]
+pub struct VoteHistory {
+    pub num_checkpoints: u8,
+    pub checkpoints: [VoteCheckpoint; VOTE_HISTORY_LEN],
+}
+
+impl VoteHistory {
+    pub const SIZE: usize =
+        // num_checkpoints
+        1 +
+        // checkpoints
+        VoteCheckpoint::SIZE * VOTE_HISTORY_LEN;
+
+    /// Record a change of votes taking effect at `from_epoch`
+    /// The first record also remembers the votes in effect before it, from epoch 0
+    /// Once full, the oldest checkpoint is dropped
+    pub fn record(&mut self, from_epoch: u64, votes_before: u64, votes_after: u64) {
+        if self.num_checkpoints == 0 {
+            self.push(VoteCheckpoint {
+                from_epoch: 0,
+                votes: votes_before,
+            });
+        }
+
+        let last = &mut self.checkpoints[self.num_checkpoints as usize - 1];
+        if last.from_epoch == from_epoch {
+            last.votes = votes_after;
+            return;
+        }
+
+        self.push(VoteCheckpoint {
+            from_epoch,
+            votes: votes_after,
+        });
+    }
+
+    /// Votes in effect during `epoch`
+    /// `None` if the epoch is older than the remembered checkpoints
+    /// Without any checkpoint, votes never changed in epoch mode and `current_votes` apply
+    pub fn votes_at(&self, epoch: u64, current_votes: u64) -> Option<u64> {
+        if self.num_checkpoints == 0 {
+            return Some(current_votes);
+        }
+
+        self.checkpoints[..self.num_checkpoints as usize]
+            .iter()
+            .rev()
+            .find(|c| c.from_epoch <= epoch)
+            .map(|c| c.votes)
+    }
+
+    fn push(&mut self, checkpoint: VoteCheckpoint) {
+        let len = self.num_checkpoints as usize;
+        if len == VOTE_HISTORY_LEN {
+            self.checkpoints.rotate_left(1);
+            self.checkpoints[VOTE_HISTORY_LEN - 1] = checkpoint;
+        } else {
+            self.checkpoints[len] = checkpoint;
+            self.num_checkpoints += 1;
+        }
+    }
+}
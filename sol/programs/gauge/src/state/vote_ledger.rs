@@ -0,0 +1,107 @@
+use crate::errors::GaugeErrors;
+use anchor_lang::prelude::*;
+
+/// Number of gauges a vote ledger can list
+pub const MAX_VOTE_LEDGER_ENTRIES: usize = 16;
+
+/// Votes of a reactor on one gauge
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, AnchorDeserialize, AnchorSerialize)]
+pub struct VoteLedgerEntry {
+    pub pool_gauge: Pubkey,
+
+    pub votes: u64,
+}
+
+impl VoteLedgerEntry {
+    pub const SIZE: usize =
+        // pool_gauge
+        32 +
+        // votes
+        8;
+}
+
+/// Optional listing of where a reactor's locked votes sit, kept in sync by every vote change
+/// Lets UIs and governance programs read a single account instead of scanning personal gauges
+/// Lists at most `MAX_VOTE_LEDGER_ENTRIES` gauges: while it exists, votes cannot be pledged to more
+/// gauges at once, the ledger must be closed to vote on more
+#[account]
+pub struct VoteLedger {
+    /// Reactor whose votes are listed
+    pub reactor: Pubkey,
+
+    pub num_entries: u8,
+
+    /// Gauges with votes, the first `num_entries` are in use
+    pub entries: [VoteLedgerEntry; MAX_VOTE_LEDGER_ENTRIES],
+}
+
+impl VoteLedger {
+    pub const SIZE: usize =
+        // discriminator
+        8 +
+        // reactor
+        32 +
+        // num_entries
+        1 +
+        // entries
+        VoteLedgerEntry::SIZE * MAX_VOTE_LEDGER_ENTRIES;
+
+    pub fn entries(&self) -> &[VoteLedgerEntry] {
+        &self.entries[..self.num_entries as usize]
+    }
+
+    /// Votes on a gauge
+    pub fn votes_on(&self, pool_gauge: Pubkey) -> u64 {
+        self.entries()
+            .iter()
+            .find(|e| e.pool_gauge == pool_gauge)
+            .map_or(0, |e| e.votes)
+    }
+
+    /// Votes across all gauges, equal to the reactor's locked votes
+    pub fn total_votes(&self) -> u64 {
+        self.entries().iter().map(|e| e.votes).sum()
+    }
+
+    /// Change the votes on a gauge
+    /// Gauges without votes are removed from the listing, zero changes are skipped
+    pub fn change_votes(&mut self, pool_gauge: Pubkey, amount: i64) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+
+        let len = self.num_entries as usize;
+
+        let i = match self
+            .entries()
+            .iter()
+            .position(|e| e.pool_gauge == pool_gauge)
+        {
+            Some(i) => i,
+            None if len == MAX_VOTE_LEDGER_ENTRIES => return err!(GaugeErrors::VoteLedgerFull),
+            None => {
+                self.entries[len] = VoteLedgerEntry {
+                    pool_gauge,
+                    votes: 0,
+                };
+                self.num_entries += 1;
+                len
+            }
+        };
+
+        let entry = &mut self.entries[i];
+        entry.votes = entry
+            .votes
+            .checked_add_signed(amount)
+            .expect("vote ledger underflow");
+
+        if entry.votes == 0 {
+            let last = self.num_entries as usize - 1;
+            self.entries.swap(i, last);
+            self.entries[last] = VoteLedgerEntry::default();
+            self.num_entries -= 1;
+        }
+
+        Ok(())
+    }
+}
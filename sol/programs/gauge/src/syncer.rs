@@ -1,13 +1,80 @@
 use anchor_lang::prelude::*;
 
-use crate::state::{Gauge, GaugeConfig};
+use crate::{
+    errors::GaugeErrors,
+    state::{common::PersonalRewarderState, EmissionSchedule, EpochSnapshot, Gauge, GaugeConfig},
+};
+use precise_number::Number;
 
 pub fn get_now() -> u64 {
     Clock::get().unwrap().unix_timestamp as u64
 }
 
 /// Sync the gauge index with the gauge config index
-pub fn sync_gauge<'i>(now: u64, gauge_config: &mut GaugeConfig, gauge: &mut Gauge) {
-    gauge_config.update_index(now);
-    gauge.update_index(gauge_config.index.into());
+/// Votes queued for an epoch that has started are applied at its boundary
+/// RAY above the gauge's share cap goes back to the emission budget
+pub fn sync_gauge<'i>(
+    now: u64,
+    gauge_config: &mut GaugeConfig,
+    emission_schedule: &EmissionSchedule,
+    gauge: &mut Gauge,
+) -> Result<()> {
+    gauge_config.update_index(now, emission_schedule);
+
+    apply_index(gauge_config, gauge)
+}
+
+/// Sync the gauge and stage the RAY a personal rewarder earned since its last update
+/// `earned_time_units` are the position's time units, brought up to `now` first
+pub fn sync_and_stage_rewarder(
+    now: u64,
+    gauge_config: &mut GaugeConfig,
+    emission_schedule: &EmissionSchedule,
+    gauge: &mut Gauge,
+    rewarder: &mut PersonalRewarderState,
+    earned_time_units: Number,
+) -> Result<u64> {
+    sync_gauge(now, gauge_config, emission_schedule, gauge)?;
+
+    Ok(rewarder.sync_and_stage(now, gauge.total_ray_emitted, earned_time_units))
+}
+
+/// Bring the gauge up to the gauge config index, which must have been updated first
+/// Fails with `EpochRecordRequired` while queued votes are due at a boundary that left the epoch history,
+/// until `apply_epoch_record` applies them
+pub fn apply_index(gauge_config: &mut GaugeConfig, gauge: &mut Gauge) -> Result<()> {
+    let mut ray_diverted = 0;
+
+    if gauge.has_due_votes(gauge_config.current_epoch) {
+        let snapshot = gauge_config
+            .epoch_snapshot(gauge.pending_epoch)
+            .ok_or(GaugeErrors::EpochRecordRequired)?;
+
+        ray_diverted += apply_due_votes(gauge_config, gauge, snapshot);
+    }
+
+    let total_emission: Number = gauge_config.total_emission.into();
+    ray_diverted += gauge_config.sync_vote_share_cap_changes(gauge, total_emission);
+    ray_diverted += gauge.update_index(
+        gauge_config.index.into(),
+        total_emission,
+        gauge_config.max_vote_share_bps_at(total_emission),
+    );
+
+    gauge_config.return_diverted_ray(ray_diverted);
+
+    Ok(())
+}
+
+/// Apply the gauge's queued votes at the boundary of `snapshot`, under the global caps in force until then
+/// Returns the amount of RAY diverted
+pub fn apply_due_votes(
+    gauge_config: &GaugeConfig,
+    gauge: &mut Gauge,
+    snapshot: EpochSnapshot,
+) -> u64 {
+    let total_emission: Number = snapshot.total_emission.into();
+
+    gauge_config.sync_vote_share_cap_changes(gauge, total_emission)
+        + gauge.apply_due_votes(snapshot, gauge_config.max_vote_share_bps_at(total_emission))
 }
@@ -25,4 +25,58 @@ pub enum ReactorErrors {
 
     #[msg("Not admin")]
     NotAdmin,
+
+    #[msg("Not pending admin")]
+    NotPendingAdmin,
+
+    #[msg("Unknown pause flags")]
+    InvalidPauseFlags,
+
+    #[msg("Deposits are paused")]
+    DepositsPaused,
+
+    #[msg("Withdrawals are paused")]
+    WithdrawalsPaused,
+
+    #[msg("Reward claims are paused")]
+    ClaimsPaused,
+
+    #[msg("RAY is locked until its unlock time")]
+    RayLocked,
+
+    #[msg("Insufficient unlocked RAY to lock")]
+    InsufficientRayToLock,
+
+    #[msg("Lock duration must be positive and at most the maximum duration")]
+    InvalidRayLockDuration,
+
+    #[msg("Too many active RAY locks")]
+    TooManyRayLocks,
+
+    #[msg("Cannot change vote mode while votes are locked")]
+    VotesLockedDuringModeChange,
+
+    #[msg("Insufficient decayed votes to release")]
+    InsufficientDecayedVotes,
+
+    #[msg("Withdrawals must go through the unstake cooldown")]
+    UnstakeCooldownRequired,
+
+    #[msg("No pending unstake")]
+    NoPendingUnstake,
+
+    #[msg("Unstake cooldown has not passed")]
+    UnstakeCooldownActive,
+
+    #[msg("Signer is neither the reactor owner nor its vote delegate")]
+    NotVoteAuthority,
+
+    #[msg("Too many gauges in the delegation allow-list")]
+    TooManyDelegationGauges,
+
+    #[msg("Reactor still holds RAY, isoRAY, votes or rewards")]
+    ReactorNotEmpty,
+
+    #[msg("Vote delegation must be revoked first")]
+    VoteDelegationActive,
 }
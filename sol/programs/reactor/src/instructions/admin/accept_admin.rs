@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ReactorErrors, state::ReactorConfig};
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    pub pending_admin: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = pending_admin @ ReactorErrors::NotPendingAdmin,
+    )]
+    pub reactor_config: Account<'info, ReactorConfig>,
+}
+
+pub fn handler(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let old_admin = ctx.accounts.reactor_config.accept_admin();
+
+    emit!(AdminAcceptedEvent {
+        old_admin,
+        new_admin: ctx.accounts.pending_admin.key(),
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct AdminAcceptedEvent {
+    pub old_admin: Pubkey,
+    pub new_admin: Pubkey,
+}
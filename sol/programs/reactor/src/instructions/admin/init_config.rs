@@ -8,6 +8,7 @@ use crate::{
 
 #[derive(Accounts)]
 pub struct InitConfig<'info> {
+    /// The deployer key bootstraps the config and becomes its first admin
     #[account(
         mut,
         address = crate::admin::id() @ ReactorErrors::NotAdmin
@@ -75,6 +76,7 @@ pub fn handler(
     c.ray_reward_hopper = ctx.accounts.ray_hopper.key();
     c.ray_reward_daily_emission = ray_reward_daily_emisison;
     c.iso_ray_apr_bps = iso_ray_apr_bps;
+    c.admin = ctx.accounts.payer.key();
 
     Ok(())
 }
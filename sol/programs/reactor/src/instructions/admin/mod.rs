@@ -1,3 +1,15 @@
+pub mod accept_admin;
 pub mod init_config;
+pub mod propose_admin;
+pub mod set_iso_ray_apr_bps;
+pub mod set_pause_flags;
+pub mod set_ray_reward_daily_emission;
+pub mod set_unstake_cooldown;
 
+pub use accept_admin::*;
 pub use init_config::*;
+pub use propose_admin::*;
+pub use set_iso_ray_apr_bps::*;
+pub use set_pause_flags::*;
+pub use set_ray_reward_daily_emission::*;
+pub use set_unstake_cooldown::*;
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ReactorErrors, state::ReactorConfig};
+
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = admin @ ReactorErrors::NotAdmin,
+    )]
+    pub reactor_config: Account<'info, ReactorConfig>,
+}
+
+pub fn handler(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+    // the current admin stays in control until the new admin accepts
+    ctx.accounts.reactor_config.propose_admin(new_admin);
+
+    emit!(AdminProposedEvent {
+        admin: ctx.accounts.admin.key(),
+        pending_admin: new_admin,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct AdminProposedEvent {
+    pub admin: Pubkey,
+    pub pending_admin: Pubkey,
+}
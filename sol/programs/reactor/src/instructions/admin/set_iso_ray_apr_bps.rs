@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::{clock::now, errors::ReactorErrors, state::ReactorConfig};
+
+#[derive(Accounts)]
+pub struct SetIsoRayAprBps<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = admin @ ReactorErrors::NotAdmin,
+    )]
+    pub reactor_config: Account<'info, ReactorConfig>,
+}
+
+pub fn handler(ctx: Context<SetIsoRayAprBps>, iso_ray_apr_bps: u16) -> Result<()> {
+    // past time is settled at the old APR before the new one takes effect
+    let old_iso_ray_apr_bps = ctx
+        .accounts
+        .reactor_config
+        .set_iso_ray_apr_bps(iso_ray_apr_bps, now());
+
+    emit!(IsoRayAprChangedEvent {
+        old_iso_ray_apr_bps,
+        new_iso_ray_apr_bps: iso_ray_apr_bps,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct IsoRayAprChangedEvent {
+    pub old_iso_ray_apr_bps: u16,
+    pub new_iso_ray_apr_bps: u16,
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ReactorErrors, state::ReactorConfig};
+
+#[derive(Accounts)]
+pub struct SetPauseFlags<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = admin @ ReactorErrors::NotAdmin,
+    )]
+    pub reactor_config: Account<'info, ReactorConfig>,
+}
+
+pub fn handler(ctx: Context<SetPauseFlags>, pause_flags: u8) -> Result<()> {
+    let old_pause_flags = ctx.accounts.reactor_config.set_pause_flags(pause_flags)?;
+
+    emit!(PauseFlagsChangedEvent {
+        old_pause_flags,
+        new_pause_flags: pause_flags,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct PauseFlagsChangedEvent {
+    pub old_pause_flags: u8,
+    pub new_pause_flags: u8,
+}
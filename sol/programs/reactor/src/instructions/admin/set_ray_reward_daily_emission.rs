@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::{clock::now, errors::ReactorErrors, state::ReactorConfig};
+
+#[derive(Accounts)]
+pub struct SetRayRewardDailyEmission<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = admin @ ReactorErrors::NotAdmin,
+    )]
+    pub reactor_config: Account<'info, ReactorConfig>,
+}
+
+pub fn handler(
+    ctx: Context<SetRayRewardDailyEmission>,
+    ray_reward_daily_emission: u64,
+) -> Result<()> {
+    // past time is settled at the old emission before the new one takes effect
+    let old_ray_reward_daily_emission = ctx
+        .accounts
+        .reactor_config
+        .set_ray_reward_daily_emission(ray_reward_daily_emission, now());
+
+    emit!(RayRewardDailyEmissionChangedEvent {
+        old_ray_reward_daily_emission,
+        new_ray_reward_daily_emission: ray_reward_daily_emission,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct RayRewardDailyEmissionChangedEvent {
+    pub old_ray_reward_daily_emission: u64,
+    pub new_ray_reward_daily_emission: u64,
+}
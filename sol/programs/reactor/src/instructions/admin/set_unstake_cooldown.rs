@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ReactorErrors, state::ReactorConfig};
+
+#[derive(Accounts)]
+pub struct SetUnstakeCooldown<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = admin @ ReactorErrors::NotAdmin,
+    )]
+    pub reactor_config: Account<'info, ReactorConfig>,
+}
+
+pub fn handler(ctx: Context<SetUnstakeCooldown>, unstake_cooldown: u64) -> Result<()> {
+    // unstakes already pending keep the unlock time they were requested with
+    let c = &mut ctx.accounts.reactor_config;
+    let old_unstake_cooldown = c.unstake_cooldown;
+    c.unstake_cooldown = unstake_cooldown;
+
+    emit!(UnstakeCooldownChangedEvent {
+        old_unstake_cooldown,
+        new_unstake_cooldown: unstake_cooldown,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct UnstakeCooldownChangedEvent {
+    pub old_unstake_cooldown: u64,
+    pub new_unstake_cooldown: u64,
+}
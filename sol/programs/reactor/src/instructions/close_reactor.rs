@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    clock::now,
+    errors::ReactorErrors,
+    instructions::sync_reactor::handle_sync_reactor,
+    state::{Reactor, ReactorConfig},
+    VOTE_DELEGATION_SEED,
+};
+
+/// Close an empty reactor and refund its rent
+/// The reactor is synced first, so that unstaged rewards keep it open instead of being lost
+#[derive(Accounts)]
+pub struct CloseReactor<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner,
+        close = destination
+    )]
+    pub reactor: Account<'info, Reactor>,
+
+    #[account(mut)]
+    pub reactor_config: Account<'info, ReactorConfig>,
+
+    /// A vote delegation must be revoked first, so that it cannot govern a re-opened reactor
+    /// CHECK: only checked to be empty
+    #[account(
+        seeds = [VOTE_DELEGATION_SEED.as_bytes(), reactor.key().as_ref()],
+        bump,
+        constraint = vote_delegation.data_is_empty() @ ReactorErrors::VoteDelegationActive
+    )]
+    pub vote_delegation: UncheckedAccount<'info>,
+
+    /// CHECK: receives the rent of the reactor
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<CloseReactor>) -> Result<()> {
+    handle_sync_reactor(
+        &mut ctx.accounts.reactor,
+        &mut ctx.accounts.reactor_config,
+        now(),
+    );
+
+    require!(
+        ctx.accounts.reactor.is_empty(),
+        ReactorErrors::ReactorNotEmpty
+    );
+
+    emit!(ReactorClosedEvent {
+        owner: ctx.accounts.owner.key(),
+        reactor: ctx.accounts.reactor.key(),
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ReactorClosedEvent {
+    pub owner: Pubkey,
+    pub reactor: Pubkey,
+}
@@ -1,7 +1,10 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 
-use crate::state::{Reactor, ReactorConfig};
+use crate::{
+    errors::ReactorErrors,
+    state::{Reactor, ReactorConfig, PAUSE_CLAIM},
+};
 
 #[derive(Accounts)]
 pub struct CollectRayRewards<'info> {
@@ -15,7 +18,8 @@ pub struct CollectRayRewards<'info> {
     pub reactor: Account<'info, Reactor>,
 
     #[account(
-        has_one = ray_reward_hopper
+        has_one = ray_reward_hopper,
+        constraint = !reactor_config.is_paused(PAUSE_CLAIM) @ ReactorErrors::ClaimsPaused,
     )]
     pub reactor_config: Account<'info, ReactorConfig>,
 
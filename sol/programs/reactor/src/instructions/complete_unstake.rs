@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::{
+    clock::now,
+    errors::ReactorErrors,
+    state::{Reactor, ReactorConfig, PAUSE_WITHDRAW},
+};
+
+/// Release the pending unstake once the cooldown has passed
+#[derive(Accounts)]
+pub struct CompleteUnstake<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner,
+    )]
+    pub reactor: Account<'info, Reactor>,
+
+    #[account(mut)]
+    pub ray_vault: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub ray_dst: Account<'info, TokenAccount>,
+
+    #[account(
+        has_one = ray_vault,
+        constraint = !reactor_config.is_paused(PAUSE_WITHDRAW) @ ReactorErrors::WithdrawalsPaused,
+    )]
+    pub reactor_config: Account<'info, ReactorConfig>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> CompleteUnstake<'info> {
+    fn withdraw_ray_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.ray_vault.to_account_info(),
+                to: self.ray_dst.to_account_info(),
+                authority: self.reactor_config.to_account_info(),
+            },
+        )
+    }
+}
+
+pub fn handler(ctx: Context<CompleteUnstake>) -> Result<()> {
+    let amount = ctx.accounts.reactor.complete_unstake(now())?;
+
+    token::transfer(
+        ctx.accounts
+            .withdraw_ray_ctx()
+            .with_signer(&[&ctx.accounts.reactor_config.seeds()]),
+        amount,
+    )?;
+
+    emit!(UnstakeCompletedEvent {
+        reactor: ctx.accounts.reactor.key(),
+        amount,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct UnstakeCompletedEvent {
+    pub reactor: Pubkey,
+    pub amount: u64,
+}
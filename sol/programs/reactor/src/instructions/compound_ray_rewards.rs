@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::{
+    clock::now,
+    errors::ReactorErrors,
+    state::{Reactor, ReactorConfig, PAUSE_CLAIM, PAUSE_DEPOSIT},
+};
+
+/// Restake the earned RAY rewards by moving them from the reward hopper into the vault
+#[derive(Accounts)]
+pub struct CompoundRayRewards<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner,
+    )]
+    pub reactor: Account<'info, Reactor>,
+
+    #[account(
+        mut,
+        has_one = ray_reward_hopper,
+        has_one = ray_vault,
+        constraint = !reactor_config.is_paused(PAUSE_CLAIM) @ ReactorErrors::ClaimsPaused,
+        constraint = !reactor_config.is_paused(PAUSE_DEPOSIT) @ ReactorErrors::DepositsPaused,
+    )]
+    pub reactor_config: Account<'info, ReactorConfig>,
+
+    #[account(mut)]
+    pub ray_reward_hopper: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub ray_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'i> CompoundRayRewards<'i> {
+    fn transfer_context(&self) -> CpiContext<'_, '_, '_, 'i, Transfer<'i>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.ray_reward_hopper.to_account_info(),
+                to: self.ray_vault.to_account_info(),
+                authority: self.reactor_config.to_account_info(),
+            },
+        )
+    }
+}
+
+pub fn handler(ctx: Context<CompoundRayRewards>) -> Result<()> {
+    let ray_compounded = handle_compound_ray_rewards(
+        &mut ctx.accounts.reactor_config,
+        &mut ctx.accounts.reactor,
+        now(),
+    );
+
+    token::transfer(
+        ctx.accounts
+            .transfer_context()
+            .with_signer(&[&ctx.accounts.reactor_config.seeds()]),
+        ray_compounded,
+    )?;
+
+    emit!(CompoundRayRewardsEvent {
+        reactor: ctx.accounts.reactor.key(),
+        ray_compounded,
+        new_reactor_amount: ctx.accounts.reactor.ray,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct CompoundRayRewardsEvent {
+    pub reactor: Pubkey,
+
+    /// Amount of RAY rewards moved into the stake
+    pub ray_compounded: u64,
+
+    /// The new amount of RAY deposited in the reactor
+    pub new_reactor_amount: u64,
+}
+
+fn handle_compound_ray_rewards(
+    reactor_config: &mut ReactorConfig,
+    reactor: &mut Reactor,
+    now: u64,
+) -> u64 {
+    // updates the global indexes, so the compounded RAY only earns from now on
+    reactor_config.deposit_ray(0, now);
+
+    // accrues the reactor up to the indexes and restakes the earned rewards
+    let ray_compounded = reactor.compound_ray_rewards(
+        reactor_config.iso_ray_index.into(),
+        reactor_config.ray_reward_index.into(),
+        now,
+    );
+
+    // indexes are already current, this only raises the total deposited
+    reactor_config.deposit_ray(ray_compounded, now);
+
+    ray_compounded
+}
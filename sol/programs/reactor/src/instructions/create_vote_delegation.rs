@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{Reactor, VoteDelegation},
+    VOTE_DELEGATION_SEED,
+};
+
+/// Authorize a delegate to allocate the reactor's votes
+#[derive(Accounts)]
+pub struct CreateVoteDelegation<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(has_one = owner)]
+    pub reactor: Account<'info, Reactor>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = VoteDelegation::LEN,
+        seeds = [
+            VOTE_DELEGATION_SEED.as_bytes(),
+            reactor.key().as_ref(),
+        ],
+        bump
+    )]
+    pub vote_delegation: Account<'info, VoteDelegation>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<CreateVoteDelegation>,
+    delegate: Pubkey,
+    expires_at: u64,
+    allowed_gauges: Vec<Pubkey>,
+) -> Result<()> {
+    let reactor = ctx.accounts.reactor.key();
+
+    ctx.accounts
+        .vote_delegation
+        .set(reactor, delegate, expires_at, &allowed_gauges)?;
+
+    emit!(VoteDelegationCreatedEvent {
+        reactor,
+        delegate,
+        expires_at,
+        allowed_gauges,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct VoteDelegationCreatedEvent {
+    pub reactor: Pubkey,
+    pub delegate: Pubkey,
+
+    /// 0 if the delegation never expires
+    pub expires_at: u64,
+
+    /// Empty if the delegate may vote on any gauge
+    pub allowed_gauges: Vec<Pubkey>,
+}
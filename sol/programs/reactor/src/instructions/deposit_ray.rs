@@ -3,7 +3,8 @@ use anchor_spl::token::{self, *};
 
 use crate::{
     clock::now,
-    state::{Reactor, ReactorConfig},
+    errors::ReactorErrors,
+    state::{Reactor, ReactorConfig, PAUSE_DEPOSIT},
 };
 
 #[derive(Accounts)]
@@ -29,6 +30,7 @@ pub struct DepositRay<'info> {
     #[account(
         mut,
         has_one = ray_vault,
+        constraint = !reactor_config.is_paused(PAUSE_DEPOSIT) @ ReactorErrors::DepositsPaused,
     )]
     pub reactor_config: Account<'info, ReactorConfig>,
 
@@ -98,6 +100,7 @@ fn handle_deposit_ray(
         amount,
         reactor_config.iso_ray_index.into(),
         reactor_config.ray_reward_index.into(),
+        now,
     );
 
     new_reactor_amount
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::{clock::now, state::ReactorConfig};
+
+/// Fund the reward hopper, raising the amount of RAY the reward index may emit
+#[derive(Accounts)]
+pub struct FundRewardHopper<'info> {
+    /// Permissionless funder
+    pub funder: Signer<'info>,
+
+    #[account(mut)]
+    pub ray_src: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        has_one = ray_reward_hopper
+    )]
+    pub reactor_config: Account<'info, ReactorConfig>,
+
+    #[account(mut)]
+    pub ray_reward_hopper: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'i> FundRewardHopper<'i> {
+    fn transfer_context(&self) -> CpiContext<'_, '_, '_, 'i, Transfer<'i>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.ray_src.to_account_info(),
+                to: self.ray_reward_hopper.to_account_info(),
+                authority: self.funder.to_account_info(),
+            },
+        )
+    }
+}
+
+pub fn handler(ctx: Context<FundRewardHopper>, amount: u64) -> Result<()> {
+    let total_ray_funded = ctx.accounts.reactor_config.fund_ray_rewards(amount, now());
+
+    token::transfer(ctx.accounts.transfer_context(), amount)?;
+
+    emit!(RewardHopperFundedEvent {
+        funder: ctx.accounts.funder.key(),
+        amount,
+        total_ray_funded,
+        total_ray_emitted: ctx.accounts.reactor_config.total_ray_emitted,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct RewardHopperFundedEvent {
+    pub funder: Pubkey,
+    pub amount: u64,
+
+    /// Lifetime RAY funded into the hopper
+    pub total_ray_funded: u64,
+
+    /// Lifetime RAY emitted through the reward index
+    pub total_ray_emitted: u64,
+}
@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{PendingUnstake, Reactor};
+
+/// Read-only view of the RAY waiting out the unstake cooldown
+#[derive(Accounts)]
+pub struct GetPendingUnstake<'info> {
+    pub reactor: Account<'info, Reactor>,
+}
+
+pub fn handler(ctx: Context<GetPendingUnstake>) -> Result<PendingUnstake> {
+    Ok(ctx.accounts.reactor.pending_unstake)
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    clock::now,
+    errors::ReactorErrors,
+    state::{Reactor, ReactorConfig, PAUSE_DEPOSIT},
+};
+
+/// Lock deposited RAY for a duration in exchange for boosted isoRAY accrual
+#[derive(Accounts)]
+pub struct LockRay<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner,
+    )]
+    pub reactor: Account<'info, Reactor>,
+
+    #[account(
+        mut,
+        constraint = !reactor_config.is_paused(PAUSE_DEPOSIT) @ ReactorErrors::DepositsPaused,
+    )]
+    pub reactor_config: Account<'info, ReactorConfig>,
+}
+
+pub fn handler(ctx: Context<LockRay>, amount: u64, duration: u64) -> Result<()> {
+    let now = now();
+
+    // updates the global indexes
+    ctx.accounts.reactor_config.deposit_ray(0, now);
+
+    let lock = ctx.accounts.reactor.lock_ray(
+        amount,
+        duration,
+        ctx.accounts.reactor_config.iso_ray_index.into(),
+        ctx.accounts.reactor_config.ray_reward_index.into(),
+        now,
+    )?;
+
+    emit!(RayLockedEvent {
+        reactor: ctx.accounts.reactor.key(),
+        amount: lock.amount,
+        unlock_ts: lock.unlock_ts,
+        bonus_bps: lock.bonus_bps,
+        total_locked_ray: ctx.accounts.reactor.locked_ray,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct RayLockedEvent {
+    pub reactor: Pubkey,
+    pub amount: u64,
+
+    /// Timestamp at which the RAY can be withdrawn
+    pub unlock_ts: u64,
+
+    /// Bonus on isoRAY accrual while locked
+    pub bonus_bps: u16,
+
+    /// Total RAY in active locks for the reactor
+    pub total_locked_ray: u64,
+}
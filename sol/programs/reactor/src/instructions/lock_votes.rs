@@ -1,19 +1,21 @@
 use crate::caller_program;
-use crate::state::Reactor;
+use crate::clock::now;
+use crate::state::{check_vote_authority, Reactor, VoteDelegation};
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::sysvar::instructions as tx_instructions;
 
 #[derive(Accounts)]
 pub struct LockVotes<'info> {
+    /// Reactor owner, or its delegate when a vote delegation is provided
     #[account(mut)]
-    pub owner: Signer<'info>,
+    pub authority: Signer<'info>,
 
-    #[account(
-        mut,
-        has_one = owner,
-    )]
+    #[account(mut)]
     pub reactor: Account<'info, Reactor>,
 
+    #[account(has_one = reactor)]
+    pub vote_delegation: Option<Account<'info, VoteDelegation>>,
+
     /// CHECK: Provide transaction instruction data.
     #[account(address = tx_instructions::ID)]
     pub sysvar_instruction: UncheckedAccount<'info>,
@@ -25,6 +27,15 @@ pub fn handler(ctx: Context<LockVotes>, amount: u64) -> Result<()> {
         tx_instructions::get_instruction_relative(0, &ctx.accounts.sysvar_instruction).unwrap();
     require_keys_eq!(current_ix.program_id, caller_program::id());
 
-    ctx.accounts.reactor.lock_votes(amount)?;
+    let now = now();
+
+    check_vote_authority(
+        ctx.accounts.reactor.owner,
+        ctx.accounts.authority.key(),
+        ctx.accounts.vote_delegation.as_deref(),
+        now,
+    )?;
+
+    ctx.accounts.reactor.lock_votes(amount, now)?;
     Ok(())
 }
@@ -1,18 +1,40 @@
 pub mod admin;
+pub mod close_reactor;
 pub mod collect_ray_rewards;
+pub mod complete_unstake;
+pub mod compound_ray_rewards;
+pub mod create_vote_delegation;
 pub mod deposit_ray;
+pub mod fund_reward_hopper;
+pub mod get_pending_unstake;
 pub mod init_reactor;
+pub mod lock_ray;
 pub mod lock_votes;
+pub mod release_decayed_votes;
+pub mod request_unstake;
+pub mod revoke_vote_delegation;
+pub mod set_ve_mode;
 pub mod sync_and_collect_ray_rewards;
 pub mod sync_reactor;
 pub mod unlock_votes;
 pub mod withdraw_ray;
 
 pub use admin::*;
+pub use close_reactor::*;
 pub use collect_ray_rewards::*;
+pub use complete_unstake::*;
+pub use compound_ray_rewards::*;
+pub use create_vote_delegation::*;
 pub use deposit_ray::*;
+pub use fund_reward_hopper::*;
+pub use get_pending_unstake::*;
 pub use init_reactor::*;
+pub use lock_ray::*;
 pub use lock_votes::*;
+pub use release_decayed_votes::*;
+pub use request_unstake::*;
+pub use revoke_vote_delegation::*;
+pub use set_ve_mode::*;
 pub use sync_and_collect_ray_rewards::*;
 pub use sync_reactor::*;
 pub use unlock_votes::*;
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::caller_program;
+use crate::clock::now;
+use crate::state::Reactor;
+use anchor_lang::solana_program::sysvar::instructions as tx_instructions;
+
+/// Release locked votes that are no longer backed by ve-mode vote power
+/// Does not require the owner, so that the gauge program can crank decay
+#[derive(Accounts)]
+pub struct ReleaseDecayedVotes<'info> {
+    #[account(mut)]
+    pub reactor: Account<'info, Reactor>,
+
+    /// CHECK: Provide transaction instruction data.
+    #[account(address = tx_instructions::ID)]
+    pub sysvar_instruction: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<ReleaseDecayedVotes>, amount: u64) -> Result<()> {
+    // must be called from CPI by gauge program, which removes the votes from its gauges
+    let current_ix =
+        tx_instructions::get_instruction_relative(0, &ctx.accounts.sysvar_instruction).unwrap();
+    require_keys_eq!(current_ix.program_id, caller_program::id());
+
+    ctx.accounts.reactor.release_decayed_votes(amount, now())?;
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    clock::now,
+    errors::ReactorErrors,
+    state::{Reactor, ReactorConfig, PAUSE_WITHDRAW},
+};
+
+/// Move RAY out of the stake into the pending unstake slot, starting the cooldown
+#[derive(Accounts)]
+pub struct RequestUnstake<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner,
+    )]
+    pub reactor: Account<'info, Reactor>,
+
+    #[account(
+        mut,
+        constraint = !reactor_config.is_paused(PAUSE_WITHDRAW) @ ReactorErrors::WithdrawalsPaused,
+    )]
+    pub reactor_config: Account<'info, ReactorConfig>,
+}
+
+pub fn handler(ctx: Context<RequestUnstake>, amount: u64) -> Result<()> {
+    let now = now();
+
+    // the RAY leaves the global stake now, so it stops earning
+    ctx.accounts.reactor_config.withdraw_ray(amount, now);
+
+    let pending = ctx.accounts.reactor.request_unstake(
+        amount,
+        ctx.accounts.reactor_config.iso_ray_index.into(),
+        ctx.accounts.reactor_config.ray_reward_index.into(),
+        now,
+        ctx.accounts.reactor_config.unstake_cooldown,
+    )?;
+
+    emit!(UnstakeRequestedEvent {
+        reactor: ctx.accounts.reactor.key(),
+        amount,
+        pending_amount: pending.amount,
+        unlock_ts: pending.unlock_ts,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct UnstakeRequestedEvent {
+    pub reactor: Pubkey,
+    pub amount: u64,
+
+    /// Total RAY pending release for the reactor
+    pub pending_amount: u64,
+
+    /// Timestamp at which the pending RAY can be released
+    pub unlock_ts: u64,
+}
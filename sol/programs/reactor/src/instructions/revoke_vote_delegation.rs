@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Reactor, VoteDelegation};
+
+/// Revoke the delegation and return its rent to the owner
+/// Votes already allocated by the delegate stay in place
+#[derive(Accounts)]
+pub struct RevokeVoteDelegation<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(has_one = owner)]
+    pub reactor: Account<'info, Reactor>,
+
+    #[account(
+        mut,
+        has_one = reactor,
+        close = owner
+    )]
+    pub vote_delegation: Account<'info, VoteDelegation>,
+}
+
+pub fn handler(ctx: Context<RevokeVoteDelegation>) -> Result<()> {
+    emit!(VoteDelegationRevokedEvent {
+        reactor: ctx.accounts.reactor.key(),
+        delegate: ctx.accounts.vote_delegation.delegate,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct VoteDelegationRevokedEvent {
+    pub reactor: Pubkey,
+    pub delegate: Pubkey,
+}
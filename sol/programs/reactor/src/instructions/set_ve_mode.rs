@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::state::Reactor;
+
+/// Opt in or out of decaying ve-mode voting power
+#[derive(Accounts)]
+pub struct SetVeMode<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner,
+    )]
+    pub reactor: Account<'info, Reactor>,
+}
+
+pub fn handler(ctx: Context<SetVeMode>, ve_mode: bool) -> Result<()> {
+    ctx.accounts.reactor.set_ve_mode(ve_mode)?;
+
+    emit!(VeModeChangedEvent {
+        reactor: ctx.accounts.reactor.key(),
+        ve_mode,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct VeModeChangedEvent {
+    pub reactor: Pubkey,
+    pub ve_mode: bool,
+}
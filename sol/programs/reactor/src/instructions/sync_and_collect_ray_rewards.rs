@@ -3,7 +3,8 @@ use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 
 use crate::{
     clock::now,
-    state::{Reactor, ReactorConfig},
+    errors::ReactorErrors,
+    state::{Reactor, ReactorConfig, PAUSE_CLAIM},
 };
 
 use super::handle_sync_reactor;
@@ -22,7 +23,8 @@ pub struct SyncAndCollectRayRewards<'info> {
 
     #[account(
         mut,
-        has_one = ray_reward_hopper
+        has_one = ray_reward_hopper,
+        constraint = !reactor_config.is_paused(PAUSE_CLAIM) @ ReactorErrors::ClaimsPaused,
     )]
     pub reactor_config: Account<'info, ReactorConfig>,
 
@@ -40,6 +40,7 @@ pub fn handle_sync_reactor(
         0,
         reactor_config.iso_ray_index.into(),
         reactor_config.ray_reward_index.into(),
+        now,
     );
 
     new_reactor_amount
@@ -1,20 +1,22 @@
 use anchor_lang::prelude::*;
 
 use crate::caller_program;
-use crate::state::Reactor;
+use crate::clock::now;
+use crate::state::{check_vote_authority, Reactor, VoteDelegation};
 use anchor_lang::solana_program::sysvar::instructions as tx_instructions;
 
 #[derive(Accounts)]
 pub struct UnlockVotes<'info> {
+    /// Reactor owner, or its delegate when a vote delegation is provided
     #[account(mut)]
-    pub owner: Signer<'info>,
+    pub authority: Signer<'info>,
 
-    #[account(
-        mut,
-        has_one = owner,
-    )]
+    #[account(mut)]
     pub reactor: Account<'info, Reactor>,
 
+    #[account(has_one = reactor)]
+    pub vote_delegation: Option<Account<'info, VoteDelegation>>,
+
     /// CHECK: Provide transaction instruction data.
     #[account(address = tx_instructions::ID)]
     pub sysvar_instruction: UncheckedAccount<'info>,
@@ -26,6 +28,13 @@ pub fn handler(ctx: Context<UnlockVotes>, amount: u64) -> Result<()> {
         tx_instructions::get_instruction_relative(0, &ctx.accounts.sysvar_instruction).unwrap();
     require_keys_eq!(current_ix.program_id, caller_program::id());
 
+    check_vote_authority(
+        ctx.accounts.reactor.owner,
+        ctx.accounts.authority.key(),
+        ctx.accounts.vote_delegation.as_deref(),
+        now(),
+    )?;
+
     ctx.accounts.reactor.unlock_votes(amount)?;
     Ok(())
 }
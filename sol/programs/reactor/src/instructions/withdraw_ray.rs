@@ -1,7 +1,10 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 
-use crate::state::{Reactor, ReactorConfig};
+use crate::{
+    errors::ReactorErrors,
+    state::{Reactor, ReactorConfig, PAUSE_WITHDRAW},
+};
 
 #[derive(Accounts)]
 pub struct WithdrawRay<'info> {
@@ -23,6 +26,8 @@ pub struct WithdrawRay<'info> {
     #[account(
         mut,
         has_one = ray_vault,
+        constraint = !reactor_config.is_paused(PAUSE_WITHDRAW) @ ReactorErrors::WithdrawalsPaused,
+        constraint = reactor_config.unstake_cooldown == 0 @ ReactorErrors::UnstakeCooldownRequired,
     )]
     pub reactor_config: Account<'info, ReactorConfig>,
 
@@ -58,6 +63,7 @@ pub fn handler(ctx: Context<WithdrawRay>, amount: u64) -> Result<()> {
         amount,
         ctx.accounts.reactor_config.iso_ray_index.into(),
         ctx.accounts.reactor_config.ray_reward_index.into(),
+        current_ts,
     )?;
 
     // Transfer the amount of ray from the vault to the destination account
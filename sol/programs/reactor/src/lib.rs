@@ -7,6 +7,12 @@ pub mod state;
 
 use instructions::*;
 
+/// State transition of `sync_reactor`, for off-chain projections
+pub use instructions::sync_reactor::handle_sync_reactor;
+use state::PendingUnstake;
+
+/// Deployer key that may initialize the config
+/// Once initialized, admin authority is the `admin` stored on the `ReactorConfig`
 pub mod admin {
     use anchor_lang::prelude::declare_id;
 
@@ -30,6 +36,7 @@ pub const REACTOR_SEED: &str = "reactor";
 pub const REACTOR_CONFIG_SEED: &str = "config";
 pub const REACTOR_VAULT_SEED: &str = "ray-vault";
 pub const REACTOR_REWARD_HOPPER_SEED: &str = "ray-reward-hopper";
+pub const VOTE_DELEGATION_SEED: &str = "vote-delegation";
 
 declare_id!("DYt9TpjENhrD8GCBPiBkwVNbb8jDAELqnCCGrHqKzvwY");
 
@@ -46,6 +53,42 @@ pub mod reactor {
         init_config::handler(ctx, ray_reward_daily_emission, iso_ray_apr_bps)
     }
 
+    /// Change the daily RAY reward emission, settling the indexes at the old rate first
+    pub fn set_ray_reward_daily_emission(
+        ctx: Context<SetRayRewardDailyEmission>,
+        ray_reward_daily_emission: u64,
+    ) -> Result<()> {
+        set_ray_reward_daily_emission::handler(ctx, ray_reward_daily_emission)
+    }
+
+    /// Change the isoRAY APR, settling the indexes at the old rate first
+    pub fn set_iso_ray_apr_bps(ctx: Context<SetIsoRayAprBps>, iso_ray_apr_bps: u16) -> Result<()> {
+        set_iso_ray_apr_bps::handler(ctx, iso_ray_apr_bps)
+    }
+
+    /// Propose a new admin for the reactor config
+    pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+        propose_admin::handler(ctx, new_admin)
+    }
+
+    /// Accept the admin role as the pending admin
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        accept_admin::handler(ctx)
+    }
+
+    /// Set the bitmask of paused actions
+    pub fn set_pause_flags(ctx: Context<SetPauseFlags>, pause_flags: u8) -> Result<()> {
+        set_pause_flags::handler(ctx, pause_flags)
+    }
+
+    /// Set the cooldown between requesting and completing an unstake
+    pub fn set_unstake_cooldown(
+        ctx: Context<SetUnstakeCooldown>,
+        unstake_cooldown: u64,
+    ) -> Result<()> {
+        set_unstake_cooldown::handler(ctx, unstake_cooldown)
+    }
+
     /// Initialize a personal reactor account
     pub fn init_reactor(ctx: Context<InitReactor>) -> Result<()> {
         init_reactor::handler(ctx)
@@ -61,6 +104,41 @@ pub mod reactor {
         withdraw_ray::handler(ctx, amount)
     }
 
+    /// Move RAY out of the stake, to be released after the unstake cooldown
+    pub fn request_unstake(ctx: Context<RequestUnstake>, amount: u64) -> Result<()> {
+        request_unstake::handler(ctx, amount)
+    }
+
+    /// Release RAY whose unstake cooldown has passed
+    pub fn complete_unstake(ctx: Context<CompleteUnstake>) -> Result<()> {
+        complete_unstake::handler(ctx)
+    }
+
+    /// View the RAY waiting out the unstake cooldown
+    pub fn get_pending_unstake(ctx: Context<GetPendingUnstake>) -> Result<PendingUnstake> {
+        get_pending_unstake::handler(ctx)
+    }
+
+    /// Lock deposited RAY until `duration` seconds from now, boosting its isoRAY accrual
+    pub fn lock_ray(ctx: Context<LockRay>, amount: u64, duration: u64) -> Result<()> {
+        lock_ray::handler(ctx, amount, duration)
+    }
+
+    /// Authorize a delegate to lock and unlock the reactor's votes through the gauge program
+    pub fn create_vote_delegation(
+        ctx: Context<CreateVoteDelegation>,
+        delegate: Pubkey,
+        expires_at: u64,
+        allowed_gauges: Vec<Pubkey>,
+    ) -> Result<()> {
+        create_vote_delegation::handler(ctx, delegate, expires_at, allowed_gauges)
+    }
+
+    /// Revoke the reactor's vote delegation
+    pub fn revoke_vote_delegation(ctx: Context<RevokeVoteDelegation>) -> Result<()> {
+        revoke_vote_delegation::handler(ctx)
+    }
+
     /// Lock Reactor votes
     pub fn lock_votes(ctx: Context<LockVotes>, amount: u64) -> Result<()> {
         lock_votes::handler(ctx, amount)
@@ -71,6 +149,21 @@ pub mod reactor {
         unlock_votes::handler(ctx, amount)
     }
 
+    /// Release Reactor votes that have decayed in ve-mode
+    pub fn release_decayed_votes(ctx: Context<ReleaseDecayedVotes>, amount: u64) -> Result<()> {
+        release_decayed_votes::handler(ctx, amount)
+    }
+
+    /// Opt in or out of ve-mode voting power
+    pub fn set_ve_mode(ctx: Context<SetVeMode>, ve_mode: bool) -> Result<()> {
+        set_ve_mode::handler(ctx, ve_mode)
+    }
+
+    /// Close an empty reactor and refund its rent
+    pub fn close_reactor(ctx: Context<CloseReactor>) -> Result<()> {
+        close_reactor::handler(ctx)
+    }
+
     /// Update the reactor's global indexes
     pub fn sync_reactor(ctx: Context<SyncReactor>) -> Result<()> {
         sync_reactor::handler(ctx)
@@ -81,8 +174,18 @@ pub mod reactor {
         sync_and_collect_ray_rewards::handler(ctx)
     }
 
+    /// Fund the reward hopper with RAY to be emitted to stakers
+    pub fn fund_reward_hopper(ctx: Context<FundRewardHopper>, amount: u64) -> Result<()> {
+        fund_reward_hopper::handler(ctx, amount)
+    }
+
     /// Collect the earned RAY rewards
     pub fn collect_ray_rewards(ctx: Context<CollectRayRewards>) -> Result<()> {
         collect_ray_rewards::handler(ctx)
     }
+
+    /// Update the reactor's global indexes and restake the earned RAY rewards
+    pub fn compound_ray_rewards(ctx: Context<CompoundRayRewards>) -> Result<()> {
+        compound_ray_rewards::handler(ctx)
+    }
 }
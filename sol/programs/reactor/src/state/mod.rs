@@ -1,7 +1,9 @@
 pub mod number;
 pub mod reactor;
 pub mod reactor_config;
+pub mod vote_delegation;
 
 pub use number::*;
 pub use reactor::*;
 pub use reactor_config::*;
+pub use vote_delegation::*;
@@ -5,6 +5,19 @@ use crate::errors::ReactorErrors;
 
 use super::NumberRaw;
 
+/// Maximum number of concurrent RAY locks per reactor
+pub const MAX_RAY_LOCKS: usize = 8;
+
+/// Longest lock duration, which earns the maximum bonus
+pub const MAX_RAY_LOCK_DURATION: u64 = 4 * 365 * 86_400;
+
+/// Bonus on isoRAY accrual for a lock of the maximum duration
+/// Shorter locks earn a linearly smaller bonus
+pub const MAX_RAY_LOCK_BONUS_BPS: u16 = 10_000;
+
+/// In ve-mode, voting power only decays at checkpoints spaced by this interval
+pub const VE_CHECKPOINT_INTERVAL: u64 = 7 * 86_400;
+
 #[account]
 pub struct Reactor {
     pub owner: Pubkey,
@@ -23,6 +36,86 @@ pub struct Reactor {
 
     /// The last seen index for isoRAY accrual
     pub last_seen_index_iso_ray: NumberRaw,
+
+    /// Timestamp of the last isoRAY accrual
+    pub last_accrued_ts: u64,
+
+    /// Amount of RAY in active locks, which cannot be withdrawn
+    pub locked_ray: u64,
+
+    /// Time-locked portions of the deposited RAY
+    pub ray_locks: [RayLock; MAX_RAY_LOCKS],
+
+    /// Whether voting power is derived from RAY locks, decaying to zero at their unlock times,
+    /// instead of from RAY + isoRAY
+    pub ve_mode: bool,
+
+    /// RAY that has left the stake and is waiting out the unstake cooldown
+    pub pending_unstake: PendingUnstake,
+}
+
+/// RAY requested for unstaking, which no longer accrues isoRAY nor rewards
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, AnchorDeserialize, AnchorSerialize)]
+pub struct PendingUnstake {
+    /// Amount of RAY waiting to be released
+    pub amount: u64,
+
+    /// Timestamp at which the RAY can be released
+    /// Each new request restarts the cooldown for the whole amount
+    pub unlock_ts: u64,
+}
+
+impl PendingUnstake {
+    pub const LEN: usize =
+        // amount
+        8 +
+        // unlock_ts
+        8;
+}
+
+/// A portion of the reactor's RAY that cannot be withdrawn until `unlock_ts`
+/// An empty slot has an `amount` of 0
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, AnchorDeserialize, AnchorSerialize)]
+pub struct RayLock {
+    /// Amount of RAY locked
+    pub amount: u64,
+
+    /// Timestamp at which the lock expires
+    pub unlock_ts: u64,
+
+    /// Bonus on isoRAY accrual for the locked RAY while the lock is active
+    pub bonus_bps: u16,
+}
+
+impl RayLock {
+    pub const LEN: usize =
+        // amount
+        8 +
+        // unlock_ts
+        8 +
+        // bonus_bps
+        2;
+
+    /// Bonus for a lock of the given duration, linear up to the maximum duration
+    pub fn bonus_bps_for_duration(duration: u64) -> u16 {
+        let duration = duration.min(MAX_RAY_LOCK_DURATION);
+        (MAX_RAY_LOCK_BONUS_BPS as u64 * duration / MAX_RAY_LOCK_DURATION) as u16
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.amount == 0
+    }
+
+    /// Share of the window `[from, to]` during which the lock was active
+    fn active_ratio(&self, from: u64, to: u64) -> Number {
+        if self.unlock_ts >= to {
+            Number::ONE
+        } else if self.unlock_ts <= from {
+            Number::ZERO
+        } else {
+            Number::from_ratio((self.unlock_ts - from).into(), (to - from).into())
+        }
+    }
 }
 
 #[derive(Default, Clone, Debug, AnchorDeserialize, AnchorSerialize)]
@@ -67,25 +160,60 @@ impl Reactor {
         // ray_stake_rewards
         RayStakeRewards::LEN +
         // last_seen_index_iso_ray
-        NumberRaw::LEN;
+        NumberRaw::LEN +
+        // last_accrued_ts
+        8 +
+        // locked_ray
+        8 +
+        // ray_locks
+        RayLock::LEN * MAX_RAY_LOCKS +
+        // ve_mode
+        1 +
+        // pending_unstake
+        PendingUnstake::LEN;
 
     /// Accrue isoRAY based on the global index
-    fn accrue_iso_ray(&mut self, current_iso_ray_index: Number) -> AccrueIsoRayResult {
+    /// Locked RAY earns its bonus on top, pro-rated by how much of the elapsed time the lock was active
+    /// Locks that have expired by `now` are released afterwards
+    fn accrue_iso_ray(&mut self, current_iso_ray_index: Number, now: u64) -> AccrueIsoRayResult {
         assert!(
             current_iso_ray_index >= self.last_seen_index_iso_ray.into(),
             "current index is less than last seen index"
         );
 
         let delta = current_iso_ray_index - self.last_seen_index_iso_ray.into();
-        let iso_ray_accrued = Number::from_natural_u64(self.ray) * delta;
+        let mut iso_ray_accrued = Number::from_natural_u64(self.ray) * delta;
+
+        for lock in self.ray_locks.iter().filter(|l| !l.is_empty()) {
+            let bonus = Number::from_natural_u64(lock.amount)
+                * delta
+                * Number::from_bps(lock.bonus_bps)
+                * lock.active_ratio(self.last_accrued_ts, now);
+
+            iso_ray_accrued += bonus;
+        }
+
         let iso_ray_accrued = iso_ray_accrued.floor_u64();
 
         self.iso_ray += iso_ray_accrued;
         self.last_seen_index_iso_ray = current_iso_ray_index.into();
+        self.last_accrued_ts = self.last_accrued_ts.max(now);
+
+        self.release_expired_ray_locks(now);
 
         AccrueIsoRayResult { iso_ray_accrued }
     }
 
+    /// Free the slots of locks that have expired
+    fn release_expired_ray_locks(&mut self, now: u64) {
+        for lock in self.ray_locks.iter_mut() {
+            if !lock.is_empty() && lock.unlock_ts <= now {
+                self.locked_ray = self.locked_ray.checked_sub(lock.amount).unwrap();
+                *lock = RayLock::default();
+            }
+        }
+    }
+
     fn accrue_ray_rewards(&mut self, current_ray_reward_index: Number) {
         let delta = current_ray_reward_index - self.ray_stake_rewards.last_seen_index.into();
         // if there is no delta, there is nothing to accrue
@@ -106,8 +234,9 @@ impl Reactor {
         amount: u64,
         iso_ray_index: Number,
         ray_reward_index: Number,
+        now: u64,
     ) -> u64 {
-        self.accrue_iso_ray(iso_ray_index);
+        self.accrue_iso_ray(iso_ray_index, now);
 
         self.accrue_ray_rewards(ray_reward_index);
 
@@ -117,14 +246,15 @@ impl Reactor {
     }
 
     /// Withdraw RAY from the reactor
-    /// Cannot withdraw more than locked votes
+    /// Cannot withdraw more than locked votes, nor RAY in active locks
     pub fn withdraw_ray(
         &mut self,
         ray_decrease: u64,
         iso_ray_index: Number,
         ray_reward_index: Number,
+        now: u64,
     ) -> Result<()> {
-        self.accrue_iso_ray(iso_ray_index);
+        self.accrue_iso_ray(iso_ray_index, now);
 
         self.accrue_ray_rewards(ray_reward_index);
 
@@ -132,10 +262,15 @@ impl Reactor {
             return err!(ReactorErrors::InsufficientRayBalance);
         }
 
+        if self.unlocked_ray() < ray_decrease {
+            return err!(ReactorErrors::RayLocked);
+        }
+
         let iso_ray_decrease = self.calc_iso_ray_slash_amount(ray_decrease);
         let total_vote_decrease = ray_decrease + iso_ray_decrease;
 
-        if self.free_votes() < total_vote_decrease {
+        // in ve-mode, only locks back voting power, and locked RAY cannot be withdrawn
+        if !self.ve_mode && self.free_votes(now) < total_vote_decrease {
             return err!(ReactorErrors::InsufficientVotesToWithdraw);
         }
 
@@ -145,18 +280,116 @@ impl Reactor {
         Ok(())
     }
 
+    /// Withdraw RAY from the stake into the pending unstake slot
+    /// Follows the same rules as a withdrawal, and restarts the cooldown for the whole pending amount
+    /// Returns the pending unstake
+    pub fn request_unstake(
+        &mut self,
+        ray_decrease: u64,
+        iso_ray_index: Number,
+        ray_reward_index: Number,
+        now: u64,
+        cooldown: u64,
+    ) -> Result<PendingUnstake> {
+        self.withdraw_ray(ray_decrease, iso_ray_index, ray_reward_index, now)?;
+
+        self.pending_unstake = PendingUnstake {
+            amount: self
+                .pending_unstake
+                .amount
+                .checked_add(ray_decrease)
+                .unwrap(),
+            unlock_ts: now.checked_add(cooldown).unwrap(),
+        };
+
+        Ok(self.pending_unstake)
+    }
+
+    /// Zero out the pending unstake once its cooldown has passed
+    /// Returns the amount of RAY released
+    pub fn complete_unstake(&mut self, now: u64) -> Result<u64> {
+        if self.pending_unstake.amount == 0 {
+            return err!(ReactorErrors::NoPendingUnstake);
+        }
+
+        if now < self.pending_unstake.unlock_ts {
+            return err!(ReactorErrors::UnstakeCooldownActive);
+        }
+
+        let amount = self.pending_unstake.amount;
+        self.pending_unstake = PendingUnstake::default();
+
+        Ok(amount)
+    }
+
+    /// Lock deposited RAY until `now + duration`, after accruing isoRAY
+    /// Cannot lock more than the RAY that is not already locked
+    /// Returns the new lock
+    pub fn lock_ray(
+        &mut self,
+        amount: u64,
+        duration: u64,
+        iso_ray_index: Number,
+        ray_reward_index: Number,
+        now: u64,
+    ) -> Result<RayLock> {
+        self.accrue_iso_ray(iso_ray_index, now);
+
+        self.accrue_ray_rewards(ray_reward_index);
+
+        if duration == 0 || duration > MAX_RAY_LOCK_DURATION {
+            return err!(ReactorErrors::InvalidRayLockDuration);
+        }
+
+        if amount == 0 || self.unlocked_ray() < amount {
+            return err!(ReactorErrors::InsufficientRayToLock);
+        }
+
+        let Some(slot) = self.ray_locks.iter_mut().find(|l| l.is_empty()) else {
+            return err!(ReactorErrors::TooManyRayLocks);
+        };
+
+        *slot = RayLock {
+            amount,
+            unlock_ts: now.checked_add(duration).unwrap(),
+            bonus_bps: RayLock::bonus_bps_for_duration(duration),
+        };
+        let lock = *slot;
+
+        self.locked_ray = self.locked_ray.checked_add(amount).unwrap();
+
+        Ok(lock)
+    }
+
+    /// RAY that is not held in an active lock
+    pub fn unlocked_ray(&self) -> u64 {
+        self.ray - self.locked_ray
+    }
+
+    /// Switch between RAY + isoRAY voting power and decaying ve-mode voting power
+    /// Can only switch while no votes are locked, so gauge votes always match the active mode
+    pub fn set_ve_mode(&mut self, ve_mode: bool) -> Result<()> {
+        if self.locked_votes > 0 {
+            return err!(ReactorErrors::VotesLockedDuringModeChange);
+        }
+
+        self.ve_mode = ve_mode;
+
+        Ok(())
+    }
+
     /// Lock votes
     /// Cannot lock more than free votes
     /// Returns the new locked votes amount
-    pub fn lock_votes(&mut self, amount: u64) -> Result<u64> {
-        if self.free_votes() < amount {
+    pub fn lock_votes(&mut self, amount: u64, now: u64) -> Result<u64> {
+        if self.free_votes(now) < amount {
             return err!(ReactorErrors::InsufficientVotesToLock);
         }
 
         self.locked_votes = self.locked_votes.checked_add(amount).unwrap();
 
         // redundant check
-        assert!(self.locked_votes <= self.vote_power());
+        assert!(self.locked_votes <= self.vote_power(now));
 
         Ok(self.locked_votes)
     }
@@ -175,13 +408,53 @@ impl Reactor {
     }
 
     /// Vote power is the sum of RAY and isoRAY
-    pub fn vote_power(&self) -> u64 {
-        self.ray.checked_add(self.iso_ray).unwrap()
+    /// In ve-mode, it is the decayed power of the RAY locks instead
+    pub fn vote_power(&self, now: u64) -> u64 {
+        if self.ve_mode {
+            self.ve_vote_power(now)
+        } else {
+            self.ray.checked_add(self.iso_ray).unwrap()
+        }
+    }
+
+    /// Each lock is worth its amount when locked for the maximum duration,
+    /// decaying linearly to zero at its unlock time
+    /// The decay is evaluated at the latest checkpoint, so power only drops once per interval
+    pub fn ve_vote_power(&self, now: u64) -> u64 {
+        let checkpoint = now - now % VE_CHECKPOINT_INTERVAL;
+
+        let power: u128 = self
+            .ray_locks
+            .iter()
+            .filter(|l| !l.is_empty() && l.unlock_ts > checkpoint)
+            .map(|l| {
+                let remaining = (l.unlock_ts - checkpoint).min(MAX_RAY_LOCK_DURATION);
+                l.amount as u128 * remaining as u128 / MAX_RAY_LOCK_DURATION as u128
+            })
+            .sum();
+
+        power.try_into().unwrap()
     }
 
     /// Free votes are the vote power minus locked votes
-    pub fn free_votes(&self) -> u64 {
-        self.vote_power() - self.locked_votes
+    pub fn free_votes(&self, now: u64) -> u64 {
+        self.vote_power(now).saturating_sub(self.locked_votes)
+    }
+
+    /// Locked votes that are no longer backed by vote power, after ve-mode decay
+    pub fn decayed_votes(&self, now: u64) -> u64 {
+        self.locked_votes.saturating_sub(self.vote_power(now))
+    }
+
+    /// Release locked votes that have decayed
+    /// Cannot release more than the decayed votes
+    /// Returns the new locked votes amount
+    pub fn release_decayed_votes(&mut self, amount: u64, now: u64) -> Result<u64> {
+        if self.decayed_votes(now) < amount {
+            return err!(ReactorErrors::InsufficientDecayedVotes);
+        }
+
+        self.unlock_votes(amount)
     }
 
     /// Calculate the amount of isoRAY to slash
@@ -189,12 +462,41 @@ impl Reactor {
         iso_ray_slash_amount(self.ray, ray_decrease, self.iso_ray)
     }
 
+    /// Nothing is left in the reactor: no RAY, isoRAY, locked votes, unstaking RAY or uncollected rewards
+    pub fn is_empty(&self) -> bool {
+        self.ray == 0
+            && self.iso_ray == 0
+            && self.locked_votes == 0
+            && self.pending_unstake.amount == 0
+            && self.ray_stake_rewards.uncollected_ray_reward == 0
+    }
+
     /// Zero out and collect the earned RAY rewards
     pub fn collect_ray_rewards(&mut self) -> u64 {
         let ray = self.ray_stake_rewards.uncollected_ray_reward;
         self.ray_stake_rewards.uncollected_ray_reward = 0;
         ray
     }
+
+    /// Accrue up to the given indexes, then deposit the earned RAY rewards back into the reactor
+    /// The indexes must already include the compounded amount's deposit
+    /// Returns the amount of RAY compounded
+    pub fn compound_ray_rewards(
+        &mut self,
+        iso_ray_index: Number,
+        ray_reward_index: Number,
+        now: u64,
+    ) -> u64 {
+        self.accrue_iso_ray(iso_ray_index, now);
+
+        self.accrue_ray_rewards(ray_reward_index);
+
+        let ray = self.collect_ray_rewards();
+
+        self.ray = self.ray.checked_add(ray).unwrap();
+
+        ray
+    }
 }
 
 fn iso_ray_slash_ratio(ray_balance: u64, ray_decrease: u64) -> Number {
@@ -232,6 +534,11 @@ mod test_reactor {
                 last_seen_index: Number::ZERO.into(),
                 uncollected_ray_reward: 0,
             },
+            last_accrued_ts: 0,
+            locked_ray: 0,
+            ray_locks: Default::default(),
+            ve_mode: false,
+            pending_unstake: PendingUnstake::default(),
         }
     }
 
@@ -316,7 +623,7 @@ mod test_reactor {
 
         let amount = 100;
 
-        let result = reactor.deposit_ray(amount, Number::ZERO, Number::ZERO);
+        let result = reactor.deposit_ray(amount, Number::ZERO, Number::ZERO, 0);
         assert_eq!(result, amount);
         assert_eq!(reactor.ray, amount);
         assert_eq!(reactor.iso_ray, 0);
@@ -331,7 +638,7 @@ mod test_reactor {
         let amount = 50;
 
         reactor
-            .withdraw_ray(amount, Number::ZERO, Number::ZERO)
+            .withdraw_ray(amount, Number::ZERO, Number::ZERO, 0)
             .unwrap();
         assert_eq!(reactor.ray, 50);
         assert_eq!(reactor.iso_ray, 50);
@@ -345,7 +652,7 @@ mod test_reactor {
 
         let amount = 150;
 
-        let result = reactor.withdraw_ray(amount, Number::ZERO, Number::ZERO);
+        let result = reactor.withdraw_ray(amount, Number::ZERO, Number::ZERO, 0);
         assert!(result.is_err());
         assert_eq!(
             result.unwrap_err(),
@@ -363,7 +670,7 @@ mod test_reactor {
 
         let current_iso_ray_index = Number::from_natural_u64(2);
 
-        let result = reactor.accrue_iso_ray(current_iso_ray_index);
+        let result = reactor.accrue_iso_ray(current_iso_ray_index, 0);
         assert_eq!(result.iso_ray_accrued, 200);
         assert_eq!(reactor.iso_ray, 200);
     }
@@ -379,4 +686,260 @@ mod test_reactor {
         let iso_ray_decrease = reactor.calc_iso_ray_slash_amount(amount);
         assert_eq!(iso_ray_decrease, 50);
     }
+
+    #[test]
+    fn test_lock_ray() {
+        let mut reactor = setup_reactor();
+        reactor.ray = 100;
+
+        let lock = reactor
+            .lock_ray(
+                60,
+                MAX_RAY_LOCK_DURATION / 2,
+                Number::ZERO,
+                Number::ZERO,
+                10,
+            )
+            .unwrap();
+        assert_eq!(lock.amount, 60);
+        assert_eq!(lock.unlock_ts, 10 + MAX_RAY_LOCK_DURATION / 2);
+        assert_eq!(lock.bonus_bps, MAX_RAY_LOCK_BONUS_BPS / 2);
+        assert_eq!(reactor.locked_ray, 60);
+        assert_eq!(reactor.unlocked_ray(), 40);
+
+        // cannot lock more than the unlocked RAY
+        let result = reactor.lock_ray(41, 1, Number::ZERO, Number::ZERO, 10);
+        assert_eq!(
+            result.unwrap_err(),
+            ReactorErrors::InsufficientRayToLock.into()
+        );
+
+        // lock duration is bounded
+        let result = reactor.lock_ray(1, 0, Number::ZERO, Number::ZERO, 10);
+        assert_eq!(
+            result.unwrap_err(),
+            ReactorErrors::InvalidRayLockDuration.into()
+        );
+        let result = reactor.lock_ray(1, MAX_RAY_LOCK_DURATION + 1, Number::ZERO, Number::ZERO, 10);
+        assert_eq!(
+            result.unwrap_err(),
+            ReactorErrors::InvalidRayLockDuration.into()
+        );
+    }
+
+    #[test]
+    fn test_too_many_ray_locks() {
+        let mut reactor = setup_reactor();
+        reactor.ray = 100;
+
+        for _ in 0..MAX_RAY_LOCKS {
+            reactor
+                .lock_ray(1, 100, Number::ZERO, Number::ZERO, 0)
+                .unwrap();
+        }
+
+        let result = reactor.lock_ray(1, 100, Number::ZERO, Number::ZERO, 0);
+        assert_eq!(result.unwrap_err(), ReactorErrors::TooManyRayLocks.into());
+
+        // expired locks free their slots
+        reactor
+            .lock_ray(1, 100, Number::ZERO, Number::ZERO, 100)
+            .unwrap();
+        assert_eq!(reactor.locked_ray, 1);
+    }
+
+    #[test]
+    fn test_cannot_withdraw_locked_ray() {
+        let mut reactor = setup_reactor();
+        reactor.ray = 100;
+
+        reactor
+            .lock_ray(60, 100, Number::ZERO, Number::ZERO, 0)
+            .unwrap();
+
+        let result = reactor.withdraw_ray(41, Number::ZERO, Number::ZERO, 99);
+        assert_eq!(result.unwrap_err(), ReactorErrors::RayLocked.into());
+
+        reactor
+            .withdraw_ray(40, Number::ZERO, Number::ZERO, 99)
+            .unwrap();
+
+        // the lock has expired
+        reactor
+            .withdraw_ray(60, Number::ZERO, Number::ZERO, 100)
+            .unwrap();
+        assert_eq!(reactor.ray, 0);
+        assert_eq!(reactor.locked_ray, 0);
+    }
+
+    #[test]
+    fn test_locked_ray_boosts_iso_ray() {
+        let mut reactor = setup_reactor();
+        reactor.ray = 100;
+
+        // half of the RAY is locked for the max duration, doubling its accrual
+        reactor
+            .lock_ray(50, MAX_RAY_LOCK_DURATION, Number::ZERO, Number::ZERO, 0)
+            .unwrap();
+
+        let result = reactor.accrue_iso_ray(Number::from_natural_u64(1), 100);
+        assert_eq!(result.iso_ray_accrued, 100 + 50);
+    }
+
+    #[test]
+    fn test_lock_bonus_pro_rated_at_expiry() {
+        let mut reactor = setup_reactor();
+        reactor.ray = 100;
+
+        reactor
+            .lock_ray(100, MAX_RAY_LOCK_DURATION, Number::ZERO, Number::ZERO, 0)
+            .unwrap();
+
+        // the lock was active for the first half of the accrual window
+        let result = reactor.accrue_iso_ray(Number::from_natural_u64(2), 2 * MAX_RAY_LOCK_DURATION);
+        assert_eq!(result.iso_ray_accrued, 200 + 100);
+        assert_eq!(reactor.locked_ray, 0);
+
+        // no more bonus once released
+        let result = reactor.accrue_iso_ray(Number::from_natural_u64(3), 3 * MAX_RAY_LOCK_DURATION);
+        assert_eq!(result.iso_ray_accrued, 100);
+    }
+
+    #[test]
+    fn test_ve_vote_power_decays_at_checkpoints() {
+        let mut reactor = setup_reactor();
+        reactor.ray = 100;
+        reactor.iso_ray = 100;
+        reactor.set_ve_mode(true).unwrap();
+
+        // no locks, no power
+        assert_eq!(reactor.vote_power(0), 0);
+
+        reactor
+            .lock_ray(100, MAX_RAY_LOCK_DURATION, Number::ZERO, Number::ZERO, 0)
+            .unwrap();
+        assert_eq!(reactor.vote_power(0), 100);
+
+        // the power holds until the next checkpoint
+        assert_eq!(reactor.vote_power(VE_CHECKPOINT_INTERVAL - 1), 100);
+
+        // a quarter of the way, a quarter of the power is gone
+        let ts = MAX_RAY_LOCK_DURATION / 4;
+        let checkpoint = ts - ts % VE_CHECKPOINT_INTERVAL;
+        let expected = 100 * (MAX_RAY_LOCK_DURATION - checkpoint) / MAX_RAY_LOCK_DURATION;
+        assert_eq!(reactor.vote_power(ts), expected);
+
+        // nothing left at expiry
+        assert_eq!(reactor.vote_power(MAX_RAY_LOCK_DURATION), 0);
+    }
+
+    #[test]
+    fn test_release_decayed_votes() {
+        let mut reactor = setup_reactor();
+        reactor.ray = 100;
+        reactor.set_ve_mode(true).unwrap();
+
+        reactor
+            .lock_ray(100, MAX_RAY_LOCK_DURATION, Number::ZERO, Number::ZERO, 0)
+            .unwrap();
+        reactor.lock_votes(100, 0).unwrap();
+        assert_eq!(reactor.decayed_votes(0), 0);
+
+        // cannot switch modes while votes are locked
+        assert_eq!(
+            reactor.set_ve_mode(false).unwrap_err(),
+            ReactorErrors::VotesLockedDuringModeChange.into()
+        );
+
+        let now = MAX_RAY_LOCK_DURATION / 2;
+        let decayed = reactor.decayed_votes(now);
+        assert_eq!(decayed, 100 - reactor.vote_power(now));
+        assert_eq!(reactor.free_votes(now), 0);
+
+        // cannot release more than what has decayed
+        assert_eq!(
+            reactor.release_decayed_votes(decayed + 1, now).unwrap_err(),
+            ReactorErrors::InsufficientDecayedVotes.into()
+        );
+
+        reactor.release_decayed_votes(decayed, now).unwrap();
+        assert_eq!(reactor.locked_votes, reactor.vote_power(now));
+        assert_eq!(reactor.decayed_votes(now), 0);
+    }
+
+    #[test]
+    fn test_unstake_cooldown() {
+        let mut reactor = setup_reactor();
+        reactor.ray = 100;
+        reactor.iso_ray = 100;
+
+        let pending = reactor
+            .request_unstake(50, Number::ZERO, Number::ZERO, 10, 100)
+            .unwrap();
+        assert_eq!(pending.amount, 50);
+        assert_eq!(pending.unlock_ts, 110);
+        assert_eq!(reactor.ray, 50);
+        assert_eq!(reactor.iso_ray, 50);
+
+        // pending RAY no longer accrues isoRAY
+        let result = reactor.accrue_iso_ray(Number::from_natural_u64(1), 20);
+        assert_eq!(result.iso_ray_accrued, 50);
+
+        // a new request restarts the cooldown for the whole amount
+        let pending = reactor
+            .request_unstake(10, Number::from_natural_u64(1), Number::ZERO, 50, 100)
+            .unwrap();
+        assert_eq!(pending.amount, 60);
+        assert_eq!(pending.unlock_ts, 150);
+
+        assert_eq!(
+            reactor.complete_unstake(149).unwrap_err(),
+            ReactorErrors::UnstakeCooldownActive.into()
+        );
+        assert_eq!(reactor.complete_unstake(150).unwrap(), 60);
+        assert_eq!(
+            reactor.complete_unstake(150).unwrap_err(),
+            ReactorErrors::NoPendingUnstake.into()
+        );
+    }
+
+    #[test]
+    fn test_compound_ray_rewards() {
+        let mut reactor = setup_reactor();
+        reactor.ray = 100;
+        reactor.ray_stake_rewards.uncollected_ray_reward = 5;
+
+        let compounded = reactor.compound_ray_rewards(
+            Number::from_natural_u64(1),
+            Number::from_ratio(1, 10),
+            10,
+        );
+
+        // rewards and isoRAY are accrued on the balance before compounding
+        assert_eq!(compounded, 5 + 10);
+        assert_eq!(reactor.ray, 100 + 15);
+        assert_eq!(reactor.iso_ray, 100);
+        assert_eq!(reactor.ray_stake_rewards.uncollected_ray_reward, 0);
+    }
+
+    #[test]
+    fn test_is_empty() {
+        let mut reactor = setup_reactor();
+        assert!(reactor.is_empty());
+
+        reactor.ray = 100;
+        reactor.iso_ray = 10;
+        reactor.ray_stake_rewards.uncollected_ray_reward = 5;
+        assert!(!reactor.is_empty());
+
+        // withdrawing all RAY slashes all isoRAY, but leaves the rewards to collect
+        reactor
+            .withdraw_ray(100, Number::ZERO, Number::ZERO, 0)
+            .unwrap();
+        assert_eq!(reactor.iso_ray, 0);
+        assert!(!reactor.is_empty());
+
+        reactor.collect_ray_rewards();
+        assert!(reactor.is_empty());
+    }
 }
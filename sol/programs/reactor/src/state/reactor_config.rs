@@ -1,4 +1,4 @@
-use crate::{state::NumberRaw, REACTOR_CONFIG_SEED};
+use crate::{errors::ReactorErrors, state::NumberRaw, REACTOR_CONFIG_SEED};
 use anchor_lang::prelude::*;
 use precise_number::Number;
 
@@ -7,6 +7,17 @@ use super::Rewarder;
 const SECONDS_IN_DAY: u64 = 86_400;
 const SECONDS_IN_YEAR: u64 = SECONDS_IN_DAY * 365;
 
+/// Pause flag for RAY deposits
+pub const PAUSE_DEPOSIT: u8 = 1 << 0;
+/// Pause flag for principal RAY withdrawals
+/// Kept separate so that users can still exit while other actions are paused
+pub const PAUSE_WITHDRAW: u8 = 1 << 1;
+/// Pause flag for collecting RAY rewards
+pub const PAUSE_CLAIM: u8 = 1 << 3;
+/// All pause flags recognized by the reactor
+/// Vote locking is paused from the gauge program, where votes are pledged
+pub const PAUSE_ALL: u8 = PAUSE_DEPOSIT | PAUSE_WITHDRAW | PAUSE_CLAIM;
+
 #[derive(Default)]
 #[account]
 pub struct ReactorConfig {
@@ -40,6 +51,26 @@ pub struct ReactorConfig {
 
     /// Bump seed for the PDA
     pub bump: [u8; 1],
+
+    /// Authority for admin-gated instructions
+    pub admin: Pubkey,
+
+    /// Admin proposed by the current admin, pending acceptance
+    pub pending_admin: Pubkey,
+
+    /// Bitmask of paused actions
+    pub pause_flags: u8,
+
+    /// Lifetime RAY funded into the reward hopper
+    pub total_ray_funded: u64,
+
+    /// Lifetime RAY emitted through the reward index
+    /// Never exceeds `total_ray_funded`
+    pub total_ray_emitted: u64,
+
+    /// Seconds between requesting an unstake and being able to complete it
+    /// While non-zero, RAY can only leave through the unstake queue
+    pub unstake_cooldown: u64,
 }
 
 impl ReactorConfig {
@@ -75,12 +106,62 @@ impl ReactorConfig {
         NumberRaw::LEN +
 
         // bump
-        1;
+        1 +
+
+        // admin
+        32 +
+
+        // pending_admin
+        32 +
+
+        // pause_flags
+        1 +
+
+        // total_ray_funded
+        8 +
+
+        // total_ray_emitted
+        8 +
+
+        // unstake_cooldown
+        8;
 
     pub fn seeds(&self) -> [&[u8]; 2] {
         [REACTOR_CONFIG_SEED.as_bytes(), &self.bump]
     }
 
+    /// Propose a new admin, who must accept before taking over
+    pub fn propose_admin(&mut self, new_admin: Pubkey) {
+        self.pending_admin = new_admin;
+    }
+
+    /// Promote the pending admin to admin
+    /// Returns the previous admin
+    pub fn accept_admin(&mut self) -> Pubkey {
+        let old = self.admin;
+        self.admin = self.pending_admin;
+        self.pending_admin = Pubkey::default();
+        old
+    }
+
+    /// Whether any of the given pause flags are set
+    pub fn is_paused(&self, flags: u8) -> bool {
+        self.pause_flags & flags != 0
+    }
+
+    /// Replace the pause flags, rejecting unknown bits
+    /// Returns the previous pause flags
+    pub fn set_pause_flags(&mut self, pause_flags: u8) -> Result<u8> {
+        require!(
+            pause_flags & !PAUSE_ALL == 0,
+            ReactorErrors::InvalidPauseFlags
+        );
+
+        let old = self.pause_flags;
+        self.pause_flags = pause_flags;
+        Ok(old)
+    }
+
     pub fn deposit_ray(&mut self, amount: u64, current_ts: u64) {
         self.accrue_rewards(current_ts);
 
@@ -99,6 +180,49 @@ impl ReactorConfig {
             .expect("total ray deposited underflow");
     }
 
+    /// Settle both indexes at the current rate, then change the RAY reward daily emission
+    /// Returns the previous daily emission
+    pub fn set_ray_reward_daily_emission(
+        &mut self,
+        ray_reward_daily_emission: u64,
+        current_ts: u64,
+    ) -> u64 {
+        self.accrue_rewards(current_ts);
+
+        let old = self.ray_reward_daily_emission;
+        self.ray_reward_daily_emission = ray_reward_daily_emission;
+        old
+    }
+
+    /// Settle both indexes at the current rate, then change the isoRAY APR
+    /// Returns the previous APR in basis points
+    pub fn set_iso_ray_apr_bps(&mut self, iso_ray_apr_bps: u16, current_ts: u64) -> u16 {
+        self.accrue_rewards(current_ts);
+
+        let old = self.iso_ray_apr_bps;
+        self.iso_ray_apr_bps = iso_ray_apr_bps;
+        old
+    }
+
+    /// Settle the indexes, then record RAY funded into the reward hopper
+    /// Time that passed while the hopper was empty is not back-paid
+    /// Returns the new lifetime funded amount
+    pub fn fund_ray_rewards(&mut self, amount: u64, current_ts: u64) -> u64 {
+        self.accrue_rewards(current_ts);
+
+        self.total_ray_funded = self
+            .total_ray_funded
+            .checked_add(amount)
+            .expect("total ray funded overflow");
+
+        self.total_ray_funded
+    }
+
+    /// RAY in the hopper that has not been promised to stakers yet
+    pub fn unemitted_ray(&self) -> u64 {
+        self.total_ray_funded - self.total_ray_emitted
+    }
+
     fn accrue_rewards(&mut self, current_ts: u64) {
         if self.rewards_emitted_until >= current_ts {
             msg!("Time has not passed, skipping");
@@ -134,6 +258,12 @@ impl ReactorConfig {
         // RAY emission
         let ray_emission = Number::from_natural_u64(self.ray_reward_daily_emission) * duration_day;
 
+        // cannot emit more than has been funded
+        let ray_emission = ray_emission.min(Number::from_natural_u64(self.unemitted_ray()));
+
+        // round up, so the recorded emission never understates what the index promises
+        self.total_ray_emitted += ray_emission.ceil().floor_u64();
+
         let total_shares = Number::from_natural_u64(total_shares);
 
         // calculate RAY per share
@@ -183,6 +313,7 @@ mod tests {
     fn test_emit_ray_reward() {
         let mut reactor_config = ReactorConfig::default();
         reactor_config.ray_reward_daily_emission = 100_000;
+        reactor_config.total_ray_funded = 1_000_000;
 
         // 0 RAY deposited, 0 time elapsed
         reactor_config.emit_ray_reward(0);
@@ -208,6 +339,57 @@ mod tests {
             Number::from_natural_u64(150_000),
             reactor_config.ray_reward_index.into()
         );
+        assert_eq!(reactor_config.total_ray_emitted, 200_000);
+    }
+
+    #[test]
+    fn test_emit_ray_reward_capped_at_funded() {
+        let mut reactor_config = ReactorConfig::default();
+        reactor_config.ray_reward_daily_emission = 100_000;
+        reactor_config.total_ray_deposited = 2;
+
+        // nothing funded, nothing emitted
+        reactor_config.emit_ray_reward(SECONDS_IN_DAY);
+        assert_eq!(Number::ZERO, reactor_config.ray_reward_index.into());
+        assert_eq!(reactor_config.total_ray_emitted, 0);
+
+        // only 30_000 of the 100_000 daily emission is funded
+        reactor_config.total_ray_funded = 30_000;
+        reactor_config.emit_ray_reward(SECONDS_IN_DAY);
+        assert_eq!(
+            Number::from_natural_u64(15_000),
+            reactor_config.ray_reward_index.into()
+        );
+        assert_eq!(reactor_config.total_ray_emitted, 30_000);
+        assert_eq!(reactor_config.unemitted_ray(), 0);
+
+        // the hopper is dry, the index stops
+        reactor_config.emit_ray_reward(SECONDS_IN_DAY);
+        assert_eq!(
+            Number::from_natural_u64(15_000),
+            reactor_config.ray_reward_index.into()
+        );
+    }
+
+    #[test]
+    fn test_fund_ray_rewards_does_not_back_pay() {
+        let mut reactor_config = ReactorConfig::default();
+        reactor_config.ray_reward_daily_emission = 100;
+        reactor_config.total_ray_deposited = 1;
+
+        // a day passes with an empty hopper
+        let total_funded = reactor_config.fund_ray_rewards(1_000, SECONDS_IN_DAY);
+        assert_eq!(total_funded, 1_000);
+        assert_eq!(reactor_config.rewards_emitted_until, SECONDS_IN_DAY);
+        assert_eq!(Number::ZERO, reactor_config.ray_reward_index.into());
+
+        // the next day is paid from the funds
+        reactor_config.fund_ray_rewards(0, 2 * SECONDS_IN_DAY);
+        assert_eq!(
+            Number::from_natural_u64(100),
+            reactor_config.ray_reward_index.into()
+        );
+        assert_eq!(reactor_config.total_ray_emitted, 100);
     }
 
     #[test]
@@ -240,4 +422,114 @@ mod tests {
         reactor_config.accrue_iso_ray(SECONDS_IN_YEAR);
         assert_eq!(Number::ONE, reactor_config.iso_ray_index.into());
     }
+
+    #[test]
+    fn test_set_rates_settles_at_old_rate() {
+        let mut reactor_config = ReactorConfig::default();
+        reactor_config.ray_reward_daily_emission = 100;
+        // 50% APR
+        reactor_config.iso_ray_apr_bps = 50_00;
+        reactor_config.total_ray_deposited = 1;
+        reactor_config.total_ray_funded = 1_000;
+
+        // 1 day passes at the old emission
+        let old = reactor_config.set_ray_reward_daily_emission(200, SECONDS_IN_DAY);
+        assert_eq!(old, 100);
+        assert_eq!(reactor_config.ray_reward_daily_emission, 200);
+        assert_eq!(reactor_config.rewards_emitted_until, SECONDS_IN_DAY);
+        assert_eq!(
+            Number::from_natural_u64(100),
+            reactor_config.ray_reward_index.into()
+        );
+
+        // 1 more day passes at the new emission, settled by the APR change
+        let old = reactor_config.set_iso_ray_apr_bps(100_00, 2 * SECONDS_IN_DAY);
+        assert_eq!(old, 50_00);
+        assert_eq!(reactor_config.iso_ray_apr_bps, 100_00);
+        assert_eq!(
+            Number::from_natural_u64(300),
+            reactor_config.ray_reward_index.into()
+        );
+
+        // isoRAY accrued for 2 days at the old APR, settled one day at a time
+        let one_day_iso_ray = Number::from_ratio(SECONDS_IN_DAY.into(), SECONDS_IN_YEAR.into())
+            * Number::from_bps(50_00);
+        let expected_iso_ray_index = one_day_iso_ray + one_day_iso_ray;
+        assert_eq!(expected_iso_ray_index, reactor_config.iso_ray_index.into());
+    }
+
+    #[test]
+    fn test_admin_transfer() {
+        let mut reactor_config = ReactorConfig::default();
+        let admin = Pubkey::new_unique();
+        let new_admin = Pubkey::new_unique();
+        reactor_config.admin = admin;
+
+        // proposing does not hand over control
+        reactor_config.propose_admin(new_admin);
+        assert_eq!(reactor_config.admin, admin);
+        assert_eq!(reactor_config.pending_admin, new_admin);
+
+        let old = reactor_config.accept_admin();
+        assert_eq!(old, admin);
+        assert_eq!(reactor_config.admin, new_admin);
+        assert_eq!(reactor_config.pending_admin, Pubkey::default());
+    }
+
+    #[test]
+    fn test_set_pause_flags() {
+        let mut reactor_config = ReactorConfig::default();
+
+        assert_eq!(reactor_config.set_pause_flags(PAUSE_ALL).unwrap(), 0);
+        assert_eq!(reactor_config.set_pause_flags(0).unwrap(), PAUSE_ALL);
+
+        // vote locking is paused from the gauge program
+        assert!(reactor_config.set_pause_flags(1 << 2).is_err());
+        assert_eq!(reactor_config.pause_flags, 0);
+    }
+
+    #[test]
+    fn test_pause_deposit() {
+        let mut reactor_config = ReactorConfig::default();
+        reactor_config.set_pause_flags(PAUSE_DEPOSIT).unwrap();
+
+        // deposit_ray, lock_ray and compound_ray_rewards are rejected
+        assert!(reactor_config.is_paused(PAUSE_DEPOSIT));
+        assert!(reactor_config.is_paused(PAUSE_CLAIM | PAUSE_DEPOSIT));
+
+        // unstaking, withdrawing and collecting stay open
+        assert!(!reactor_config.is_paused(PAUSE_WITHDRAW));
+        assert!(!reactor_config.is_paused(PAUSE_CLAIM));
+        reactor_config.deposit_ray(100, 0);
+        reactor_config.withdraw_ray(100, 1);
+        assert_eq!(reactor_config.total_ray_deposited, 0);
+    }
+
+    #[test]
+    fn test_pause_withdraw() {
+        let mut reactor_config = ReactorConfig::default();
+        reactor_config.set_pause_flags(PAUSE_WITHDRAW).unwrap();
+
+        // request_unstake, complete_unstake and withdraw_ray are rejected
+        assert!(reactor_config.is_paused(PAUSE_WITHDRAW));
+
+        // depositing, compounding and collecting stay open
+        assert!(!reactor_config.is_paused(PAUSE_DEPOSIT));
+        assert!(!reactor_config.is_paused(PAUSE_CLAIM));
+        assert!(!reactor_config.is_paused(PAUSE_CLAIM | PAUSE_DEPOSIT));
+    }
+
+    #[test]
+    fn test_pause_claim() {
+        let mut reactor_config = ReactorConfig::default();
+        reactor_config.set_pause_flags(PAUSE_CLAIM).unwrap();
+
+        // collect_ray_rewards, sync_and_collect_ray_rewards and compound_ray_rewards are rejected
+        assert!(reactor_config.is_paused(PAUSE_CLAIM));
+        assert!(reactor_config.is_paused(PAUSE_CLAIM | PAUSE_DEPOSIT));
+
+        // principal can still be deposited, unstaked and withdrawn
+        assert!(!reactor_config.is_paused(PAUSE_DEPOSIT));
+        assert!(!reactor_config.is_paused(PAUSE_WITHDRAW));
+    }
 }
@@ -0,0 +1,173 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ReactorErrors;
+
+/// Maximum number of gauges a delegation can be restricted to
+pub const MAX_DELEGATION_GAUGES: usize = 8;
+
+/// Authorizes a delegate to lock and unlock a reactor's votes through the gauge program
+/// Created by the reactor owner, one per reactor
+#[account]
+pub struct VoteDelegation {
+    /// Reactor whose votes are delegated
+    pub reactor: Pubkey,
+
+    /// Wallet allowed to allocate the reactor's votes
+    pub delegate: Pubkey,
+
+    /// Timestamp after which the delegation no longer applies
+    /// 0 means the delegation never expires
+    pub expires_at: u64,
+
+    /// Number of gauges in the allow-list
+    /// 0 means the delegate may vote on any gauge
+    pub num_allowed_gauges: u8,
+
+    /// Pool gauges the delegate may change votes on
+    pub allowed_gauges: [Pubkey; MAX_DELEGATION_GAUGES],
+}
+
+impl VoteDelegation {
+    pub const LEN: usize =
+        // discriminator
+        8 +
+        // reactor
+        32 +
+        // delegate
+        32 +
+        // expires_at
+        8 +
+        // num_allowed_gauges
+        1 +
+        // allowed_gauges
+        32 * MAX_DELEGATION_GAUGES;
+
+    /// Set the delegation parameters
+    /// Fails if the allow-list is too long
+    pub fn set(
+        &mut self,
+        reactor: Pubkey,
+        delegate: Pubkey,
+        expires_at: u64,
+        allowed_gauges: &[Pubkey],
+    ) -> Result<()> {
+        if allowed_gauges.len() > MAX_DELEGATION_GAUGES {
+            return err!(ReactorErrors::TooManyDelegationGauges);
+        }
+
+        self.reactor = reactor;
+        self.delegate = delegate;
+        self.expires_at = expires_at;
+        self.num_allowed_gauges = allowed_gauges.len() as u8;
+        self.allowed_gauges = [Pubkey::default(); MAX_DELEGATION_GAUGES];
+        self.allowed_gauges[..allowed_gauges.len()].copy_from_slice(allowed_gauges);
+
+        Ok(())
+    }
+
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.expires_at != 0 && now > self.expires_at
+    }
+
+    /// Whether `signer` may act as the delegate at `now`
+    pub fn authorizes(&self, signer: Pubkey, now: u64) -> bool {
+        self.delegate == signer && !self.is_expired(now)
+    }
+
+    /// Whether the delegate may change votes on the given pool gauge
+    pub fn allows_gauge(&self, pool_gauge: Pubkey) -> bool {
+        let allowed = &self.allowed_gauges[..self.num_allowed_gauges as usize];
+        allowed.is_empty() || allowed.contains(&pool_gauge)
+    }
+}
+
+/// Check that `authority` is either the reactor owner, or its unexpired delegate
+pub fn check_vote_authority(
+    reactor_owner: Pubkey,
+    authority: Pubkey,
+    vote_delegation: Option<&VoteDelegation>,
+    now: u64,
+) -> Result<()> {
+    if authority == reactor_owner {
+        return Ok(());
+    }
+
+    match vote_delegation {
+        Some(d) if d.authorizes(authority, now) => Ok(()),
+        _ => err!(ReactorErrors::NotVoteAuthority),
+    }
+}
+
+#[cfg(test)]
+mod test_vote_delegation {
+    use super::*;
+
+    fn setup_delegation(expires_at: u64, allowed_gauges: &[Pubkey]) -> VoteDelegation {
+        let mut d = VoteDelegation {
+            reactor: Pubkey::default(),
+            delegate: Pubkey::default(),
+            expires_at: 0,
+            num_allowed_gauges: 0,
+            allowed_gauges: [Pubkey::default(); MAX_DELEGATION_GAUGES],
+        };
+        d.set(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            expires_at,
+            allowed_gauges,
+        )
+        .unwrap();
+        d
+    }
+
+    #[test]
+    fn test_delegation_expiry() {
+        let d = setup_delegation(100, &[]);
+
+        assert!(d.authorizes(d.delegate, 100));
+        assert!(!d.authorizes(d.delegate, 101));
+        assert!(!d.authorizes(Pubkey::new_unique(), 0));
+
+        // no expiry
+        let d = setup_delegation(0, &[]);
+        assert!(d.authorizes(d.delegate, u64::MAX));
+    }
+
+    #[test]
+    fn test_delegation_allow_list() {
+        let gauge = Pubkey::new_unique();
+
+        // an empty allow-list allows any gauge
+        let d = setup_delegation(0, &[]);
+        assert!(d.allows_gauge(gauge));
+
+        let d = setup_delegation(0, &[gauge]);
+        assert!(d.allows_gauge(gauge));
+        assert!(!d.allows_gauge(Pubkey::new_unique()));
+
+        let mut d = setup_delegation(0, &[]);
+        let too_many = [gauge; MAX_DELEGATION_GAUGES + 1];
+        assert_eq!(
+            d.set(d.reactor, d.delegate, 0, &too_many).unwrap_err(),
+            ReactorErrors::TooManyDelegationGauges.into()
+        );
+    }
+
+    #[test]
+    fn test_check_vote_authority() {
+        let owner = Pubkey::new_unique();
+        let d = setup_delegation(100, &[]);
+
+        assert!(check_vote_authority(owner, owner, None, 0).is_ok());
+        assert!(check_vote_authority(owner, d.delegate, Some(&d), 100).is_ok());
+
+        assert_eq!(
+            check_vote_authority(owner, d.delegate, None, 0).unwrap_err(),
+            ReactorErrors::NotVoteAuthority.into()
+        );
+        assert_eq!(
+            check_vote_authority(owner, d.delegate, Some(&d), 101).unwrap_err(),
+            ReactorErrors::NotVoteAuthority.into()
+        );
+    }
+}
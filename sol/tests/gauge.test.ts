@@ -26,6 +26,7 @@ import { getAssociatedTokenAddressSync, TOKEN_PROGRAM_ID } from "@solana/spl-tok
 import { createPoolWithTrackerRewarder, increaseLiquidity, openPositionSmart, setUpClSwap } from "./cl-swap.test"
 import { accounts } from "@raygauge/ray-cl-idl"
 import { RayClPda } from "@raygauge/ray-cl-pda"
+import { types } from "@raygauge/gauge-gen"
 
 async function prelude(rayPerDay: bigint = 360n) {
   const { client, provider, context } = await bankrunPrelude()
@@ -51,20 +52,28 @@ async function prelude(rayPerDay: bigint = 360n) {
 
 describe("cl gauge", () => {
   it("inits a pool gauge", async () => {
-    const { client, clAdmin, gaugeConfig } = await prelude()
+    const { client, admin, clAdmin, gaugeConfig } = await prelude()
     // set up cl swap config & operation account
     await setUpClSwap({ client, clAdmin })
 
     // create pool
-    const { poolState, token1, token0 } = await logBlock("creating pool with rewarder", () =>
+    const { poolState, token1, token0, rewardTokenMint } = await logBlock("creating pool with rewarder", () =>
       createPoolWithTrackerRewarder({ client, clAdmin }),
     )
 
-    // init pool gauge
-    const poolGauge = await logBlock("init pool gauge", () =>
-      initPoolGauge({ client, payer: clAdmin, poolId: poolState, gaugeConfig }),
+    // the pool's rewarder tracks time units for the gauge
+    await logBlock("set cl time tracker mint", () =>
+      setClTimeTrackerMint({ client, admin, gaugeConfig, mint: rewardTokenMint.publicKey }),
     )
 
+    // init pool gauge, the pool already emits the time tracker reward
+    const poolGauge = await logBlock("init cl pool gauge", () =>
+      initClPoolGauge({ client, admin, poolId: poolState, gaugeConfig }),
+    )
+
+    // CL pool gauges are created by the admin, so they start whitelisted
+    expect(poolGauge.status).to.equal("Whitelisted")
+
     const bilbo = await initUser(client)
 
     const bilboToken0Ata = await logBlock("create token 0 ata for bilbo", () =>
@@ -137,6 +146,14 @@ describe("cp gauge ", () => {
       initPoolGauge({ client, payer: admin, poolId: poolA, gaugeConfig }),
     )
 
+    // new pool gauges cannot take votes until the admin whitelists them
+    expect(poolGaugeA.status).to.equal("Pending")
+
+    await logBlock("whitelist pool gauge A", () =>
+      whitelistPoolGauge({ client, admin, poolGauge: poolGaugeA, gaugeConfig }),
+    )
+
+    expect(poolGaugeA.status).to.equal("Whitelisted")
     expect(gaugeConfig.index).to.equal("0")
     expect(gaugeConfig.totalVotes.toString()).to.equal("0")
 
@@ -160,6 +177,10 @@ describe("cp gauge ", () => {
       initPoolGauge({ client, payer: admin, poolId: poolA, gaugeConfig }),
     )
 
+    await logBlock("whitelist pool gauge A", () =>
+      whitelistPoolGauge({ client, admin, poolGauge: poolGaugeA, gaugeConfig }),
+    )
+
     // Create user with a reactor
     const bilbo = await initUser(client)
     const frodo = await initUser(client)
@@ -281,6 +302,11 @@ describe("cp gauge ", () => {
       initPoolGauge({ client, payer: admin, poolId: poolB, gaugeConfig }),
     )
 
+    await logBlock("whitelist pool gauges", async () => {
+      await whitelistPoolGauge({ client, admin, poolGauge: poolGaugeA, gaugeConfig })
+      await whitelistPoolGauge({ client, admin, poolGauge: poolGaugeB, gaugeConfig })
+    })
+
     // Create user with a reactor
     const bilbo = await initUser(client)
     const frodo = await initUser(client)
@@ -804,3 +830,63 @@ async function initPoolGauge({
 
   return poolGauge
 }
+
+async function whitelistPoolGauge({
+  client,
+  admin,
+  poolGauge,
+  gaugeConfig,
+}: {
+  client: BankrunClient
+  admin: web3.Keypair
+  poolGauge: PoolGauge
+  gaugeConfig: GaugeConfig
+}) {
+  const ix = gaugeConfig.setGaugeStatusIx({ poolId: poolGauge.poolId, status: new types.GaugeStatus.Whitelisted() })
+  await signSendConfirm(client, [ix], admin)
+  await poolGauge.reload(client.getConnection())
+}
+
+async function setClTimeTrackerMint({
+  client,
+  admin,
+  gaugeConfig,
+  mint,
+}: {
+  client: BankrunClient
+  admin: web3.Keypair
+  gaugeConfig: GaugeConfig
+  mint: web3.PublicKey
+}) {
+  const ix = gaugeConfig.setClTimeTrackerMintIx({ mint })
+  await signSendConfirm(client, [ix], admin)
+  await gaugeConfig.reload(client.getConnection())
+}
+
+async function initClPoolGauge({
+  client,
+  admin,
+  poolId,
+  gaugeConfig,
+}: {
+  client: BankrunClient
+  admin: web3.Keypair
+  poolId: web3.PublicKey
+  gaugeConfig: GaugeConfig
+}) {
+  // only used when the pool does not emit the time tracker reward yet
+  const now = BigInt(Math.floor(Date.now() / 1000))
+  const ix = gaugeConfig.initClPoolGaugeIx({
+    poolId,
+    rewardOpenTime: now + 10n,
+    rewardEndTime: now + 60n * 24n * 60n * 60n,
+  })
+  await signSendConfirm(client, [ix], admin)
+
+  const poolGauge = await PoolGauge.load({
+    connection: client.getConnection(),
+    poolId,
+  })
+
+  return poolGauge
+}